@@ -151,7 +151,20 @@ pub(crate) unsafe fn create_connection_request(
         periodic_checks: None,
         pubsub_subscriptions: None,
         inflight_requests_limit: None,
+        inflight_overflow_policy: Default::default(),
         lazy_connect: false,
+        sentinel_configuration: None,
+        client_side_cache: None,
+        circuit_breaker: None,
+        request_hedging: None,
+        command_retry_strategy: None,
+        retry_budget: None,
+        adaptive_timeout: None,
+        key_prefix: None,
+        read_your_writes: false,
+        primary_check_interval: None,
+        tls_configuration: None,
+        credential_provider: None,
     }
 }
 
@@ -167,6 +180,7 @@ impl From<&Address> for NodeAddress {
         NodeAddress {
             host: unsafe { ptr_to_str(addr.host) },
             port: addr.port,
+            unix_socket_path: None,
         }
     }
 }
@@ -177,6 +177,9 @@ fn execute_command(
             route_bytes,
             route_len,
             0,
+            false,
+            0,
+            false,
         )
     };
     if command_res_ptr.is_null() {
@@ -313,3 +316,36 @@ fn test_ffi_client_command_execution(#[values(false, true)] async_client: bool)
         close_client(client_ptr);
     }
 }
+
+#[test]
+fn test_ffi_create_client_from_invalid_uri_returns_error() {
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+    let uri = c"valkey://localhost:6379?protocol=resp4";
+    unsafe {
+        let response_ptr = create_client_from_uri(
+            uri.as_ptr(),
+            client_type,
+            std::mem::transmute::<
+                *mut c_void,
+                unsafe extern "C-unwind" fn(
+                    client_ptr: usize,
+                    kind: PushKind,
+                    message: *const u8,
+                    message_len: i64,
+                    channel: *const u8,
+                    channel_len: i64,
+                    pattern: *const u8,
+                    pattern_len: i64,
+                ),
+            >(std::ptr::null_mut()),
+        );
+
+        assert!(!response_ptr.is_null(), "Failed to create a response");
+        let response = &*response_ptr;
+        assert!(response.conn_ptr.is_null());
+        let err_msg = parse_error_msg(response.connection_error_message);
+        assert!(err_msg.contains("protocol"), "{err_msg}");
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        drop(Box::from_raw(client_type));
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use crate::cmd::{Arg, Cmd};
+use crate::latency_tracker::LatencyWindow;
+use logger_core::log_error;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use telemetrylib::GlideOpenTelemetry;
+
+// === Default constants ===
+pub(crate) const DEFAULT_TIMEOUT_FLOOR: Duration = Duration::from_millis(50);
+pub(crate) const DEFAULT_P99_MULTIPLIER: f64 = 3.0;
+/// The latency percentile of each node from which the timeout is derived.
+pub(crate) const TIMEOUT_PERCENTILE: f64 = 99.0;
+/// The number of elements above which a range command is expected to be slow.
+pub(crate) const LARGE_RANGE: i64 = 1000;
+
+/// Configuration of an [`AdaptiveTimeout`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdaptiveTimeoutConfig {
+    /// The minimal effective timeout.
+    pub floor: Duration,
+    /// The multiple of the p99 latency that requests wait for before they time out.
+    pub multiplier: f64,
+}
+
+impl Default for AdaptiveTimeoutConfig {
+    fn default() -> Self {
+        Self {
+            floor: DEFAULT_TIMEOUT_FLOOR,
+            multiplier: DEFAULT_P99_MULTIPLIER,
+        }
+    }
+}
+
+/// Tracks the latency distribution of each node, and derives the request timeout from it,
+/// so that failures are detected quickly without timing out requests when the nodes are slow under load.
+///
+/// The effective timeout is `max(floor, multiplier × p99)` of the slowest node, capped by the configured request timeout.
+#[derive(Debug)]
+pub struct AdaptiveTimeout {
+    config: AdaptiveTimeoutConfig,
+    max_timeout: Duration,
+    nodes: Mutex<HashMap<String, LatencyWindow>>,
+    /// The last effective timeout in milliseconds, so that the metric is only recorded when it changes.
+    reported_timeout_ms: AtomicU64,
+}
+
+impl AdaptiveTimeout {
+    /// Create a tracker whose timeouts are capped by `max_timeout`, the configured request timeout.
+    pub fn new(config: AdaptiveTimeoutConfig, max_timeout: Duration) -> Self {
+        Self {
+            config,
+            max_timeout,
+            nodes: Default::default(),
+            reported_timeout_ms: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns whether the latency of `cmd` is tracked and its timeout derived from the observed latencies.
+    ///
+    /// Blocking commands wait for their own timeout, and known-slow commands - such as `KEYS`, scripts, functions,
+    /// search commands and range commands over large or unbounded ranges - take longer than the usual requests
+    /// regardless of the node's health, so they neither skew the tracked latencies nor time out by them.
+    pub fn applies_to(cmd: &Cmd) -> bool {
+        let arg = |idx| match cmd.args_iter().nth(idx) {
+            Some(Arg::Simple(arg)) => Some(arg),
+            _ => None,
+        };
+        let Some(command) = arg(0).map(<[u8]>::to_ascii_uppercase) else {
+            return true;
+        };
+        let has_arg = |candidate: &[u8]| {
+            cmd.args_iter().skip(1).any(|arg| match arg {
+                Arg::Simple(arg) => arg.eq_ignore_ascii_case(candidate),
+                Arg::Cursor => false,
+            })
+        };
+        match command.as_slice() {
+            // Blocking commands
+            b"BLPOP" | b"BRPOP" | b"BLMOVE" | b"BLMPOP" | b"BRPOPLPUSH" | b"BZPOPMAX"
+            | b"BZPOPMIN" | b"BZMPOP" | b"WAIT" | b"WAITAOF" => false,
+            b"XREAD" | b"XREADGROUP" => !has_arg(b"BLOCK"),
+            // Known-slow commands
+            b"KEYS" | b"EVAL" | b"EVALSHA" | b"EVAL_RO" | b"EVALSHA_RO" | b"FCALL"
+            | b"FCALL_RO" => false,
+            command if command.starts_with(b"FT.") => false,
+            b"LRANGE" | b"ZRANGE" | b"ZREVRANGE" => {
+                if has_arg(b"BYSCORE") || has_arg(b"BYLEX") {
+                    return has_arg(b"LIMIT");
+                }
+                let index = |idx| {
+                    arg(idx)
+                        .and_then(|arg| std::str::from_utf8(arg).ok())
+                        .and_then(|arg| arg.parse::<i64>().ok())
+                };
+                match (index(2), index(3)) {
+                    (Some(start), Some(stop)) if start >= 0 && stop >= 0 => {
+                        stop.saturating_sub(start) < LARGE_RANGE
+                    }
+                    _ => false,
+                }
+            }
+            b"ZRANGEBYSCORE" | b"ZREVRANGEBYSCORE" | b"ZRANGEBYLEX" | b"ZREVRANGEBYLEX" => {
+                has_arg(b"LIMIT")
+            }
+            b"XRANGE" | b"XREVRANGE" => has_arg(b"COUNT"),
+            _ => true,
+        }
+    }
+
+    /// Record the latency of a successful request to the given node.
+    /// Latencies above the configured request timeout are ignored, since they're of requests without a timeout, such as blocking commands.
+    pub fn record(&self, address: &str, latency: Duration) {
+        if latency > self.max_timeout {
+            return;
+        }
+        self.nodes
+            .lock()
+            .unwrap()
+            .entry(address.to_string())
+            .or_default()
+            .record(latency, TIMEOUT_PERCENTILE);
+    }
+
+    /// Drop the latencies of all nodes for which `keep` returns false.
+    pub fn retain(&self, mut keep: impl FnMut(&str) -> bool) {
+        self.nodes
+            .lock()
+            .unwrap()
+            .retain(|address, _| keep(address));
+    }
+
+    /// Returns the effective request timeout.
+    /// The configured request timeout is returned while too few latencies were recorded on all nodes.
+    pub fn timeout(&self) -> Duration {
+        let slowest_p99 = self
+            .nodes
+            .lock()
+            .unwrap()
+            .values()
+            .filter_map(LatencyWindow::percentile)
+            .max();
+        let timeout = match slowest_p99 {
+            Some(p99) => p99
+                .mul_f64(self.config.multiplier)
+                .max(self.config.floor)
+                .min(self.max_timeout),
+            None => self.max_timeout,
+        };
+        let timeout_ms = timeout.as_millis() as u64;
+        if self.reported_timeout_ms.swap(timeout_ms, Ordering::Relaxed) != timeout_ms {
+            if let Err(e) = GlideOpenTelemetry::record_effective_request_timeout(timeout_ms) {
+                log_error(
+                    "OpenTelemetry:effective_request_timeout_error",
+                    format!("Failed to record effective request timeout: {e}"),
+                );
+            }
+        }
+        timeout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::latency_tracker::MIN_LATENCY_SAMPLES;
+
+    fn record_samples(tracker: &AdaptiveTimeout, address: &str, latency: Duration) {
+        for _ in 0..MIN_LATENCY_SAMPLES {
+            tracker.record(address, latency);
+        }
+    }
+
+    #[test]
+    fn test_configured_timeout_is_used_without_samples() {
+        let tracker = AdaptiveTimeout::new(Default::default(), Duration::from_secs(1));
+        tracker.record("node1", Duration::from_millis(1));
+        assert_eq!(tracker.timeout(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_timeout_is_multiple_of_slowest_node_p99() {
+        let tracker = AdaptiveTimeout::new(
+            AdaptiveTimeoutConfig {
+                floor: Duration::from_millis(10),
+                multiplier: 2.0,
+            },
+            Duration::from_secs(1),
+        );
+        record_samples(&tracker, "node1", Duration::from_millis(20));
+        record_samples(&tracker, "node2", Duration::from_millis(100));
+        assert_eq!(tracker.timeout(), Duration::from_millis(200));
+
+        tracker.retain(|address| address == "node1");
+        assert_eq!(tracker.timeout(), Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_timeout_is_bounded_by_floor_and_configured_timeout() {
+        let tracker = AdaptiveTimeout::new(
+            AdaptiveTimeoutConfig {
+                floor: Duration::from_millis(50),
+                multiplier: 3.0,
+            },
+            Duration::from_millis(500),
+        );
+        record_samples(&tracker, "fast", Duration::from_millis(1));
+        assert_eq!(tracker.timeout(), Duration::from_millis(50));
+
+        record_samples(&tracker, "slow", Duration::from_millis(400));
+        assert_eq!(tracker.timeout(), Duration::from_millis(500));
+
+        // Latencies above the configured timeout are ignored.
+        record_samples(&tracker, "blocking", Duration::from_secs(10));
+        tracker.retain(|address| address != "slow");
+        assert_eq!(tracker.timeout(), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_blocking_and_slow_commands_are_exempt() {
+        let command = |args: &[&str]| {
+            let mut cmd = crate::cmd(args[0]);
+            cmd.arg(&args[1..]);
+            cmd
+        };
+        for args in [
+            &["GET", "foo"][..],
+            &["XREAD", "STREAMS", "foo", "0"],
+            &["LRANGE", "foo", "0", "99"],
+            &["ZRANGE", "foo", "0", "10", "BYSCORE", "LIMIT", "0", "10"],
+            &["XRANGE", "foo", "-", "+", "COUNT", "10"],
+        ] {
+            assert!(AdaptiveTimeout::applies_to(&command(args)), "{args:?}");
+        }
+        for args in [
+            &["BLPOP", "foo", "1"][..],
+            &["xread", "block", "100", "STREAMS", "foo", "0"],
+            &["WAIT", "1", "100"],
+            &["KEYS", "*"],
+            &["EVALSHA", "sha", "0"],
+            &["FCALL", "func", "0"],
+            &["FT.SEARCH", "index", "*"],
+            &["LRANGE", "foo", "0", "-1"],
+            &["LRANGE", "foo", "0", "100000"],
+            &["ZRANGE", "foo", "-inf", "+inf", "BYSCORE"],
+            &["ZRANGEBYSCORE", "foo", "-inf", "+inf"],
+            &["XRANGE", "foo", "-", "+"],
+        ] {
+            assert!(!AdaptiveTimeout::applies_to(&command(args)), "{args:?}");
+        }
+    }
+}
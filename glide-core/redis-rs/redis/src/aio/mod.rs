@@ -1,7 +1,7 @@
 //! Adds async IO support to redis.
 use crate::cmd::{cmd, Cmd};
 use crate::connection::{
-    get_resp3_hello_command_error, PubSubSubscriptionKind, RedisConnectionInfo,
+    get_resp3_hello_command_error, ClientTrackingMode, PubSubSubscriptionKind, RedisConnectionInfo,
 };
 use crate::pipeline::PipelineRetryStrategy;
 use crate::types::{
@@ -221,6 +221,30 @@ where
         }
     }
 
+    if let Some(client_tracking) = &connection_info.client_tracking {
+        if connection_info.protocol != ProtocolVersion::RESP3 {
+            fail!((
+                ErrorKind::InvalidClientConfig,
+                "Client tracking requires RESP3"
+            ));
+        }
+        let mut tracking_command = cmd("CLIENT");
+        tracking_command.arg("TRACKING").arg("ON");
+        if let ClientTrackingMode::Broadcast { prefixes } = client_tracking {
+            tracking_command.arg("BCAST");
+            for prefix in prefixes {
+                tracking_command.arg("PREFIX").arg(prefix);
+            }
+        }
+        match tracking_command.query_async(con).await {
+            Ok(Value::Okay) => {}
+            _ => fail!((
+                ErrorKind::ResponseError,
+                "Redis server refused to enable client tracking"
+            )),
+        }
+    }
+
     if discover_az {
         update_az_from_info(con).await?;
     }
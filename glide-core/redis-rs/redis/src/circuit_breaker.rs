@@ -0,0 +1,398 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use crate::types::{ErrorKind, RedisError, RedisResult};
+use logger_core::log_error;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use telemetrylib::GlideOpenTelemetry;
+use tracing::{info, warn};
+
+// === Default constants ===
+pub(crate) const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+pub(crate) const DEFAULT_OPEN_DURATION: Duration = Duration::from_secs(5);
+pub(crate) const DEFAULT_HALF_OPEN_PROBES: u32 = 1;
+
+thread_local! {
+    /// Set while [`cancel_permits`] runs, so the permits dropped in it are cancelled rather than failed.
+    static CANCELLING_PERMITS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Configuration of a [`CircuitBreaker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// The number of consecutive failures on a node after which its circuit opens.
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects requests before letting probe requests through to the node.
+    pub open_duration: Duration,
+    /// The number of probe requests let through while the circuit is half open.
+    pub half_open_probes: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+            open_duration: DEFAULT_OPEN_DURATION,
+            half_open_probes: DEFAULT_HALF_OPEN_PROBES,
+        }
+    }
+}
+
+/// The state of the circuit of a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent to the node.
+    Closed,
+    /// Requests to the node fail immediately with [`ErrorKind::CircuitOpen`].
+    Open,
+    /// A limited number of probe requests are sent to the node, to check whether it recovered.
+    HalfOpen,
+}
+
+impl fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitState::Closed => write!(f, "closed"),
+            CircuitState::Open => write!(f, "open"),
+            CircuitState::HalfOpen => write!(f, "half_open"),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Circuit {
+    Closed { consecutive_failures: u32 },
+    Open { since: Instant },
+    HalfOpen { probes: u32 },
+}
+
+impl Circuit {
+    fn state(&self) -> CircuitState {
+        match self {
+            Circuit::Closed { .. } => CircuitState::Closed,
+            Circuit::Open { .. } => CircuitState::Open,
+            Circuit::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+}
+
+/// Tracks the failures of requests to each node, and fails requests to a node immediately
+/// once it failed `failure_threshold` consecutive times, instead of waiting for it to time out.
+///
+/// Only errors that indicate an unhealthy node, such as I/O errors and timeouts, count as failures.
+/// After `open_duration`, probe requests are let through, and the circuit closes once one of them succeeds.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    circuits: Mutex<HashMap<String, Circuit>>,
+}
+
+impl CircuitBreaker {
+    /// Create a circuit breaker in which all circuits are closed.
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            circuits: Default::default(),
+        }
+    }
+
+    /// Returns the state of the circuit of the given node address.
+    pub fn state(&self, address: &str) -> CircuitState {
+        self.circuits
+            .lock()
+            .unwrap()
+            .get(address)
+            .map_or(CircuitState::Closed, Circuit::state)
+    }
+
+    /// Checks whether a request may be sent to the given node address.
+    /// Returns an [`ErrorKind::CircuitOpen`] error if the circuit is open, or if it's half open and the probes are already in flight.
+    /// The outcome of the request should be reported through [`CircuitPermit::record`].
+    pub fn try_acquire(&self, address: &str) -> RedisResult<CircuitPermit<'_>> {
+        let mut circuits = self.circuits.lock().unwrap();
+        if let Some(circuit) = circuits.get_mut(address) {
+            match circuit {
+                Circuit::Closed { .. } => {}
+                Circuit::Open { since } if since.elapsed() >= self.config.open_duration => {
+                    *circuit = Circuit::HalfOpen { probes: 1 };
+                    report_transition(address, CircuitState::Open, CircuitState::HalfOpen);
+                }
+                Circuit::HalfOpen { probes } if *probes < self.config.half_open_probes => {
+                    *probes += 1;
+                }
+                Circuit::Open { .. } | Circuit::HalfOpen { .. } => {
+                    return Err(RedisError::from((
+                        ErrorKind::CircuitOpen,
+                        "Circuit breaker is open for node",
+                        address.to_string(),
+                    )));
+                }
+            }
+        }
+        Ok(CircuitPermit {
+            breaker: self,
+            address: address.to_string(),
+            recorded: false,
+        })
+    }
+
+    /// Drop the circuits of all nodes for which `keep` returns false.
+    pub fn retain(&self, mut keep: impl FnMut(&str) -> bool) {
+        self.circuits
+            .lock()
+            .unwrap()
+            .retain(|address, _| keep(address));
+    }
+
+    fn record(&self, address: &str, failed: bool) {
+        let mut circuits = self.circuits.lock().unwrap();
+        if failed {
+            let circuit = circuits
+                .entry(address.to_string())
+                .or_insert(Circuit::Closed {
+                    consecutive_failures: 0,
+                });
+            self.record_failure(address, circuit);
+            return;
+        }
+        match circuits.get(address) {
+            // Requests that were sent before the circuit opened don't close it.
+            None | Some(Circuit::Open { .. }) => {}
+            Some(Circuit::Closed { .. }) => {
+                circuits.remove(address);
+            }
+            Some(Circuit::HalfOpen { .. }) => {
+                circuits.remove(address);
+                report_transition(address, CircuitState::HalfOpen, CircuitState::Closed);
+            }
+        }
+    }
+
+    /// Releases a permit without an outcome. A half open circuit lets another probe through instead.
+    fn cancel(&self, address: &str) {
+        if let Some(Circuit::HalfOpen { probes }) = self.circuits.lock().unwrap().get_mut(address) {
+            *probes = probes.saturating_sub(1);
+        }
+    }
+
+    fn record_failure(&self, address: &str, circuit: &mut Circuit) {
+        match circuit {
+            Circuit::Closed {
+                consecutive_failures,
+            } => {
+                *consecutive_failures += 1;
+                if *consecutive_failures >= self.config.failure_threshold {
+                    *circuit = Circuit::Open {
+                        since: Instant::now(),
+                    };
+                    report_transition(address, CircuitState::Closed, CircuitState::Open);
+                }
+            }
+            Circuit::Open { .. } => {}
+            Circuit::HalfOpen { .. } => {
+                *circuit = Circuit::Open {
+                    since: Instant::now(),
+                };
+                report_transition(address, CircuitState::HalfOpen, CircuitState::Open);
+            }
+        }
+    }
+}
+
+/// Permission to send a single request to a node, returned by [`CircuitBreaker::try_acquire`].
+///
+/// A permit that is dropped without being recorded counts as a failure, since the request was abandoned before it completed,
+/// usually because it timed out. Requests that are abandoned because they're no longer needed, such as the slower of two hedged reads,
+/// are cancelled with [`CircuitPermit::cancel`] instead.
+#[derive(Debug)]
+pub struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    address: String,
+    recorded: bool,
+}
+
+impl CircuitPermit<'_> {
+    /// Report the outcome of the request.
+    pub fn record<T>(mut self, result: &RedisResult<T>) {
+        self.recorded = true;
+        self.breaker
+            .record(&self.address, result.as_ref().is_err_and(is_node_failure));
+    }
+
+    /// Release the permit without an outcome, since the request was cancelled. The state of the circuit doesn't change.
+    pub fn cancel(mut self) {
+        self.recorded = true;
+        self.breaker.cancel(&self.address);
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if self.recorded {
+            return;
+        }
+        if CANCELLING_PERMITS.with(Cell::get) {
+            self.breaker.cancel(&self.address);
+        } else {
+            self.breaker.record(&self.address, true);
+        }
+    }
+}
+
+/// Runs `drop_requests`, and cancels the circuit permits that are dropped by it, instead of counting them as failures.
+/// Used to drop requests that hold permits, and whose replies are no longer needed.
+pub fn cancel_permits<R>(drop_requests: impl FnOnce() -> R) -> R {
+    struct ResetOnDrop(bool);
+    impl Drop for ResetOnDrop {
+        fn drop(&mut self) {
+            CANCELLING_PERMITS.with(|cancelling| cancelling.set(self.0));
+        }
+    }
+    let _reset = ResetOnDrop(CANCELLING_PERMITS.with(|cancelling| cancelling.replace(true)));
+    drop_requests()
+}
+
+/// Returns true if the error indicates that the node is unhealthy, rather than that the request itself failed.
+fn is_node_failure(err: &RedisError) -> bool {
+    err.kind() == ErrorKind::IoError
+        || err.is_timeout()
+        || err.is_connection_dropped()
+        || err.is_connection_refusal()
+}
+
+fn report_transition(address: &str, from: CircuitState, to: CircuitState) {
+    match to {
+        CircuitState::Open => {
+            warn!("Circuit breaker for node {address} changed from {from} to {to}")
+        }
+        _ => info!("Circuit breaker for node {address} changed from {from} to {to}"),
+    }
+    if let Err(e) = GlideOpenTelemetry::record_circuit_breaker_transition(&to.to_string()) {
+        log_error(
+            "OpenTelemetry:circuit_breaker_transition",
+            format!("Failed to record circuit breaker transition: {e}"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    fn config(open_duration: Duration) -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_duration,
+            half_open_probes: 1,
+        }
+    }
+
+    fn io_error() -> RedisResult<()> {
+        Err(io::Error::from(io::ErrorKind::ConnectionReset).into())
+    }
+
+    #[test]
+    fn test_opens_after_consecutive_failures() {
+        let breaker = CircuitBreaker::new(config(Duration::from_secs(60)));
+        breaker
+            .try_acquire("node1:6379")
+            .unwrap()
+            .record(&io_error());
+        breaker.try_acquire("node1:6379").unwrap().record(&Ok(()));
+        breaker
+            .try_acquire("node1:6379")
+            .unwrap()
+            .record(&io_error());
+        assert_eq!(breaker.state("node1:6379"), CircuitState::Closed);
+
+        breaker
+            .try_acquire("node1:6379")
+            .unwrap()
+            .record(&io_error());
+        assert_eq!(breaker.state("node1:6379"), CircuitState::Open);
+        let err = breaker.try_acquire("node1:6379").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CircuitOpen);
+        assert!(breaker.try_acquire("node2:6379").is_ok());
+    }
+
+    #[test]
+    fn test_response_errors_are_not_failures() {
+        let breaker = CircuitBreaker::new(config(Duration::from_secs(60)));
+        for _ in 0..3 {
+            let result: RedisResult<()> = Err((ErrorKind::ResponseError, "WRONGTYPE").into());
+            breaker.try_acquire("node1:6379").unwrap().record(&result);
+        }
+        assert_eq!(breaker.state("node1:6379"), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_abandoned_requests_are_failures() {
+        let breaker = CircuitBreaker::new(config(Duration::from_secs(60)));
+        drop(breaker.try_acquire("node1:6379").unwrap());
+        drop(breaker.try_acquire("node1:6379").unwrap());
+        assert_eq!(breaker.state("node1:6379"), CircuitState::Open);
+    }
+
+    #[test]
+    fn test_cancelled_requests_are_not_failures() {
+        let breaker = CircuitBreaker::new(config(Duration::from_secs(60)));
+        breaker
+            .try_acquire("node1:6379")
+            .unwrap()
+            .record(&io_error());
+        breaker.try_acquire("node1:6379").unwrap().cancel();
+        let permit = breaker.try_acquire("node1:6379").unwrap();
+        cancel_permits(|| drop(permit));
+        assert_eq!(breaker.state("node1:6379"), CircuitState::Closed);
+
+        // The failure count wasn't reset by the cancelled requests.
+        breaker
+            .try_acquire("node1:6379")
+            .unwrap()
+            .record(&io_error());
+        assert_eq!(breaker.state("node1:6379"), CircuitState::Open);
+    }
+
+    #[test]
+    fn test_cancelled_probe_lets_another_probe_through() {
+        let breaker = CircuitBreaker::new(config(Duration::ZERO));
+        for _ in 0..2 {
+            breaker
+                .try_acquire("node1:6379")
+                .unwrap()
+                .record(&io_error());
+        }
+        breaker.try_acquire("node1:6379").unwrap().cancel();
+        assert_eq!(breaker.state("node1:6379"), CircuitState::HalfOpen);
+        breaker.try_acquire("node1:6379").unwrap().record(&Ok(()));
+        assert_eq!(breaker.state("node1:6379"), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_probe_closes_or_reopens() {
+        let breaker = CircuitBreaker::new(config(Duration::ZERO));
+        breaker
+            .try_acquire("node1:6379")
+            .unwrap()
+            .record(&io_error());
+        breaker
+            .try_acquire("node1:6379")
+            .unwrap()
+            .record(&io_error());
+        assert_eq!(breaker.state("node1:6379"), CircuitState::Open);
+
+        let probe = breaker.try_acquire("node1:6379").unwrap();
+        assert_eq!(breaker.state("node1:6379"), CircuitState::HalfOpen);
+        // Only a single probe is let through at a time.
+        assert!(breaker.try_acquire("node1:6379").is_err());
+        probe.record(&io_error());
+        assert_eq!(breaker.state("node1:6379"), CircuitState::Open);
+
+        breaker.try_acquire("node1:6379").unwrap().record(&Ok(()));
+        assert_eq!(breaker.state("node1:6379"), CircuitState::Closed);
+    }
+}
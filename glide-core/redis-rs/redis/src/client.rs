@@ -1,3 +1,4 @@
+use std::sync::Arc;
 use std::time::Duration;
 
 #[cfg(feature = "aio")]
@@ -6,6 +7,7 @@ use crate::aio::DisconnectNotifier;
 use crate::{
     connection::{connect, Connection, ConnectionInfo, ConnectionLike, IntoConnectionInfo},
     push_manager::PushInfo,
+    retry_budget::RetryBudget,
     retry_strategies::RetryStrategy,
     types::{RedisResult, Value},
 };
@@ -96,6 +98,8 @@ pub struct GlideConnectionOptions {
     pub connection_timeout: Option<Duration>,
     /// Retry strategy configuration for reconnect attempts.
     pub connection_retry_strategy: Option<RetryStrategy>,
+    /// The retry budget of the client. Reconnect attempts after the first one draw from it, and are postponed while it's exhausted.
+    pub retry_budget: Option<Arc<RetryBudget>>,
 }
 
 /// To enable async support you need to enable the feature: `tokio-comp`
@@ -509,6 +513,7 @@ impl Client {
     ///                 client_key: client_key_vec,
     ///             }),
     ///             root_cert: Some(root_cert_vec),
+    ///             server_name: None,
     ///         }
     ///     )
     ///     .expect("Unable to build client");
@@ -569,6 +574,14 @@ impl Client {
     pub fn update_password(&mut self, password: Option<String>) {
         self.connection_info.redis.password = password;
     }
+
+    /// Updates the pubsub subscriptions in connection_info, which are restored whenever a connection is established.
+    pub fn update_pubsub_subscriptions(
+        &mut self,
+        pubsub_subscriptions: Option<crate::PubSubSubscriptionInfo>,
+    ) {
+        self.connection_info.redis.pubsub_subscriptions = pubsub_subscriptions;
+    }
 }
 
 #[cfg(feature = "aio")]
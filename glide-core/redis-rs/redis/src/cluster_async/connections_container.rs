@@ -2,6 +2,7 @@ use crate::cluster_async::ConnectionFuture;
 use crate::cluster_routing::{Route, ShardAddrs, SlotAddr};
 use crate::cluster_slotmap::{ReadFromReplicaStrategy, SlotMap, SlotMapValue};
 use crate::cluster_topology::TopologyHash;
+use crate::{LatencyTracker, ReadYourWrites};
 use dashmap::DashMap;
 use futures::FutureExt;
 use rand::seq::IteratorRandom;
@@ -296,6 +297,9 @@ pub(crate) struct ConnectionsContainer<Connection> {
     read_from_replica_strategy: ReadFromReplicaStrategy,
     topology_hash: TopologyHash,
     pub(crate) refresh_conn_state: RefreshConnectionStates,
+    pub(crate) latency_tracker: Arc<LatencyTracker>,
+    /// Limits reads from replicas to those that replicated the client's writes, if session consistency is enabled.
+    read_your_writes: Option<Arc<ReadYourWrites>>,
 }
 
 impl<Connection> Drop for ConnectionsContainer<Connection> {
@@ -313,6 +317,8 @@ impl<Connection> Default for ConnectionsContainer<Connection> {
             read_from_replica_strategy: ReadFromReplicaStrategy::AlwaysFromPrimary,
             topology_hash: 0,
             refresh_conn_state: Default::default(),
+            latency_tracker: Default::default(),
+            read_your_writes: None,
         }
     }
 }
@@ -328,6 +334,8 @@ where
         connection_map: ConnectionsMap<Connection>,
         read_from_replica_strategy: ReadFromReplicaStrategy,
         topology_hash: TopologyHash,
+        latency_tracker: Arc<LatencyTracker>,
+        read_your_writes: Option<Arc<ReadYourWrites>>,
     ) -> Self {
         let connection_map = connection_map.0;
 
@@ -341,9 +349,20 @@ where
             read_from_replica_strategy,
             topology_hash,
             refresh_conn_state: Default::default(),
+            latency_tracker,
+            read_your_writes,
         }
     }
 
+    /// Returns the latency tracker if the read from strategy relies on node latencies.
+    pub(crate) fn latency_tracker(&self) -> Option<&Arc<LatencyTracker>> {
+        matches!(
+            self.read_from_replica_strategy,
+            ReadFromReplicaStrategy::LowestLatency
+        )
+        .then_some(&self.latency_tracker)
+    }
+
     /// Returns an iterator over the nodes in the `slot_map`, yielding pairs of the node address and its associated shard addresses.
     pub(crate) fn slot_map_nodes(
         &self,
@@ -480,6 +499,65 @@ where
         self.round_robin_read_from_replica(slot_map_value)
     }
 
+    /// Returns the connection of the replica with the lowest measured latency.
+    /// Until latency samples are collected for the connected replicas, falls back to round robin.
+    fn lowest_latency_read_from_replica(
+        &self,
+        slot_map_value: &SlotMapValue,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        let current_index = slot_map_value.last_used_replica.load(Ordering::Relaxed);
+        let selected_replica = {
+            let replicas = slot_map_value.addrs.replicas();
+            let candidates = replicas
+                .iter()
+                .enumerate()
+                .filter(|(_, replica)| self.connection_map.contains_key(replica.as_str()))
+                .map(|(index, replica)| (index, replica.as_str()));
+            self.latency_tracker
+                .select(candidates, current_index)
+                .map(|index| (index, replicas[index].clone()))
+        };
+        match selected_replica {
+            Some((index, replica)) => {
+                slot_map_value
+                    .last_used_replica
+                    .store(index, Ordering::Relaxed);
+                self.connection_for_address(replica.as_str())
+                    .or_else(|| self.round_robin_read_from_replica(slot_map_value))
+            }
+            None => self.round_robin_read_from_replica(slot_map_value),
+        }
+    }
+
+    /// Returns true if reads may be sent to `replica` of `primary`, i.e. session consistency is disabled or the replica replicated the client's writes.
+    fn is_readable_replica(&self, primary: &str, replica: &str) -> bool {
+        self.read_your_writes
+            .as_ref()
+            .map_or(true, |read_your_writes| {
+                read_your_writes.is_caught_up(primary, replica)
+            })
+    }
+
+    /// Returns the connections of the replicas of `primary`.
+    pub(crate) fn replica_connections(
+        &self,
+        primary: &str,
+    ) -> Vec<ConnectionAndAddress<Connection>> {
+        let Some(shard_addrs) = self
+            .slot_map
+            .nodes_map()
+            .get(&primary.to_string())
+            .map(|item| item.value().clone())
+        else {
+            return Vec::new();
+        };
+        let replicas = shard_addrs.replicas();
+        replicas
+            .iter()
+            .filter_map(|replica| self.connection_for_address(replica.as_str()))
+            .collect()
+    }
+
     fn lookup_route(&self, route: &Route) -> Option<ConnectionAndAddress<Connection>> {
         let slot_map_value = self.slot_map.slot_value_for_route(route)?;
         let addrs = &slot_map_value.addrs;
@@ -487,6 +565,25 @@ where
             return self.connection_for_address(addrs.primary().as_str());
         }
 
+        let primary = addrs.primary();
+        let connection = self.lookup_route_by_strategy(route, slot_map_value);
+        match connection {
+            // Reads from a replica that didn't replicate the client's writes fall back to the primary.
+            Some((address, _))
+                if address != *primary && !self.is_readable_replica(&primary, &address) =>
+            {
+                self.connection_for_address(primary.as_str())
+            }
+            connection => connection,
+        }
+    }
+
+    fn lookup_route_by_strategy(
+        &self,
+        route: &Route,
+        slot_map_value: &SlotMapValue,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        let addrs = &slot_map_value.addrs;
         match route.slot_addr() {
             // Master strategy will be in use when the command is not read_only
             SlotAddr::Master => self.connection_for_address(addrs.primary().as_str()),
@@ -498,6 +595,9 @@ where
                 ReadFromReplicaStrategy::RoundRobin => {
                     self.round_robin_read_from_replica(slot_map_value)
                 }
+                ReadFromReplicaStrategy::LowestLatency => {
+                    self.lowest_latency_read_from_replica(slot_map_value)
+                }
                 ReadFromReplicaStrategy::AZAffinity(az) => self
                     .round_robin_read_from_replica_with_az_awareness(
                         slot_map_value,
@@ -521,6 +621,9 @@ where
                         slot_map_value,
                         az.to_string(),
                     ),
+                ReadFromReplicaStrategy::LowestLatency => {
+                    self.lowest_latency_read_from_replica(slot_map_value)
+                }
                 _ => self.round_robin_read_from_replica(slot_map_value),
             },
         }
@@ -539,6 +642,33 @@ where
         })
     }
 
+    /// Returns a connection to another node that serves the route's slot, to which a hedged read can be sent.
+    /// Prefers a connected replica other than `excluded_address`, and falls back to the primary.
+    /// Returns `None` if reads aren't routed to replicas, or if the slot has no other connected node.
+    pub(crate) fn hedge_connection_for_route(
+        &self,
+        route: &Route,
+        excluded_address: &str,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        if route.slot_addr() == SlotAddr::Master
+            || matches!(
+                self.read_from_replica_strategy,
+                ReadFromReplicaStrategy::AlwaysFromPrimary
+            )
+        {
+            return None;
+        }
+        let addrs = &self.slot_map.slot_value_for_route(route)?.addrs;
+        let primary = addrs.primary();
+        let replicas = addrs.replicas();
+        replicas
+            .iter()
+            .filter(|replica| self.is_readable_replica(&primary, replica))
+            .chain(std::iter::once(&primary))
+            .filter(|address| address.as_str() != excluded_address)
+            .find_map(|address| self.connection_for_address(address.as_str()))
+    }
+
     // Fetches the master address for a given route.
     // Returns `None` if no master address can be resolved.
     pub(crate) fn address_for_route(&self, route: &Route) -> Option<String> {
@@ -695,6 +825,7 @@ where
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use std::time::Duration;
 
     use crate::cluster_routing::Slot;
 
@@ -818,6 +949,8 @@ mod tests {
                 .unwrap_or(ReadFromReplicaStrategy::AZAffinity("use-1a".to_string())),
             topology_hash: 0,
             refresh_conn_state: Default::default(),
+            latency_tracker: Default::default(),
+            read_your_writes: None,
         }
     }
 
@@ -875,6 +1008,8 @@ mod tests {
             read_from_replica_strategy: strategy,
             topology_hash: 0,
             refresh_conn_state: Default::default(),
+            latency_tracker: Default::default(),
+            read_your_writes: None,
         }
     }
 
@@ -1046,6 +1181,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_connection_for_lowest_latency_route() {
+        let container =
+            create_container_with_strategy(ReadFromReplicaStrategy::LowestLatency, false);
+
+        // without latency samples, the replicas are used in a round robin manner
+        assert!(one_of(
+            container.connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional)),
+            &[31, 32],
+        ));
+
+        let tracker = container.latency_tracker().unwrap();
+        tracker.record("replica3-1", Duration::from_millis(10));
+        tracker.record("replica3-2", Duration::from_millis(1));
+        for _ in 0..3 {
+            assert_eq!(
+                32,
+                container
+                    .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                    .unwrap()
+                    .1
+            );
+        }
+
+        // remove the fastest replica and get the other one
+        remove_nodes(&container, &["replica3-2"]);
+        assert_eq!(
+            31,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaRequired))
+                .unwrap()
+                .1
+        );
+
+        // remove the last replica and get the primary
+        remove_nodes(&container, &["replica3-1"]);
+        assert_eq!(
+            3,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+    }
+
+    #[test]
+    fn get_hedge_connection_for_replica_route() {
+        let container = create_container();
+        let route = Route::new(2001, SlotAddr::ReplicaOptional);
+
+        assert_eq!(
+            32,
+            container
+                .hedge_connection_for_route(&route, "replica3-1")
+                .unwrap()
+                .1
+        );
+        assert_eq!(
+            31,
+            container
+                .hedge_connection_for_route(&route, "replica3-2")
+                .unwrap()
+                .1
+        );
+
+        // without other connected replicas, the hedge is sent to the primary
+        remove_nodes(&container, &["replica3-2"]);
+        assert_eq!(
+            3,
+            container
+                .hedge_connection_for_route(&route, "replica3-1")
+                .unwrap()
+                .1
+        );
+
+        // writes and slots without other nodes aren't hedged
+        assert!(container
+            .hedge_connection_for_route(&Route::new(2001, SlotAddr::Master), "primary3")
+            .is_none());
+        assert!(container
+            .hedge_connection_for_route(&Route::new(500, SlotAddr::ReplicaOptional), "primary1")
+            .is_none());
+    }
+
+    #[test]
+    fn hedge_connection_is_not_returned_when_reading_from_primary() {
+        let container =
+            create_container_with_strategy(ReadFromReplicaStrategy::AlwaysFromPrimary, false);
+        assert!(container
+            .hedge_connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional), "primary3")
+            .is_none());
+    }
+
+    #[test]
+    fn latency_tracker_is_only_exposed_for_lowest_latency_strategy() {
+        let container = create_container();
+        assert!(container.latency_tracker().is_none());
+    }
+
     #[test]
     fn get_connection_for_az_affinity_route() {
         let container = create_container_with_az_strategy(
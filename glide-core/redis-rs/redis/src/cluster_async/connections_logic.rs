@@ -196,6 +196,7 @@ where
             discover_az,
             connection_timeout: Some(params.connection_timeout),
             connection_retry_strategy: None,
+            retry_budget: None,
         },
     )
     .await
@@ -369,6 +370,30 @@ where
     Ok(())
 }
 
+/// Creates a user connection to the node which isn't tracked by the cluster connection, so it's never shared with other requests.
+/// The connection doesn't receive pubsub subscriptions, client tracking or push notifications,
+/// and otherwise uses the cluster's `glide_connection_options`, such as its connection timeout.
+pub(crate) async fn create_dedicated_connection<C>(
+    node: &str,
+    mut params: ClusterParams,
+    glide_connection_options: GlideConnectionOptions,
+) -> RedisResult<C>
+where
+    C: ConnectionLike + Connect + Send + 'static,
+{
+    params.pubsub_subscriptions = None;
+    params.client_tracking = None;
+    let glide_connection_options = GlideConnectionOptions {
+        push_sender: None,
+        disconnect_notifier: None,
+        ..glide_connection_options
+    };
+    let mut connection: ConnectionDetails<C> =
+        create_connection(node, params.clone(), None, false, glide_connection_options).await?;
+    setup_user_connection(&mut connection, params).await?;
+    Ok(connection.conn)
+}
+
 #[doc(hidden)]
 pub const MANAGEMENT_CONN_NAME: &str = "glide_management_connection";
 
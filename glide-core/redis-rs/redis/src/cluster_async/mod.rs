@@ -40,7 +40,8 @@ use crate::{
     cmd,
     commands::cluster_scan::{cluster_scan, ClusterScanArgs, ScanStateRC},
     types::ServerError,
-    FromRedisValue, InfoDict, PipelineRetryStrategy,
+    AdaptiveTimeout, CircuitBreaker, FromRedisValue, InfoDict, LatencyTracker,
+    PipelineRetryStrategy, ReadYourWrites, RequestDeadline, RequestHedger, RequestStage,
 };
 use connections_container::{RefreshTaskNotifier, RefreshTaskState, RefreshTaskStatus};
 use dashmap::DashMap;
@@ -75,17 +76,18 @@ use crate::{
     aio::{get_socket_addrs, ConnectionLike, MultiplexedConnection, Runtime},
     cluster::slot_cmd,
     cluster_async::connections_logic::{
-        get_host_and_port_from_addr, get_or_create_conn, ConnectionFuture, RefreshConnectionType,
+        create_dedicated_connection, get_host_and_port_from_addr, get_or_create_conn,
+        ConnectionFuture, RefreshConnectionType,
     },
     cluster_client::{ClusterParams, RetryParams},
     cluster_routing::{
-        self, MultipleNodeRoutingInfo, Redirect, ResponsePolicy, Route, SingleNodeRoutingInfo,
-        SlotAddr,
+        self, is_readonly_cmd, MultipleNodeRoutingInfo, Redirect, ResponsePolicy, Route,
+        SingleNodeRoutingInfo, SlotAddr,
     },
-    connection::{PubSubSubscriptionInfo, PubSubSubscriptionKind},
+    connection::{PubSubChannelOrPattern, PubSubSubscriptionInfo, PubSubSubscriptionKind},
     push_manager::PushInfo,
-    Cmd, ConnectionInfo, ErrorKind, IntoConnectionInfo, RedisError, RedisFuture, RedisResult,
-    Value,
+    Cmd, ConnectionInfo, ErrorKind, IntoConnectionInfo, PushKind, RedisError, RedisFuture,
+    RedisResult, Value,
 };
 use futures::stream::{FuturesUnordered, StreamExt};
 use std::time::Duration;
@@ -218,6 +220,7 @@ where
             .send(Message {
                 cmd: CmdArg::ClusterScan { cluster_scan_args },
                 sender,
+                deadline: None,
             })
             .await
             .map_err(|e| {
@@ -245,6 +248,26 @@ where
         &mut self,
         cmd: &Cmd,
         routing: cluster_routing::RoutingInfo,
+    ) -> RedisResult<Value> {
+        self.send_command(cmd, routing, None).await
+    }
+
+    /// Send a command to the given `routing`, like [`ClusterConnection::route_command`].
+    /// Redirects and retries of the command stop once `deadline` is too close, and the command fails with the deadline's timeout error.
+    pub async fn route_command_with_deadline(
+        &mut self,
+        cmd: &Cmd,
+        routing: cluster_routing::RoutingInfo,
+        deadline: Arc<RequestDeadline>,
+    ) -> RedisResult<Value> {
+        self.send_command(cmd, routing, Some(deadline)).await
+    }
+
+    async fn send_command(
+        &mut self,
+        cmd: &Cmd,
+        routing: cluster_routing::RoutingInfo,
+        deadline: Option<Arc<RequestDeadline>>,
     ) -> RedisResult<Value> {
         trace!("route_command");
         let (sender, receiver) = oneshot::channel();
@@ -255,6 +278,7 @@ where
                     routing: routing.into(),
                 },
                 sender,
+                deadline,
             })
             .await
             .map_err(|e| {
@@ -304,6 +328,7 @@ where
                     pipeline_retry_strategy: pipeline_retry_strategy.unwrap_or_default(),
                 },
                 sender,
+                deadline: None,
             })
             .await
             .map_err(|err| {
@@ -337,6 +362,85 @@ where
         self.route_operation_request(Operation::GetUsername).await
     }
 
+    /// Add the given channels or patterns to the desired subscriptions of the connection.
+    /// The subscriptions are applied on the nodes owning the channels' slots, and are restored after reconnections and topology changes.
+    /// Requires RESP3.
+    pub async fn add_pubsub_subscriptions(
+        &mut self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<Value> {
+        self.route_operation_request(Operation::AddPubSubSubscriptions(
+            kind,
+            channels_or_patterns,
+        ))
+        .await
+    }
+
+    /// Remove the given channels or patterns from the desired subscriptions of the connection.
+    /// Requires RESP3.
+    pub async fn remove_pubsub_subscriptions(
+        &mut self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<Value> {
+        self.route_operation_request(Operation::RemovePubSubSubscriptions(
+            kind,
+            channels_or_patterns,
+        ))
+        .await
+    }
+
+    /// Resolve `route` to the address of the node that currently serves it.
+    /// Requests that depend on state kept by a single node, such as search cursors, can be sent to the returned address,
+    /// so that they keep reaching the same node after topology changes.
+    pub async fn pin_route(&mut self, route: Route) -> RedisResult<SingleNodeRoutingInfo> {
+        let address = self
+            .route_operation_request(Operation::GetNodeAddress(route))
+            .await?;
+        let address = String::from_owned_redis_value(address)?;
+        let (host, port) = get_host_and_port_from_addr(&address).ok_or_else(|| {
+            RedisError::from((
+                ErrorKind::ClientError,
+                "Invalid node address",
+                address.clone(),
+            ))
+        })?;
+        Ok(SingleNodeRoutingInfo::ByAddress {
+            host: host.to_string(),
+            port,
+        })
+    }
+
+    /// Create a new connection to the node serving `route`, which isn't shared with any other request.
+    /// It's authenticated and configured like the connections of the cluster, but it isn't reconnected or refreshed on topology changes,
+    /// so it's meant for short-lived exclusive usage, such as `WATCH`-based transactions.
+    pub async fn create_dedicated_connection(&mut self, route: Route) -> RedisResult<C> {
+        let (connection_sender, connection_receiver) = oneshot::channel();
+        let (sender, receiver) = oneshot::channel();
+        self.0
+            .send(Message {
+                cmd: CmdArg::DedicatedConnection {
+                    route,
+                    connection_sender: Arc::new(std::sync::Mutex::new(Some(connection_sender))),
+                },
+                sender,
+                deadline: None,
+            })
+            .await
+            .map_err(|_| RedisError::from(io::Error::from(io::ErrorKind::BrokenPipe)))?;
+
+        receiver.await.unwrap_or_else(|err| {
+            Err(RedisError::from(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                err.to_string(),
+            )))
+        })?;
+        connection_receiver
+            .await
+            .map_err(|_| RedisError::from(io::Error::from(io::ErrorKind::BrokenPipe)))
+    }
+
     /// Routes an operation request to the appropriate handler.
     async fn route_operation_request(
         &mut self,
@@ -347,6 +451,7 @@ where
             .send(Message {
                 cmd: CmdArg::OperationRequest(operation_request),
                 sender,
+                deadline: None,
             })
             .await
             .map_err(|_| RedisError::from(io::Error::from(io::ErrorKind::BrokenPipe)))?;
@@ -413,6 +518,10 @@ pub(crate) struct InnerCore<C> {
     subscriptions_by_address: TokioRwLock<HashMap<String, PubSubSubscriptionInfo>>,
     unassigned_subscriptions: TokioRwLock<PubSubSubscriptionInfo>,
     glide_connection_options: GlideConnectionOptions,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    request_hedger: Option<RequestHedger>,
+    adaptive_timeout: Option<Arc<AdaptiveTimeout>>,
+    read_your_writes: Option<Arc<ReadYourWrites>>,
 }
 
 pub(crate) type Core<C> = Arc<InnerCore<C>>;
@@ -649,6 +758,12 @@ enum CmdArg<C> {
     },
     // Operational requests which are connected to the internal state of the connection and not send as a command to the server.
     OperationRequest(Operation),
+    // Creates a new connection to the node serving the route. The connection is sent through `connection_sender`,
+    // which is shared between clones of the request, since the request may be retried.
+    DedicatedConnection {
+        route: Route,
+        connection_sender: Arc<std::sync::Mutex<Option<oneshot::Sender<C>>>>,
+    },
 }
 
 // Operation requests which are connected to the internal state of the connection and not send as a command to the server.
@@ -656,6 +771,16 @@ enum CmdArg<C> {
 enum Operation {
     UpdateConnectionPassword(Option<String>),
     GetUsername,
+    AddPubSubSubscriptions(PubSubSubscriptionKind, Vec<PubSubChannelOrPattern>),
+    RemovePubSubSubscriptions(PubSubSubscriptionKind, Vec<PubSubChannelOrPattern>),
+    GetNodeAddress(Route),
+}
+
+/// Returns true if `cmd` is a write whose replication offset is tracked for read-your-writes.
+fn is_tracked_write(cmd: &Cmd) -> bool {
+    cmd.command().is_some_and(|command| {
+        !is_readonly_cmd(&command) && RoutingInfo::is_key_routing_command(&command)
+    })
 }
 
 fn boxed_sleep(duration: Duration) -> BoxFuture<'static, ()> {
@@ -706,6 +831,7 @@ impl RedirectNode {
 struct Message<C: Sized> {
     cmd: CmdArg<C>,
     sender: oneshot::Sender<RedisResult<Response>>,
+    deadline: Option<Arc<RequestDeadline>>,
 }
 
 enum RecoverFuture {
@@ -735,6 +861,8 @@ impl fmt::Debug for ConnectionState {
 #[derive(Clone)]
 struct RequestInfo<C> {
     cmd: CmdArg<C>,
+    // Internal retries stop once the deadline is too close, and fail with its timeout error.
+    deadline: Option<Arc<RequestDeadline>>,
 }
 
 impl<C> RequestInfo<C> {
@@ -771,6 +899,10 @@ impl<C> RequestInfo<C> {
                 CmdArg::OperationRequest(_) => {
                     unreachable!()
                 }
+                // Dedicated connections are created for the route's node, and aren't redirected.
+                CmdArg::DedicatedConnection { .. } => {
+                    unreachable!()
+                }
             }
         }
     }
@@ -812,6 +944,10 @@ impl<C> RequestInfo<C> {
             CmdArg::OperationRequest { .. } => {
                 unreachable!()
             }
+            // Dedicated connections are created for the route's node, and aren't redirected.
+            CmdArg::DedicatedConnection { .. } => {
+                unreachable!()
+            }
         }
     }
 }
@@ -921,8 +1057,30 @@ impl<C> Future for Request<C> {
             }
             Err((target, err)) => {
                 let request = this.request.as_mut().unwrap();
+                let out_of_retries = request.retry >= this.retry_params.number_of_retries;
+                let sleep_duration = this
+                    .retry_params
+                    .wait_time_for_retry(request.retry.saturating_add(1));
+                if let (false, Some(deadline)) = (out_of_retries, &request.info.deadline) {
+                    // Only retries that wait before resending the request need more than the remaining time.
+                    let wait = if matches!(err.retry_method(), RetryMethod::WaitAndRetry)
+                        || matches!(target, OperationTarget::NotFound)
+                    {
+                        sleep_duration
+                    } else {
+                        Duration::ZERO
+                    };
+                    if Self::is_retried(&target, &err) && deadline.is_too_close(wait) {
+                        let err = deadline.timeout_error();
+                        self.respond(Err(err));
+                        return Next::Done.into();
+                    }
+                }
                 // TODO - would be nice if we didn't need to repeat this code twice, with & without retries.
-                if request.retry >= this.retry_params.number_of_retries {
+                // The retry budget is only charged for retries that are sent before the request's deadline.
+                if out_of_retries
+                    || (Self::is_retried(&target, &err) && !this.retry_params.try_withdraw_retry())
+                {
                     let retry_method = err.retry_method();
                     let next = if err.kind() == ErrorKind::AllConnectionsUnavailable {
                         Next::ReconnectToInitialNodes { request: None }.into()
@@ -969,8 +1127,6 @@ impl<C> Future for Request<C> {
                     .into();
                 }
 
-                let sleep_duration = this.retry_params.wait_time_for_retry(request.retry);
-
                 let address = match target {
                     OperationTarget::Node { address } => address,
                     OperationTarget::FanOut => {
@@ -1024,7 +1180,9 @@ impl<C> Future for Request<C> {
                         .into()
                     }
                     RetryMethod::WaitAndRetry => {
-                        let sleep_duration = this.retry_params.wait_time_for_retry(request.retry);
+                        if let Some(deadline) = &request.info.deadline {
+                            deadline.enter(RequestStage::RetryBackoff);
+                        }
                         // Sleep and retry.
                         this.future.set(RequestState::Sleep {
                             sleep: boxed_sleep(sleep_duration),
@@ -1066,6 +1224,17 @@ impl<C> Future for Request<C> {
 }
 
 impl<C> Request<C> {
+    /// Returns true if a request that failed with `err` is sent again, rather than responded to with the error.
+    fn is_retried(target: &OperationTarget, err: &RedisError) -> bool {
+        !matches!(
+            target,
+            OperationTarget::FanOut | OperationTarget::FatalError
+        ) && !matches!(
+            err.retry_method(),
+            RetryMethod::NoRetry | RetryMethod::Reconnect
+        )
+    }
+
     fn respond(self: Pin<&mut Self>, msg: RedisResult<Response>) {
         // If `send` errors the receiver has dropped and thus does not care about the message
         let _ = self
@@ -1116,6 +1285,7 @@ where
             discover_az,
             connection_timeout: Some(cluster_params.connection_timeout),
             connection_retry_strategy: Some(connection_retry_strategy),
+            retry_budget: cluster_params.retry_params.retry_budget.clone(),
         };
 
         let connections = Self::create_initial_connections(
@@ -1127,12 +1297,17 @@ where
 
         let topology_checks_interval = cluster_params.topology_checks_interval;
         let slots_refresh_rate_limiter = cluster_params.slots_refresh_rate_limit;
+        let read_your_writes = cluster_params
+            .read_your_writes
+            .then(|| Arc::new(ReadYourWrites::new()));
         let inner = Arc::new(InnerCore {
             conn_lock: StdRwLock::new(ConnectionsContainer::new(
                 Default::default(),
                 connections,
                 cluster_params.read_from_replicas.clone(),
                 0,
+                Default::default(),
+                read_your_writes.clone(),
             )),
             cluster_params: StdRwLock::new(cluster_params.clone()),
             pending_requests: Mutex::new(Vec::new()),
@@ -1147,6 +1322,12 @@ where
             ),
             subscriptions_by_address: TokioRwLock::new(Default::default()),
             glide_connection_options,
+            circuit_breaker: cluster_params
+                .circuit_breaker
+                .map(|config| Arc::new(CircuitBreaker::new(config))),
+            request_hedger: cluster_params.request_hedging.map(RequestHedger::new),
+            adaptive_timeout: cluster_params.adaptive_timeout.clone(),
+            read_your_writes,
         });
         let mut connection = ClusterConnInner {
             inner,
@@ -1336,13 +1517,16 @@ where
     // This function serves as a cheap alternative to slot_refresh() and thus can be used much more frequently.
     // The function does not discover the topology from the cluster and assumes the cached topology is valid.
     // In addition, the validation is done by peeking at the state of the underlying transport w/o overhead of additional commands to server.
+    // Only when the lowest latency read strategy is used, PINGs are sent in the background to sample the round-trip time of the nodes.
     async fn validate_all_user_connections(inner: Arc<InnerCore<C>>) {
         let mut all_valid_conns = HashMap::new();
         // prep connections and clean out these w/o assigned slots, as we might have established connections to unwanted hosts
         let mut nodes_to_delete = Vec::new();
         let all_nodes_with_slots: HashSet<Arc<String>>;
+        let latency_tracker;
         {
             let connections_container = inner.conn_lock.read().expect(MUTEX_READ_ERR);
+            latency_tracker = connections_container.latency_tracker().cloned();
 
             all_nodes_with_slots = connections_container.slot_map.all_node_addresses();
 
@@ -1363,15 +1547,39 @@ where
 
         // identify nodes with closed connection
         let mut addrs_to_refresh = HashSet::new();
+        let mut open_conns = Vec::new();
         for (addr, con_fut) in &all_valid_conns {
             let con = con_fut.clone().await;
             // connection object might be present despite the transport being closed
             if con.is_closed() {
                 // transport is closed, need to refresh
                 addrs_to_refresh.insert(addr.clone());
+            } else {
+                open_conns.push((addr.clone(), con));
             }
         }
 
+        // sample the round-trip time of the open connections, to be used by the lowest latency read strategy.
+        // the PINGs are sent in a separate task, so that a slow node doesn't delay the refresh of the closed connections,
+        // and each PING is bounded by the connection timeout.
+        if let (Some(latency_tracker), Ok(ping_timeout)) = (
+            latency_tracker,
+            inner.get_cluster_param(|params| params.connection_timeout),
+        ) {
+            let pings = open_conns.into_iter().map(|(addr, mut con)| {
+                let latency_tracker = latency_tracker.clone();
+                async move {
+                    let start = std::time::Instant::now();
+                    let ping = cmd("PING");
+                    let ping = con.req_packed_command(&ping);
+                    if let Ok(Ok(_)) = tokio::time::timeout(ping_timeout, ping).await {
+                        latency_tracker.record(&addr, start.elapsed());
+                    }
+                }
+            });
+            tokio::spawn(future::join_all(pings));
+        }
+
         // identify missing nodes
         addrs_to_refresh.extend(
             all_nodes_with_slots
@@ -1481,6 +1689,20 @@ where
                 )));
                 let mut first_attempt = true;
                 for backoff_duration in infinite_backoff_iter {
+                    if !first_attempt
+                        && inner_clone
+                            .glide_connection_options
+                            .retry_budget
+                            .as_ref()
+                            .is_some_and(|budget| !budget.try_withdraw())
+                    {
+                        debug!(
+                            "Retry budget exhausted, postponing the reconnect to node {} by {:?}",
+                            address_clone_for_task, backoff_duration
+                        );
+                        tokio::time::sleep(backoff_duration).await;
+                        continue;
+                    }
                     let mut cluster_params = inner_clone
                         .cluster_params
                         .read()
@@ -2162,6 +2384,91 @@ where
         }
     }
 
+    fn check_pubsub_protocol(inner: &InnerCore<C>) -> RedisResult<()> {
+        if inner.cluster_params.read().expect(MUTEX_READ_ERR).protocol
+            != crate::types::ProtocolVersion::RESP3
+        {
+            return Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "PubSub subscriptions require RESP3",
+            )));
+        }
+        Ok(())
+    }
+
+    /// Adds the channels or patterns to the unassigned subscriptions, and assigns them to the nodes owning their slots.
+    async fn add_pubsub_subscriptions(
+        inner: Arc<InnerCore<C>>,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<()> {
+        Self::check_pubsub_protocol(&inner)?;
+        {
+            let subs_by_address_guard = inner.subscriptions_by_address.read().await;
+            let mut unassigned_subs_guard = inner.unassigned_subscriptions.write().await;
+            for channel_pattern in channels_or_patterns {
+                let already_assigned = subs_by_address_guard.values().any(|address_subs| {
+                    address_subs.get(&kind).is_some_and(|channels_patterns| {
+                        channels_patterns.contains(&channel_pattern)
+                    })
+                });
+                if !already_assigned {
+                    unassigned_subs_guard
+                        .entry(kind)
+                        .or_default()
+                        .insert(channel_pattern);
+                }
+            }
+        }
+        Self::refresh_pubsub_subscriptions(inner).await;
+        Ok(())
+    }
+
+    /// Removes the channels or patterns from the desired subscriptions, and resets the connections which were subscribed to them.
+    async fn remove_pubsub_subscriptions(
+        inner: Arc<InnerCore<C>>,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<()> {
+        Self::check_pubsub_protocol(&inner)?;
+        let mut addrs_to_refresh: HashSet<String> = HashSet::new();
+        {
+            let mut subs_by_address_guard = inner.subscriptions_by_address.write().await;
+            let mut unassigned_subs_guard = inner.unassigned_subscriptions.write().await;
+            let conns_read_guard = inner.conn_lock.read().expect(MUTEX_READ_ERR);
+            for channel_pattern in channels_or_patterns.iter() {
+                if let Some(channels_patterns) = unassigned_subs_guard.get_mut(&kind) {
+                    channels_patterns.remove(channel_pattern);
+                }
+                for (address, address_subs) in subs_by_address_guard.iter_mut() {
+                    let removed = address_subs
+                        .get_mut(&kind)
+                        .is_some_and(|channels_patterns| channels_patterns.remove(channel_pattern));
+                    // need to drop the connection for clearing the subscription in the server
+                    if removed && conns_read_guard.connection_for_address(address).is_some() {
+                        addrs_to_refresh.insert(address.clone());
+                    }
+                }
+            }
+            unassigned_subs_guard.retain(|_, channels_patterns| !channels_patterns.is_empty());
+            subs_by_address_guard.retain(|_, address_subs| {
+                address_subs.retain(|_, channels_patterns| !channels_patterns.is_empty());
+                !address_subs.is_empty()
+            });
+        }
+
+        if !addrs_to_refresh.is_empty() {
+            Self::refresh_and_update_connections(
+                inner,
+                addrs_to_refresh,
+                RefreshConnectionType::AllConnections,
+                false,
+            )
+            .await;
+        }
+        Ok(())
+    }
+
     /// Queries log2n nodes (where n represents the number of cluster nodes) to determine whether their
     /// topology view differs from the one currently stored in the connection manager.
     /// Returns true if change was detected, otherwise false.
@@ -2293,21 +2600,58 @@ where
         info!("refresh_slots found nodes:\n{new_connections}");
         // Reset the current slot map and connection vector with the new ones
         let mut write_guard = inner.conn_lock.write().expect(MUTEX_WRITE_ERR);
+        let topology_changed = write_guard.get_current_topology_hash() != topology_hash;
         // Clear the refresh tasks of the prev instance
         // TODO - Maybe we can take the running refresh tasks and use them instead of running new connection creation
         write_guard.refresh_conn_state.clear_refresh_state();
         let read_from_replicas = inner
             .get_cluster_param(|params| params.read_from_replicas.clone())
             .expect(MUTEX_READ_ERR);
+        // Keep the latency samples of nodes that are still part of the topology
+        let latency_tracker = write_guard.latency_tracker.clone();
+        latency_tracker.retain(|address| new_connections.0.contains_key(address));
+        if let Some(circuit_breaker) = &inner.circuit_breaker {
+            circuit_breaker.retain(|address| new_connections.0.contains_key(address));
+        }
+        if let Some(adaptive_timeout) = &inner.adaptive_timeout {
+            adaptive_timeout.retain(|address| new_connections.0.contains_key(address));
+        }
+        if let Some(read_your_writes) = &inner.read_your_writes {
+            read_your_writes.retain(|address| new_connections.0.contains_key(address));
+        }
         *write_guard = ConnectionsContainer::new(
             new_slots,
             new_connections,
             read_from_replicas,
             topology_hash,
+            latency_tracker,
+            inner.read_your_writes.clone(),
         );
+        drop(write_guard);
+        if topology_changed {
+            Self::notify_topology_change(&inner);
+        }
         Ok(())
     }
 
+    /// When client tracking is enabled, sends an invalidation of all keys to the push sender.
+    /// After a topology change, keys may be served by nodes that don't track them for this client,
+    /// so locally cached values can no longer be trusted.
+    fn notify_topology_change(inner: &InnerCore<C>) {
+        let tracking_enabled = inner
+            .get_cluster_param(|params| params.client_tracking.is_some())
+            .expect(MUTEX_READ_ERR);
+        if !tracking_enabled {
+            return;
+        }
+        if let Some(push_sender) = &inner.glide_connection_options.push_sender {
+            let _ = push_sender.send(PushInfo {
+                kind: PushKind::Invalidate,
+                data: vec![Value::Nil],
+            });
+        }
+    }
+
     /// Handles MOVED errors by updating the client's slot and node mappings based on the new primary's role:
     ///
     /// 1. **No Change**: If the new primary is already the current slot owner, no updates are needed.
@@ -2345,13 +2689,18 @@ where
             match curr_shard_addrs.attempt_shard_role_update(new_primary.clone()) {
                 // Scenario 1: No changes needed as the new primary is already the current slot owner.
                 // Scenario 2: Failover occurred and the new primary was promoted from a replica.
-                ShardUpdateResult::AlreadyPrimary | ShardUpdateResult::Promoted => return Ok(()),
+                ShardUpdateResult::AlreadyPrimary => return Ok(()),
+                ShardUpdateResult::Promoted => {
+                    Self::notify_topology_change(&inner);
+                    return Ok(());
+                }
                 // The node was not found in this shard, proceed with further scenarios.
                 ShardUpdateResult::NodeNotFound => {}
             }
         }
 
         // Scenario 3 & 4: Check if the new primary exists in other shards
+        Self::notify_topology_change(&inner);
 
         let mut wlock_conn_container = inner.conn_lock.write().expect(MUTEX_READ_ERR);
         let mut nodes_iter = wlock_conn_container.slot_map_nodes();
@@ -2424,6 +2773,7 @@ where
                                         }
                                         .into(),
                                     },
+                                    deadline: None,
                                 },
                             }),
                         )
@@ -2511,13 +2861,145 @@ where
         };
         trace!("route request to single node");
 
+        // Reads that are routed to replicas may be hedged to another node of the slot.
+        let hedge_route = match &routing {
+            InternalSingleNodeRouting::SpecificNode(route)
+                if core.request_hedger.is_some()
+                    && route.slot_addr() != SlotAddr::Master
+                    && cmd
+                        .command()
+                        .is_some_and(|command| is_readonly_cmd(&command))
+                    && RequestHedger::applies_to(&cmd) =>
+            {
+                Some(*route)
+            }
+            _ => None,
+        };
+
         // if we reached this point, we're sending the command only to single node, and we need to find the
         // right connection to the node.
-        let (address, mut conn) = Self::get_connection(routing, core, Some(cmd.clone()))
+        let latency_tracker = core
+            .conn_lock
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .latency_tracker()
+            .cloned();
+        let (address, conn) = Self::get_connection(routing, core.clone(), Some(cmd.clone()))
             .await
             .map_err(|err| (OperationTarget::NotFound, err))?;
-        conn.req_packed_command(&cmd)
-            .await
+        // Writes are tracked on the connection they were sent on, so that the offset covers them.
+        let tracked_write = core
+            .read_your_writes
+            .clone()
+            .filter(|_| is_tracked_write(&cmd))
+            .map(|read_your_writes| (read_your_writes, conn.clone()));
+        let request = Self::send_cmd_to_node(
+            &cmd,
+            address.clone(),
+            conn,
+            latency_tracker.clone(),
+            core.circuit_breaker.clone(),
+            core.adaptive_timeout.clone(),
+        );
+        let result = match (&core.request_hedger, hedge_route) {
+            (Some(request_hedger), Some(hedge_route)) => {
+                request_hedger
+                    .run(request, || {
+                        let (hedge_address, hedge_conn) = core
+                            .conn_lock
+                            .read()
+                            .expect(MUTEX_READ_ERR)
+                            .hedge_connection_for_route(&hedge_route, &address)?;
+                        let circuit_breaker = core.circuit_breaker.clone();
+                        let adaptive_timeout = core.adaptive_timeout.clone();
+                        let cmd = &cmd;
+                        Some(async move {
+                            Self::send_cmd_to_node(
+                                cmd,
+                                hedge_address,
+                                hedge_conn.await,
+                                latency_tracker,
+                                circuit_breaker,
+                                adaptive_timeout,
+                            )
+                            .await
+                        })
+                    })
+                    .await
+            }
+            _ => request.await,
+        };
+        if let (Ok(_), Some((read_your_writes, conn))) = (&result, tracked_write) {
+            Self::record_write(&core, read_your_writes, address, conn).await;
+        }
+        result
+    }
+
+    /// Records the replication offset of a primary after a write to it,
+    /// and starts polling its replicas if they aren't polled already.
+    async fn record_write(
+        core: &Core<C>,
+        read_your_writes: Arc<ReadYourWrites>,
+        address: String,
+        mut conn: C,
+    ) {
+        if !core
+            .conn_lock
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .is_primary(&address)
+        {
+            return;
+        }
+        match read_your_writes.record_write(&address, &mut conn).await {
+            Ok(true) => {
+                let replicas = core
+                    .conn_lock
+                    .read()
+                    .expect(MUTEX_READ_ERR)
+                    .replica_connections(&address);
+                tokio::spawn(async move {
+                    let mut connections = Vec::with_capacity(replicas.len());
+                    for (replica, conn) in replicas {
+                        connections.push((replica, conn.await));
+                    }
+                    read_your_writes.poll_replicas(&address, connections).await;
+                });
+            }
+            Ok(false) => {}
+            Err(err) => warn!("Failed to record the replication offset of {address}: {err}"),
+        }
+    }
+
+    async fn send_cmd_to_node(
+        cmd: &Cmd,
+        address: String,
+        mut conn: C,
+        latency_tracker: Option<Arc<LatencyTracker>>,
+        circuit_breaker: Option<Arc<CircuitBreaker>>,
+        adaptive_timeout: Option<Arc<AdaptiveTimeout>>,
+    ) -> OperationResult {
+        let permit = circuit_breaker
+            .as_deref()
+            .map(|circuit_breaker| circuit_breaker.try_acquire(&address))
+            .transpose()
+            .map_err(|err| (OperationTarget::FatalError, err))?;
+        let start = std::time::Instant::now();
+        let result = conn.req_packed_command(cmd).await;
+        if let Some(permit) = permit {
+            permit.record(&result);
+        }
+        // Blocking and known-slow commands don't reflect the node's round-trip time.
+        if result.is_ok() && AdaptiveTimeout::applies_to(cmd) {
+            let latency = start.elapsed();
+            if let Some(latency_tracker) = latency_tracker {
+                latency_tracker.record(&address, latency);
+            }
+            if let Some(adaptive_timeout) = adaptive_timeout {
+                adaptive_timeout.record(&address, latency);
+            }
+        }
+        result
             .map(Response::Single)
             .map_err(|err| (address.into(), err))
     }
@@ -2527,16 +3009,40 @@ where
         offset: usize,
         count: usize,
         conn: impl Future<Output = RedisResult<(String, C)>>,
+        core: Core<C>,
     ) -> OperationResult {
         trace!("try_pipeline_request");
         let (address, mut conn) = conn.await.map_err(|err| (OperationTarget::NotFound, err))?;
-        conn.req_packed_commands(&pipeline, offset, count, None)
-            .await
+        let permit = core
+            .circuit_breaker
+            .as_deref()
+            .map(|circuit_breaker| circuit_breaker.try_acquire(&address))
+            .transpose()
+            .map_err(|err| (OperationTarget::FatalError, err))?;
+        // Writes in pipelines and transactions are tracked like single writes, so that the reads after them see them.
+        let tracked_write = core
+            .read_your_writes
+            .clone()
+            .filter(|_| pipeline.cmd_iter().any(|cmd| is_tracked_write(cmd)))
+            .map(|read_your_writes| (read_your_writes, conn.clone()));
+        let result = conn
+            .req_packed_commands(&pipeline, offset, count, None)
+            .await;
+        if let Some(permit) = permit {
+            permit.record(&result);
+        }
+        if let (Ok(_), Some((read_your_writes, conn))) = (&result, tracked_write) {
+            Self::record_write(&core, read_your_writes, address.clone(), conn).await;
+        }
+        result
             .map(Response::Multiple)
             .map_err(|err| (OperationTarget::Node { address }, err))
     }
 
     async fn try_request(info: RequestInfo<C>, core: Core<C>) -> OperationResult {
+        if let Some(deadline) = &info.deadline {
+            deadline.enter(RequestStage::Server);
+        }
         match info.cmd {
             CmdArg::Cmd { cmd, routing } => Self::try_cmd_request(cmd, routing, core).await,
             CmdArg::Pipeline {
@@ -2555,9 +3061,10 @@ where
                         count,
                         Self::get_connection(
                             route.unwrap_or(InternalSingleNodeRouting::Random),
-                            core,
+                            core.clone(),
                             None,
                         ),
+                        core,
                     )
                     .await
                 } else {
@@ -2602,7 +3109,72 @@ where
                     };
                     Ok(Response::Single(username))
                 }
+                Operation::AddPubSubSubscriptions(kind, channels_or_patterns) => {
+                    Self::add_pubsub_subscriptions(core, kind, channels_or_patterns)
+                        .await
+                        .map(|_| Response::Single(Value::Okay))
+                        .map_err(|err| (OperationTarget::FatalError, err))
+                }
+                Operation::RemovePubSubSubscriptions(kind, channels_or_patterns) => {
+                    Self::remove_pubsub_subscriptions(core, kind, channels_or_patterns)
+                        .await
+                        .map(|_| Response::Single(Value::Okay))
+                        .map_err(|err| (OperationTarget::FatalError, err))
+                }
+                Operation::GetNodeAddress(route) => core
+                    .conn_lock
+                    .read()
+                    .expect(MUTEX_READ_ERR)
+                    .connection_for_route(&route)
+                    .map(|(address, _)| Response::Single(Value::BulkString(address.into())))
+                    .ok_or_else(|| {
+                        (
+                            OperationTarget::NotFound,
+                            RedisError::from((
+                                ErrorKind::ConnectionNotFoundForRoute,
+                                "Requested connection not found for route",
+                                format!("{route:?}"),
+                            )),
+                        )
+                    }),
             },
+            CmdArg::DedicatedConnection {
+                route,
+                connection_sender,
+            } => {
+                let address = core
+                    .conn_lock
+                    .read()
+                    .expect(MUTEX_READ_ERR)
+                    .connection_for_route(&route)
+                    .map(|(address, _)| address)
+                    .ok_or_else(|| {
+                        (
+                            OperationTarget::NotFound,
+                            RedisError::from((
+                                ErrorKind::ConnectionNotFoundForRoute,
+                                "Requested connection not found for route",
+                                format!("{route:?}"),
+                            )),
+                        )
+                    })?;
+                let params = core
+                    .get_cluster_param(|params| params.clone())
+                    .map_err(|err| (OperationTarget::FatalError, err))?;
+                let connection = create_dedicated_connection(
+                    &address,
+                    params,
+                    core.glide_connection_options.clone(),
+                )
+                .await
+                .map_err(|err| (OperationTarget::FatalError, err))?;
+                if let Some(connection_sender) =
+                    connection_sender.lock().expect(MUTEX_WRITE_ERR).take()
+                {
+                    let _ = connection_sender.send(connection);
+                }
+                Ok(Response::Single(Value::Okay))
+            }
         }
     }
 
@@ -3061,7 +3633,15 @@ where
         retry: u32,
         retry_params: RetryParams,
     ) -> OperationResult {
-        Self::handle_loading_error(core.clone(), address, retry, retry_params).await;
+        Self::handle_loading_error(
+            core.clone(),
+            address,
+            retry,
+            retry_params,
+            info.deadline.as_deref(),
+        )
+        .await
+        .map_err(|err| (OperationTarget::FatalError, err))?;
         Self::try_request(info, core).await
     }
 
@@ -3070,7 +3650,8 @@ where
         address: String,
         retry: u32,
         retry_params: RetryParams,
-    ) {
+        deadline: Option<&RequestDeadline>,
+    ) -> RedisResult<()> {
         let is_primary = core
             .conn_lock
             .read()
@@ -3087,8 +3668,15 @@ where
         } else {
             // If the connection is primary, just sleep and retry
             let sleep_duration = retry_params.wait_time_for_retry(retry);
+            if let Some(deadline) = deadline {
+                if deadline.is_too_close(sleep_duration) {
+                    return Err(deadline.timeout_error());
+                }
+                deadline.enter(RequestStage::RetryBackoff);
+            }
             boxed_sleep(sleep_duration).await;
         }
+        Ok(())
     }
 
     fn poll_complete(&mut self, cx: &mut task::Context<'_>) -> Poll<PollFlushAction> {
@@ -3107,6 +3695,19 @@ where
                 if request.sender.is_closed() {
                     continue;
                 }
+                // Requests that waited for a reconnect past their deadline aren't sent.
+                if let Some(deadline) = request
+                    .info
+                    .deadline
+                    .as_ref()
+                    .filter(|deadline| deadline.is_too_close(Duration::ZERO))
+                {
+                    let _ = request.sender.send(Err(deadline.timeout_error()));
+                    continue;
+                }
+                if request.retry == 0 {
+                    retry_params.record_request();
+                }
 
                 let future = Self::try_request(request.info.clone(), self.inner.clone()).boxed();
                 self.in_flight_requests.push(Box::pin(Request {
@@ -3161,6 +3762,10 @@ where
                 } => {
                     poll_flush_action =
                         poll_flush_action.change_state(PollFlushAction::RebuildSlots);
+                    if let Some(deadline) = request.as_ref().and_then(|r| r.info.deadline.as_ref())
+                    {
+                        deadline.enter(RequestStage::Topology);
+                    }
                     let future: Option<
                         RequestState<Pin<Box<dyn Future<Output = OperationResult> + Send>>>,
                     > = if let Some(moved_redirect) = moved_redirect {
@@ -3198,6 +3803,9 @@ where
                     poll_flush_action = poll_flush_action
                         .change_state(PollFlushAction::Reconnect(HashSet::from_iter([target])));
                     if let Some(request) = request {
+                        if let Some(deadline) = &request.info.deadline {
+                            deadline.enter(RequestStage::Reconnect);
+                        }
                         self.inner.pending_requests.lock().unwrap().push(request);
                     }
                 }
@@ -3205,6 +3813,9 @@ where
                     poll_flush_action = poll_flush_action
                         .change_state(PollFlushAction::ReconnectFromInitialConnections);
                     if let Some(request) = request {
+                        if let Some(deadline) = &request.info.deadline {
+                            deadline.enter(RequestStage::Reconnect);
+                        }
                         self.inner.pending_requests.lock().unwrap().push(request);
                     }
                 }
@@ -3278,9 +3889,13 @@ where
     }
 
     fn start_send(self: Pin<&mut Self>, msg: Message<C>) -> Result<(), Self::Error> {
-        let Message { cmd, sender } = msg;
+        let Message {
+            cmd,
+            sender,
+            deadline,
+        } = msg;
 
-        let info = RequestInfo { cmd };
+        let info = RequestInfo { cmd, deadline };
 
         self.inner
             .pending_requests
@@ -455,6 +455,7 @@ where
                     sub_pipeline: true,
                     pipeline_retry_strategy,
                 },
+                deadline: None,
             },
         });
         // Record the node address and its associated command indices for result mapping
@@ -786,7 +787,10 @@ where
         ) {
             Ok(retry_map) => {
                 // If there are no retirable errors, or we have reached the maximum number of retries, we're done
-                if retry_map.is_empty() || retry >= retry_params.number_of_retries {
+                if retry_map.is_empty()
+                    || retry >= retry_params.number_of_retries
+                    || !retry_params.try_withdraw_retry()
+                {
                     return Ok(pipeline_responses);
                 }
 
@@ -1014,6 +1018,7 @@ where
                     address,
                     retry,
                     retry_params.clone(),
+                    None,
                 )
             });
 
@@ -6,16 +6,19 @@ use crate::cluster_topology::{
 use crate::connection::{ConnectionAddr, ConnectionInfo, IntoConnectionInfo};
 use crate::types::{ErrorKind, ProtocolVersion, RedisError, RedisResult};
 use crate::{cluster, cluster::TlsMode};
-use crate::{PubSubSubscriptionInfo, PushInfo, RetryStrategy};
+use crate::{ClientTrackingMode, PubSubSubscriptionInfo, PushInfo, RetryBudget, RetryStrategy};
 use rand::Rng;
 #[cfg(feature = "cluster-async")]
 use std::ops::Add;
+use std::sync::Arc;
 use std::time::Duration;
 
 use crate::tls::TlsConnParams;
 
 #[cfg(feature = "cluster-async")]
 use crate::cluster_async;
+#[cfg(feature = "cluster-async")]
+use crate::{AdaptiveTimeout, CircuitBreakerConfig, RequestHedgingConfig};
 
 use crate::tls::{retrieve_tls_certificates, TlsCertificates};
 
@@ -43,7 +46,16 @@ struct BuilderParams {
     response_timeout: Option<Duration>,
     protocol: ProtocolVersion,
     pubsub_subscriptions: Option<PubSubSubscriptionInfo>,
+    client_tracking: Option<ClientTrackingMode>,
     reconnect_retry_strategy: Option<RetryStrategy>,
+    #[cfg(feature = "cluster-async")]
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    #[cfg(feature = "cluster-async")]
+    request_hedging: Option<RequestHedgingConfig>,
+    #[cfg(feature = "cluster-async")]
+    adaptive_timeout: Option<Arc<AdaptiveTimeout>>,
+    #[cfg(feature = "cluster-async")]
+    read_your_writes: bool,
 }
 
 #[derive(Clone)]
@@ -53,6 +65,7 @@ pub(crate) struct RetryParams {
     min_wait_time: u64,
     exponent_base: u64,
     factor: u64,
+    pub(crate) retry_budget: Option<Arc<RetryBudget>>,
 }
 
 impl Default for RetryParams {
@@ -68,6 +81,7 @@ impl Default for RetryParams {
             min_wait_time: DEFAULT_MIN_RETRY_WAIT_TIME,
             exponent_base: DEFAULT_EXPONENT_BASE,
             factor: DEFAULT_FACTOR,
+            retry_budget: None,
         }
     }
 }
@@ -81,6 +95,20 @@ impl RetryParams {
         let jittered_wait = rand::rng().random_range(self.min_wait_time..clamped_wait);
         Duration::from_millis(jittered_wait)
     }
+
+    /// Adds a new request to the retry budget, if one is set.
+    pub(crate) fn record_request(&self) {
+        if let Some(budget) = &self.retry_budget {
+            budget.record_request();
+        }
+    }
+
+    /// Consumes a retry from the retry budget, if one is set. Returns false if the request shouldn't be retried.
+    pub(crate) fn try_withdraw_retry(&self) -> bool {
+        self.retry_budget
+            .as_ref()
+            .map_or(true, |budget| budget.try_withdraw())
+    }
 }
 
 /// Configuration for rate limiting slot refresh operations in a Redis cluster.
@@ -143,7 +171,16 @@ pub struct ClusterParams {
     pub(crate) response_timeout: Duration,
     pub(crate) protocol: ProtocolVersion,
     pub(crate) pubsub_subscriptions: Option<PubSubSubscriptionInfo>,
+    pub(crate) client_tracking: Option<ClientTrackingMode>,
     pub(crate) reconnect_retry_strategy: Option<RetryStrategy>,
+    #[cfg(feature = "cluster-async")]
+    pub(crate) circuit_breaker: Option<CircuitBreakerConfig>,
+    #[cfg(feature = "cluster-async")]
+    pub(crate) request_hedging: Option<RequestHedgingConfig>,
+    #[cfg(feature = "cluster-async")]
+    pub(crate) adaptive_timeout: Option<Arc<AdaptiveTimeout>>,
+    #[cfg(feature = "cluster-async")]
+    pub(crate) read_your_writes: bool,
 }
 
 impl ClusterParams {
@@ -172,7 +209,16 @@ impl ClusterParams {
             response_timeout: value.response_timeout.unwrap_or(Duration::MAX),
             protocol: value.protocol,
             pubsub_subscriptions: value.pubsub_subscriptions,
+            client_tracking: value.client_tracking,
             reconnect_retry_strategy: value.reconnect_retry_strategy,
+            #[cfg(feature = "cluster-async")]
+            circuit_breaker: value.circuit_breaker,
+            #[cfg(feature = "cluster-async")]
+            request_hedging: value.request_hedging,
+            #[cfg(feature = "cluster-async")]
+            adaptive_timeout: value.adaptive_timeout,
+            #[cfg(feature = "cluster-async")]
+            read_your_writes: value.read_your_writes,
         })
     }
 }
@@ -340,6 +386,15 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Sets a retry budget that caps the retries of the new ClusterClient.
+    ///
+    /// Each request of the client is added to the budget, and each retry consumes from it.
+    /// When the budget is exhausted, failed requests return their error instead of being retried.
+    pub fn retry_budget(mut self, retry_budget: Arc<RetryBudget>) -> ClusterClientBuilder {
+        self.builder_params.retries_configuration.retry_budget = Some(retry_budget);
+        self
+    }
+
     /// Sets TLS mode for the new ClusterClient.
     ///
     /// It is extracted from the first node of initial_nodes if not set.
@@ -407,6 +462,52 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Enables a circuit breaker per node for this client.
+    ///
+    /// Once a node fails `failure_threshold` consecutive requests with I/O errors or timeouts,
+    /// requests routed to it fail immediately with [`ErrorKind::CircuitOpen`],
+    /// until a probe request sent after `open_duration` succeeds.
+    #[cfg(feature = "cluster-async")]
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> ClusterClientBuilder {
+        self.builder_params.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Enables hedging of reads that are routed to replicas.
+    ///
+    /// A read that didn't complete within the configured delay is also sent to another replica of the slot,
+    /// or to its primary, and the first successful reply is returned.
+    /// Has no effect unless reads are routed to replicas by the read from strategy.
+    #[cfg(feature = "cluster-async")]
+    pub fn request_hedging(mut self, config: RequestHedgingConfig) -> ClusterClientBuilder {
+        self.builder_params.request_hedging = Some(config);
+        self
+    }
+
+    /// Records the latency of requests to each node in `adaptive_timeout`,
+    /// from which the caller derives the request timeout.
+    #[cfg(feature = "cluster-async")]
+    pub fn adaptive_timeout(
+        mut self,
+        adaptive_timeout: Arc<AdaptiveTimeout>,
+    ) -> ClusterClientBuilder {
+        self.builder_params.adaptive_timeout = Some(adaptive_timeout);
+        self
+    }
+
+    /// Enables session consistency for reads from replicas.
+    ///
+    /// After each write, the client queries the replication offset of the primary with an additional `INFO REPLICATION` round trip,
+    /// and sends reads only to the replicas that reached it, falling back to the primary otherwise.
+    /// If the offset can't be queried, reads are sent to the primary until the next write to it is tracked.
+    /// Writes to multiple slots, such as a multi-slot `MSET` or `DEL`, and writes that are sent in pipelines and transactions aren't tracked.
+    /// Has no effect unless reads are routed to replicas by the read from strategy.
+    #[cfg(feature = "cluster-async")]
+    pub fn read_your_writes(mut self) -> ClusterClientBuilder {
+        self.builder_params.read_your_writes = true;
+        self
+    }
+
     /// Enables periodic topology checks for this client.
     ///
     /// If enabled, periodic topology checks will be executed at the configured intervals to examine whether there
@@ -514,6 +615,14 @@ impl ClusterClientBuilder {
         self.builder_params.pubsub_subscriptions = Some(pubsub_subscriptions);
         self
     }
+
+    /// Enables client tracking on all connections of the new ClusterClient. Requires RESP3.
+    ///
+    /// Invalidation messages are sent to the push sender, and an invalidation of all keys is sent when the topology changes.
+    pub fn client_tracking(mut self, client_tracking: ClientTrackingMode) -> ClusterClientBuilder {
+        self.builder_params.client_tracking = Some(client_tracking);
+        self
+    }
 }
 
 /// This is a Redis Cluster client.
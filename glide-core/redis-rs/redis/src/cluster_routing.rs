@@ -675,7 +675,9 @@ fn base_routing(cmd: &[u8]) -> RouteBy {
         | b"OBJECT FREQ"
         | b"OBJECT IDLETIME"
         | b"OBJECT REFCOUNT"
-        | b"JSON.DEBUG" => RouteBy::SecondArg,
+        | b"JSON.DEBUG"
+        | b"FT.CURSOR DEL"
+        | b"FT.CURSOR READ" => RouteBy::SecondArg,
 
         b"LMPOP" | b"SINTERCARD" | b"ZDIFF" | b"ZINTER" | b"ZINTERCARD" | b"ZMPOP" | b"ZUNION" => {
             RouteBy::SecondArgAfterKeyCount
@@ -867,6 +869,122 @@ impl RoutingInfo {
     }
 }
 
+/// Returns the number of arguments in `r`, including the command name.
+fn arg_count<R>(r: &R) -> usize
+where
+    R: Routable + ?Sized,
+{
+    (0..).take_while(|idx| r.arg_idx(*idx).is_some()).count()
+}
+
+/// Returns the indices of the `key_count` keys that start at `first_key_index`, where `key_count` is the argument at `key_count_index`.
+fn counted_key_indices<R>(r: &R, key_count_index: usize, first_key_index: usize) -> Vec<usize>
+where
+    R: Routable + ?Sized,
+{
+    let key_count = r
+        .arg_idx(key_count_index)
+        .and_then(|x| std::str::from_utf8(x).ok())
+        .and_then(|x| x.parse::<usize>().ok())
+        .unwrap_or_default();
+    (first_key_index..arg_count(r)).take(key_count).collect()
+}
+
+/// Returns the indices of the key arguments of `r`, located by the same rules that are used to route it.
+///
+/// Commands that are routed by their first key, but have more keys, such as `RENAME` or `SINTERSTORE`, return all of their keys.
+/// Keys that can't be located by their position, such as the destination of `SORT ... STORE`, are only returned for the known commands.
+pub fn key_arg_indices<R>(r: &R) -> Vec<usize>
+where
+    R: Routable + ?Sized,
+{
+    let Some(cmd) = r.command() else {
+        return Vec::new();
+    };
+    let cmd = &cmd[..];
+    let count = arg_count(r);
+    match base_routing(cmd) {
+        RouteBy::MultiShard(MultiSlotArgPattern::KeysOnly) => (1..count).collect(),
+        RouteBy::MultiShard(MultiSlotArgPattern::KeyValuePairs) => (1..count).step_by(2).collect(),
+        RouteBy::MultiShard(MultiSlotArgPattern::KeysAndLastArg) => {
+            (1..count.saturating_sub(1)).collect()
+        }
+        RouteBy::MultiShard(MultiSlotArgPattern::KeyWithTwoArgTriples) => {
+            (1..count).step_by(3).collect()
+        }
+        RouteBy::ThirdArgAfterKeyCount => counted_key_indices(r, 2, 3),
+        RouteBy::SecondArgAfterKeyCount => counted_key_indices(r, 1, 2),
+        RouteBy::SecondArg if cmd == b"BITOP" => (2..count).collect(),
+        // Search cursors are routed by their index name, which isn't a key.
+        RouteBy::SecondArg if cmd.starts_with(b"FT.") => Vec::new(),
+        RouteBy::SecondArg if count > 2 => vec![2],
+        RouteBy::StreamsIndex => match r.position(b"STREAMS") {
+            // The keys are followed by an ID for each key.
+            Some(streams_position) => {
+                let first_key_index = streams_position + 1;
+                let key_count = count.saturating_sub(first_key_index) / 2;
+                (first_key_index..first_key_index + key_count).collect()
+            }
+            None => Vec::new(),
+        },
+        RouteBy::FirstKey => first_key_command_key_indices(r, cmd, count),
+        RouteBy::SecondArg
+        | RouteBy::AllNodes
+        | RouteBy::AllPrimaries
+        | RouteBy::Random
+        | RouteBy::SecondArgSlot
+        | RouteBy::Undefined => Vec::new(),
+    }
+}
+
+fn first_key_command_key_indices<R>(r: &R, cmd: &[u8], count: usize) -> Vec<usize>
+where
+    R: Routable + ?Sized,
+{
+    // Container commands that aren't known to have a key, such as `CLIENT NO-EVICT`, have the subcommand as their first argument.
+    if cmd.contains(&b' ') || cmd.starts_with(b"FT.") {
+        return Vec::new();
+    }
+    match cmd {
+        // keyless commands whose first argument is routed as a key.
+        b"PUBLISH" | b"SPUBLISH" | b"SUBSCRIBE" | b"SSUBSCRIBE" | b"PSUBSCRIBE"
+        | b"UNSUBSCRIBE" | b"SUNSUBSCRIBE" | b"PUNSUBSCRIBE" | b"SELECT" | b"SWAPDB" | b"HELLO"
+        | b"MIGRATE" | b"FAILOVER" | b"PSYNC" | b"REPLCONF" => Vec::new(),
+
+        b"COPY" | b"RENAME" | b"RENAMENX" | b"SMOVE" | b"LMOVE" | b"BLMOVE" | b"RPOPLPUSH"
+        | b"BRPOPLPUSH" | b"LCS" | b"GEOSEARCHSTORE" | b"ZRANGESTORE" => {
+            (1..count.min(3)).collect()
+        }
+
+        b"SDIFF" | b"SDIFFSTORE" | b"SINTER" | b"SINTERSTORE" | b"SUNION" | b"SUNIONSTORE"
+        | b"PFCOUNT" | b"PFMERGE" => (1..count).collect(),
+
+        // The last argument is the timeout.
+        b"BLPOP" | b"BRPOP" | b"BZPOPMIN" | b"BZPOPMAX" => (1..count.saturating_sub(1)).collect(),
+
+        b"MSETNX" => (1..count).step_by(2).collect(),
+
+        b"ZDIFFSTORE" | b"ZINTERSTORE" | b"ZUNIONSTORE" => {
+            let mut indices = vec![1];
+            indices.extend(counted_key_indices(r, 2, 3));
+            indices
+        }
+
+        b"SORT" | b"SORT_RO" => {
+            let mut indices = vec![1];
+            indices.extend(
+                r.position(b"STORE")
+                    .map(|store_position| store_position + 1)
+                    .filter(|destination_index| *destination_index < count),
+            );
+            indices
+        }
+
+        _ if count > 1 => vec![1],
+        _ => Vec::new(),
+    }
+}
+
 /// Returns true if the given `routable` represents a readonly command.
 pub fn is_readonly(routable: &impl Routable) -> bool {
     match routable.command() {
@@ -893,6 +1011,10 @@ pub fn is_readonly_cmd(cmd: &[u8]) -> bool {
             | b"ACL USERS"
             | b"ACL WHOAMI"
             | b"AUTH"
+            | b"BF.CARD"
+            | b"BF.EXISTS"
+            | b"BF.INFO"
+            | b"BF.MEXISTS"
             | b"BGREWRITEAOF"
             | b"BGSAVE"
             | b"BITCOUNT"
@@ -953,6 +1075,7 @@ pub fn is_readonly_cmd(cmd: &[u8]) -> bool {
             | b"EXPIRETIME"
             | b"FCALL_RO"
             | b"FT.AGGREGATE"
+            | b"FT.CURSOR READ"
             | b"FT.EXPLAIN"
             | b"FT.EXPLAINCLI"
             | b"FT.INFO"
@@ -976,14 +1099,18 @@ pub fn is_readonly_cmd(cmd: &[u8]) -> bool {
             | b"GETRANGE"
             | b"HELLO"
             | b"HEXISTS"
+            | b"HEXPIRETIME"
             | b"HGET"
             | b"HGETALL"
             | b"HKEYS"
             | b"HLEN"
             | b"HMGET"
+            | b"HPEXPIRETIME"
+            | b"HPTTL"
             | b"HRANDFIELD"
             | b"HSCAN"
             | b"HSTRLEN"
+            | b"HTTL"
             | b"HVALS"
             | b"JSON.ARRINDEX"
             | b"JSON.ARRLEN"
@@ -1119,7 +1246,7 @@ pub trait Routable {
         let mut primary_command = match primary_command.as_slice() {
             b"XGROUP" | b"OBJECT" | b"SLOWLOG" | b"FUNCTION" | b"MODULE" | b"COMMAND"
             | b"PUBSUB" | b"CONFIG" | b"MEMORY" | b"XINFO" | b"CLIENT" | b"ACL" | b"SCRIPT"
-            | b"CLUSTER" | b"LATENCY" => primary_command,
+            | b"CLUSTER" | b"LATENCY" | b"FT.CURSOR" => primary_command,
             _ => {
                 return Some(primary_command);
             }
@@ -1429,8 +1556,9 @@ fn random_slot() -> u16 {
 #[cfg(test)]
 mod tests_routing {
     use super::{
-        command_for_multi_slot_indices, AggregateOp, MultiSlotArgPattern, MultipleNodeRoutingInfo,
-        ResponsePolicy, Route, RoutingInfo, ShardAddrs, SingleNodeRoutingInfo, SlotAddr,
+        command_for_multi_slot_indices, key_arg_indices, AggregateOp, MultiSlotArgPattern,
+        MultipleNodeRoutingInfo, ResponsePolicy, Route, RoutingInfo, ShardAddrs,
+        SingleNodeRoutingInfo, SlotAddr,
     };
     use crate::cluster_routing::ShardUpdateResult;
     use crate::{cluster_topology::slot, cmd, parser::parse_redis_value, Value};
@@ -1650,6 +1778,102 @@ mod tests_routing {
                     )),
                 )),
             ),
+            (
+                cmd("HTTL")
+                    .arg("session")
+                    .arg("FIELDS")
+                    .arg("1")
+                    .arg("token"),
+                Some(RoutingInfo::SingleNode(
+                    SingleNodeRoutingInfo::SpecificNode(Route::new(
+                        slot(b"session"),
+                        SlotAddr::ReplicaOptional,
+                    )),
+                )),
+            ),
+            (
+                cmd("HEXPIRE")
+                    .arg("session")
+                    .arg("60")
+                    .arg("FIELDS")
+                    .arg("1")
+                    .arg("token"),
+                Some(RoutingInfo::SingleNode(
+                    SingleNodeRoutingInfo::SpecificNode(Route::new(
+                        slot(b"session"),
+                        SlotAddr::Master,
+                    )),
+                )),
+            ),
+            (
+                cmd("HGETEX")
+                    .arg("session")
+                    .arg("PERSIST")
+                    .arg("FIELDS")
+                    .arg("1")
+                    .arg("token"),
+                Some(RoutingInfo::SingleNode(
+                    SingleNodeRoutingInfo::SpecificNode(Route::new(
+                        slot(b"session"),
+                        SlotAddr::Master,
+                    )),
+                )),
+            ),
+            (
+                cmd("SET")
+                    .arg("lock")
+                    .arg("new-token")
+                    .arg("IFEQ")
+                    .arg("token"),
+                Some(RoutingInfo::SingleNode(
+                    SingleNodeRoutingInfo::SpecificNode(Route::new(
+                        slot(b"lock"),
+                        SlotAddr::Master,
+                    )),
+                )),
+            ),
+            (
+                cmd("DELIFEQ").arg("lock").arg("token"),
+                Some(RoutingInfo::SingleNode(
+                    SingleNodeRoutingInfo::SpecificNode(Route::new(
+                        slot(b"lock"),
+                        SlotAddr::Master,
+                    )),
+                )),
+            ),
+            (
+                cmd("BF.MADD").arg("seen").arg("a").arg("b"),
+                Some(RoutingInfo::SingleNode(
+                    SingleNodeRoutingInfo::SpecificNode(Route::new(
+                        slot(b"seen"),
+                        SlotAddr::Master,
+                    )),
+                )),
+            ),
+            (
+                cmd("BF.MEXISTS").arg("seen").arg("a").arg("b"),
+                Some(RoutingInfo::SingleNode(
+                    SingleNodeRoutingInfo::SpecificNode(Route::new(
+                        slot(b"seen"),
+                        SlotAddr::ReplicaOptional,
+                    )),
+                )),
+            ),
+            (
+                cmd("FT.CURSOR").arg("READ").arg("idx").arg("123"),
+                Some(RoutingInfo::SingleNode(
+                    SingleNodeRoutingInfo::SpecificNode(Route::new(
+                        slot(b"idx"),
+                        SlotAddr::ReplicaOptional,
+                    )),
+                )),
+            ),
+            (
+                cmd("FT.CURSOR").arg("DEL").arg("idx").arg("123"),
+                Some(RoutingInfo::SingleNode(
+                    SingleNodeRoutingInfo::SpecificNode(Route::new(slot(b"idx"), SlotAddr::Master)),
+                )),
+            ),
         ] {
             assert_eq!(
                 RoutingInfo::for_routable(cmd),
@@ -1682,6 +1906,80 @@ mod tests_routing {
             ]).unwrap()), Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(Route(slot, SlotAddr::Master)))) if slot == 5210));
     }
 
+    #[test]
+    fn test_key_arg_indices() {
+        let key_indices = |args: &[&str]| {
+            let mut cmd = cmd(args[0]);
+            for arg in &args[1..] {
+                cmd.arg(*arg);
+            }
+            key_arg_indices(&cmd)
+        };
+        assert_eq!(key_indices(&["GET", "foo"]), vec![1]);
+        assert_eq!(key_indices(&["SET", "foo", "bar", "EX", "10"]), vec![1]);
+        assert_eq!(key_indices(&["MGET", "foo", "bar"]), vec![1, 2]);
+        assert_eq!(key_indices(&["MSET", "foo", "1", "bar", "2"]), vec![1, 3]);
+        assert_eq!(key_indices(&["JSON.MGET", "foo", "bar", "$"]), vec![1, 2]);
+        assert_eq!(
+            key_indices(&["JSON.MSET", "foo", "$", "1", "bar", "$", "2"]),
+            vec![1, 4]
+        );
+        assert_eq!(
+            key_indices(&["EVAL", "return 1", "2", "foo", "bar", "arg"]),
+            vec![3, 4]
+        );
+        assert_eq!(
+            key_indices(&["FCALL", "func", "0", "arg"]),
+            Vec::<usize>::new()
+        );
+        assert_eq!(
+            key_indices(&["LMPOP", "2", "foo", "bar", "LEFT"]),
+            vec![2, 3]
+        );
+        assert_eq!(
+            key_indices(&["XREAD", "COUNT", "2", "STREAMS", "foo", "bar", "0", "0"]),
+            vec![4, 5]
+        );
+        assert_eq!(
+            key_indices(&["BITOP", "AND", "dest", "foo", "bar"]),
+            vec![2, 3, 4]
+        );
+        assert_eq!(key_indices(&["OBJECT", "ENCODING", "foo"]), vec![2]);
+        assert_eq!(
+            key_indices(&["FT.CURSOR", "READ", "idx", "123"]),
+            Vec::<usize>::new()
+        );
+        assert_eq!(key_indices(&["RENAME", "foo", "bar"]), vec![1, 2]);
+        assert_eq!(key_indices(&["BLPOP", "foo", "bar", "0"]), vec![1, 2]);
+        assert_eq!(
+            key_indices(&[
+                "ZUNIONSTORE",
+                "dest",
+                "2",
+                "foo",
+                "bar",
+                "WEIGHTS",
+                "1",
+                "2"
+            ]),
+            vec![1, 3, 4]
+        );
+        assert_eq!(
+            key_indices(&["SORT", "foo", "LIMIT", "0", "1", "STORE", "dest"]),
+            vec![1, 6]
+        );
+        assert_eq!(key_indices(&["KEYS", "*"]), Vec::<usize>::new());
+        assert_eq!(
+            key_indices(&["PUBLISH", "channel", "message"]),
+            Vec::<usize>::new()
+        );
+        assert_eq!(
+            key_indices(&["CLIENT", "NO-EVICT", "ON"]),
+            Vec::<usize>::new()
+        );
+        assert_eq!(key_indices(&["PING"]), Vec::<usize>::new());
+    }
+
     #[test]
     fn test_multi_shard_keys_only() {
         let mut cmd = cmd("DEL");
@@ -35,6 +35,10 @@ pub enum ReadFromReplicaStrategy {
     /// Spread the read requests among nodes within the client's Availability Zone (AZ) in a round robin manner,
     /// prioritizing local replicas, then the local primary, and falling back to any replica or the primary if needed.
     AZAffinityReplicasAndPrimary(String),
+    /// Route the read requests to the replica with the lowest measured round-trip time.
+    /// Replicas without latency samples are used in a round robin manner until samples are collected.
+    /// If no replica is available, route the requests to the primary.
+    LowestLatency,
 }
 
 #[derive(Debug, Default)]
@@ -55,7 +59,7 @@ fn get_address_from_slot(
     }
     match read_from_replica {
         ReadFromReplicaStrategy::AlwaysFromPrimary => addrs.primary(),
-        ReadFromReplicaStrategy::RoundRobin => {
+        ReadFromReplicaStrategy::RoundRobin | ReadFromReplicaStrategy::LowestLatency => {
             let index = slot
                 .last_used_replica
                 .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
@@ -223,6 +223,20 @@ pub type PubSubChannelOrPattern = Vec<u8>;
 /// Type for pubsub channels/patterns
 pub type PubSubSubscriptionInfo = HashMap<PubSubSubscriptionKind, HashSet<PubSubChannelOrPattern>>;
 
+/// Modes of server assisted client side caching, enabled with `CLIENT TRACKING` on every connection.
+/// See <https://valkey.io/topics/client-side-caching> for more details
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClientTrackingMode {
+    /// The server remembers the keys read by the connection, and sends invalidations when they're modified.
+    Default,
+    /// The server sends invalidations for every modified key which starts with one of the prefixes.
+    /// An empty list of prefixes matches all keys.
+    Broadcast {
+        /// Prefixes of the keys to receive invalidations for.
+        prefixes: Vec<String>,
+    },
+}
+
 /// Redis specific/connection independent information used to establish a connection to redis.
 #[derive(Clone, Debug, Default)]
 pub struct RedisConnectionInfo {
@@ -238,6 +252,8 @@ pub struct RedisConnectionInfo {
     pub client_name: Option<String>,
     /// Optionally a pubsub subscriptions that should be used for connection
     pub pubsub_subscriptions: Option<PubSubSubscriptionInfo>,
+    /// Optionally enables client tracking on the connection. Requires RESP3.
+    pub client_tracking: Option<ClientTrackingMode>,
 }
 
 impl FromStr for ConnectionInfo {
@@ -396,6 +412,7 @@ fn url_to_tcp_connection_info(url: url::Url) -> RedisResult<ConnectionInfo> {
             },
             client_name: None,
             pubsub_subscriptions: None,
+            client_tracking: None,
         },
     })
 }
@@ -429,6 +446,7 @@ fn url_to_unix_connection_info(url: url::Url) -> RedisResult<ConnectionInfo> {
             },
             client_name: None,
             pubsub_subscriptions: None,
+            client_tracking: None,
         },
     })
 }
@@ -607,7 +625,8 @@ impl ActualConnection {
             } => {
                 let host: &str = host;
                 let config = create_rustls_config(insecure, tls_params.as_ref().cloned())?;
-                let server_name = rustls_pki_types::ServerName::try_from(host)
+                let server_name = TlsConnParams::server_name(tls_params, host);
+                let server_name = rustls_pki_types::ServerName::try_from(server_name)
                     .map_err(|e| {
                         RedisError::from((
                             ErrorKind::InvalidClientConfig,
@@ -1829,6 +1848,7 @@ mod tests {
                         protocol: ProtocolVersion::RESP2,
                         client_name: None,
                         pubsub_subscriptions: None,
+                        client_tracking: None,
                     },
                 },
             ),
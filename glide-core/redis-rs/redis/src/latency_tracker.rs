@@ -0,0 +1,193 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::RwLock;
+use std::time::Duration;
+
+// === Default constants ===
+/// Weight given to a new round-trip sample in the moving average.
+pub(crate) const EWMA_ALPHA: f64 = 0.2;
+/// A node must be at least this much faster (relative) than the currently selected one
+/// before the selection moves to it, to avoid flapping between nodes with similar latency.
+pub(crate) const SWITCH_THRESHOLD: f64 = 0.2;
+/// The number of recent latencies from which a percentile is computed.
+pub(crate) const LATENCY_WINDOW_SIZE: usize = 1000;
+/// The number of latency samples required before a percentile is computed.
+pub(crate) const MIN_LATENCY_SAMPLES: usize = 100;
+/// The percentile is recomputed after this many new samples.
+pub(crate) const PERCENTILE_REFRESH_INTERVAL: usize = 100;
+
+/// A window of recent latencies, from which a percentile is periodically computed.
+#[derive(Debug, Default)]
+pub(crate) struct LatencyWindow {
+    samples: VecDeque<Duration>,
+    samples_since_refresh: usize,
+    percentile: Option<Duration>,
+}
+
+impl LatencyWindow {
+    /// Record a latency sample, and recompute the `percentile` (between 0 and 100) of the window if it's due.
+    pub(crate) fn record(&mut self, latency: Duration, percentile: f64) {
+        if self.samples.len() == LATENCY_WINDOW_SIZE {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(latency);
+        self.samples_since_refresh += 1;
+        if self.samples.len() < MIN_LATENCY_SAMPLES
+            || (self.percentile.is_some()
+                && self.samples_since_refresh < PERCENTILE_REFRESH_INTERVAL)
+        {
+            return;
+        }
+        let mut sorted: Vec<Duration> = self.samples.iter().copied().collect();
+        sorted.sort_unstable();
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * (sorted.len() - 1) as f64).round();
+        self.percentile = Some(sorted[rank as usize]);
+        self.samples_since_refresh = 0;
+    }
+
+    /// Returns the last computed percentile, or `None` while too few latencies were recorded.
+    pub(crate) fn percentile(&self) -> Option<Duration> {
+        self.percentile
+    }
+}
+
+/// Tracks an exponentially weighted moving average of the round-trip time to each node,
+/// and selects the lowest latency node among a set of candidates.
+#[derive(Debug, Default)]
+pub struct LatencyTracker {
+    averages: RwLock<HashMap<String, f64>>,
+}
+
+impl LatencyTracker {
+    /// Create an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a round-trip sample for the given node address.
+    pub fn record(&self, address: &str, rtt: Duration) {
+        let sample = rtt.as_secs_f64();
+        let mut averages = self.averages.write().unwrap();
+        match averages.get_mut(address) {
+            Some(average) => *average = EWMA_ALPHA * sample + (1.0 - EWMA_ALPHA) * *average,
+            None => {
+                averages.insert(address.to_string(), sample);
+            }
+        }
+    }
+
+    /// Returns the current average round-trip time of the given node, if any sample was recorded.
+    pub fn average(&self, address: &str) -> Option<Duration> {
+        self.averages
+            .read()
+            .unwrap()
+            .get(address)
+            .map(|average| Duration::from_secs_f64(*average))
+    }
+
+    /// Drop the samples of all nodes for which `keep` returns false.
+    pub fn retain(&self, mut keep: impl FnMut(&str) -> bool) {
+        self.averages
+            .write()
+            .unwrap()
+            .retain(|address, _| keep(address));
+    }
+
+    /// Select the candidate with the lowest average latency.
+    /// `candidates` are pairs of an opaque index and the node's address, and `current` is the index that was selected last.
+    /// The selection stays on `current` unless another candidate is faster by more than [`SWITCH_THRESHOLD`].
+    /// Candidates without samples are ignored. Returns `None` if no candidate has samples.
+    pub fn select<'a>(
+        &self,
+        candidates: impl IntoIterator<Item = (usize, &'a str)>,
+        current: usize,
+    ) -> Option<usize> {
+        let averages = self.averages.read().unwrap();
+        let mut best: Option<(usize, f64)> = None;
+        let mut current_average = None;
+        for (index, address) in candidates {
+            let Some(average) = averages.get(address).copied() else {
+                continue;
+            };
+            if index == current {
+                current_average = Some(average);
+            }
+            if best.map_or(true, |(_, best_average)| average < best_average) {
+                best = Some((index, average));
+            }
+        }
+        let (best_index, best_average) = best?;
+        match current_average {
+            Some(current_average) if best_average > current_average * (1.0 - SWITCH_THRESHOLD) => {
+                Some(current)
+            }
+            _ => Some(best_index),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_computes_moving_average() {
+        let tracker = LatencyTracker::new();
+        tracker.record("node1:6379", Duration::from_millis(10));
+        assert_eq!(
+            tracker.average("node1:6379"),
+            Some(Duration::from_millis(10))
+        );
+        tracker.record("node1:6379", Duration::from_millis(20));
+        let average = tracker.average("node1:6379").unwrap();
+        assert!((average.as_secs_f64() - 0.012).abs() < 1e-9);
+        assert_eq!(tracker.average("node2:6379"), None);
+    }
+
+    #[test]
+    fn test_select_lowest_latency() {
+        let tracker = LatencyTracker::new();
+        tracker.record("node1:6379", Duration::from_millis(10));
+        tracker.record("node2:6379", Duration::from_millis(2));
+        tracker.record("node3:6379", Duration::from_millis(5));
+        let candidates = [(0, "node1:6379"), (1, "node2:6379"), (2, "node3:6379")];
+        assert_eq!(tracker.select(candidates, usize::MAX), Some(1));
+        assert_eq!(tracker.select(candidates, 0), Some(1));
+    }
+
+    #[test]
+    fn test_select_applies_hysteresis() {
+        let tracker = LatencyTracker::new();
+        tracker.record("node1:6379", Duration::from_millis(10));
+        tracker.record("node2:6379", Duration::from_millis(9));
+        let candidates = [(0, "node1:6379"), (1, "node2:6379")];
+        // node2 is faster, but not by enough to move away from node1.
+        assert_eq!(tracker.select(candidates, 0), Some(0));
+
+        tracker.record("node2:6379", Duration::from_millis(1));
+        tracker.record("node2:6379", Duration::from_millis(1));
+        assert_eq!(tracker.select(candidates, 0), Some(1));
+    }
+
+    #[test]
+    fn test_select_without_samples() {
+        let tracker = LatencyTracker::new();
+        assert_eq!(tracker.select([(0, "node1:6379")], 0), None);
+        tracker.record("node2:6379", Duration::from_millis(1));
+        assert_eq!(
+            tracker.select([(0, "node1:6379"), (1, "node2:6379")], 0),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_retain_removes_unknown_nodes() {
+        let tracker = LatencyTracker::new();
+        tracker.record("node1:6379", Duration::from_millis(1));
+        tracker.record("node2:6379", Duration::from_millis(1));
+        tracker.retain(|address| address == "node2:6379");
+        assert_eq!(tracker.average("node1:6379"), None);
+        assert!(tracker.average("node2:6379").is_some());
+    }
+}
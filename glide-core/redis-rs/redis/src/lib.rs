@@ -343,13 +343,23 @@ pub use crate::commands::{
     Commands, ControlFlow, Direction, LposOptions, PubSubCommands, SetOptions,
 };
 pub use crate::connection::{
-    parse_redis_url, transaction, Connection, ConnectionAddr, ConnectionInfo, ConnectionLike,
-    IntoConnectionInfo, Msg, PubSub, PubSubChannelOrPattern, PubSubSubscriptionInfo,
-    PubSubSubscriptionKind, RedisConnectionInfo, TlsMode,
+    parse_redis_url, transaction, ClientTrackingMode, Connection, ConnectionAddr, ConnectionInfo,
+    ConnectionLike, IntoConnectionInfo, Msg, PubSub, PubSubChannelOrPattern,
+    PubSubSubscriptionInfo, PubSubSubscriptionKind, RedisConnectionInfo, TlsMode,
 };
 pub use crate::parser::{parse_redis_value, Parser};
 pub use crate::pipeline::{Pipeline, PipelineRetryStrategy};
+pub use adaptive_timeout::{AdaptiveTimeout, AdaptiveTimeoutConfig};
+pub use circuit_breaker::{
+    cancel_permits, CircuitBreaker, CircuitBreakerConfig, CircuitPermit, CircuitState,
+};
+pub use latency_tracker::LatencyTracker;
 pub use push_manager::{PushInfo, PushManager};
+#[cfg(feature = "aio")]
+pub use read_your_writes::ReadYourWrites;
+pub use request_deadline::{RequestDeadline, RequestStage};
+pub use request_hedging::{HedgeDelay, RequestHedger, RequestHedgingConfig};
+pub use retry_budget::{RetryBudget, RetryBudgetConfig};
 pub use retry_strategies::RetryStrategy;
 
 // preserve grouping and order
@@ -458,13 +468,21 @@ pub mod sentinel;
 
 mod tls;
 
-pub use crate::tls::{ClientTlsConfig, TlsCertificates};
+pub use crate::tls::{retrieve_tls_certificates, ClientTlsConfig, TlsCertificates, TlsConnParams};
 
+mod adaptive_timeout;
+mod circuit_breaker;
 mod client;
 mod cmd;
 mod commands;
 mod connection;
+mod latency_tracker;
 mod parser;
 mod push_manager;
+#[cfg(feature = "aio")]
+mod read_your_writes;
+mod request_deadline;
+mod request_hedging;
+mod retry_budget;
 mod retry_strategies;
 mod types;
@@ -0,0 +1,323 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use crate::aio::ConnectionLike;
+use crate::cmd::cmd;
+use crate::types::{ErrorKind, RedisResult, Value};
+use crate::FromRedisValue;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::debug;
+
+// === Default constants ===
+/// The interval at which the offsets of lagging replicas are first polled after a write.
+pub(crate) const MIN_REPLICA_POLL_INTERVAL: Duration = Duration::from_millis(10);
+/// The poll interval doubles while replicas lag, up to this interval.
+pub(crate) const MAX_REPLICA_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// The write offset of a primary whose last write offset is unknown, which no replica reaches.
+const UNKNOWN_WRITE_OFFSET: u64 = u64::MAX;
+
+#[derive(Debug, Default)]
+struct OffsetsState {
+    /// The replication offset of each primary after the client's last write to it.
+    write_offsets: HashMap<String, u64>,
+    /// The replication offset that each replica last reported.
+    replica_offsets: HashMap<String, u64>,
+    /// Primaries whose replicas are being polled.
+    polled_primaries: HashSet<String>,
+}
+
+/// Session consistency for reads from replicas: a read is only sent to a replica that replicated all of the client's writes to its primary,
+/// and otherwise falls back to the primary.
+///
+/// After each write, the client records the replication offset of the primary, and polls its replicas until they reach it.
+/// Recording the offset costs an additional `INFO REPLICATION` round trip to the primary after every tracked write.
+#[derive(Debug, Default)]
+pub struct ReadYourWrites {
+    state: Mutex<OffsetsState>,
+}
+
+/// Returns the `field` of a node's `INFO REPLICATION` reply.
+fn replication_offset(info: &str, field: &str) -> Option<u64> {
+    info.lines()
+        .find_map(|line| line.strip_prefix(field)?.strip_prefix(':'))
+        .and_then(|offset| offset.trim().parse().ok())
+}
+
+async fn query_replication_offset(
+    connection: &mut (impl ConnectionLike + Send),
+    field: &str,
+) -> RedisResult<u64> {
+    let info = connection
+        .req_packed_command(cmd("INFO").arg("REPLICATION"))
+        .await?;
+    let info = match info {
+        Value::VerbatimString { text, .. } => text,
+        info => String::from_owned_redis_value(info)?,
+    };
+    replication_offset(&info, field).ok_or_else(|| {
+        (
+            ErrorKind::ResponseError,
+            "Replication offset is missing from INFO REPLICATION",
+            field.to_string(),
+        )
+            .into()
+    })
+}
+
+impl OffsetsState {
+    fn is_caught_up(&self, primary: &str, replica: &str) -> bool {
+        match self.write_offsets.get(primary) {
+            Some(write_offset) => self
+                .replica_offsets
+                .get(replica)
+                .is_some_and(|replica_offset| replica_offset >= write_offset),
+            None => true,
+        }
+    }
+}
+
+impl ReadYourWrites {
+    /// Create a tracker without recorded writes, so reads may be sent to any replica.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a write to `primary`, by querying its replication offset on the connection that the write was sent on.
+    /// Returns true if the primary's replicas should be polled by [`ReadYourWrites::poll_replicas`], since they aren't already polled.
+    pub async fn record_write(
+        &self,
+        primary: &str,
+        connection: &mut (impl ConnectionLike + Send),
+    ) -> RedisResult<bool> {
+        let offset = match query_replication_offset(connection, "master_repl_offset").await {
+            Ok(offset) => offset,
+            Err(err) => {
+                self.pin_to_primary(primary);
+                return Err(err);
+            }
+        };
+        let mut state = self.state.lock().unwrap();
+        let write_offset = state.write_offsets.entry(primary.to_string()).or_default();
+        // Offsets only grow, so the offset of this write covers the unknown offset of an earlier write.
+        *write_offset = if *write_offset == UNKNOWN_WRITE_OFFSET {
+            offset
+        } else {
+            (*write_offset).max(offset)
+        };
+        Ok(state.polled_primaries.insert(primary.to_string()))
+    }
+
+    /// Send all reads to `primary` until the next write to it is recorded,
+    /// since the offset of a write to it couldn't be recorded, and no replica is known to have replicated it.
+    pub fn pin_to_primary(&self, primary: &str) {
+        self.state
+            .lock()
+            .unwrap()
+            .write_offsets
+            .insert(primary.to_string(), UNKNOWN_WRITE_OFFSET);
+    }
+
+    /// Returns true if `replica` replicated all of the client's writes to `primary`, so reads from it return them.
+    pub fn is_caught_up(&self, primary: &str, replica: &str) -> bool {
+        self.state.lock().unwrap().is_caught_up(primary, replica)
+    }
+
+    /// Poll the replication offsets of the replicas of `primary` until all of them replicated the client's writes.
+    /// Replicas that caught up are polled again if a later write raises the offset they have to reach.
+    /// Replicas that fail to report their offset aren't polled anymore, and reads keep falling back to the primary instead of them.
+    pub async fn poll_replicas<C>(&self, primary: &str, mut replicas: Vec<(String, C)>)
+    where
+        C: ConnectionLike + Send,
+    {
+        let mut interval = MIN_REPLICA_POLL_INTERVAL;
+        let mut polled_write_offset = None;
+        loop {
+            {
+                // The check and the removal of the primary are done under the same lock, so that a write recorded in between starts polling again.
+                let mut state = self.state.lock().unwrap();
+                if replicas
+                    .iter()
+                    .all(|(replica, _)| state.is_caught_up(primary, replica))
+                {
+                    state.polled_primaries.remove(primary);
+                    return;
+                }
+                // A later write is polled for from the shortest interval again.
+                let write_offset = state.write_offsets.get(primary).copied();
+                if polled_write_offset.is_some_and(|offset| Some(offset) != write_offset) {
+                    interval = MIN_REPLICA_POLL_INTERVAL;
+                }
+                polled_write_offset = write_offset;
+            }
+            tokio::time::sleep(interval).await;
+            interval = (interval * 2).min(MAX_REPLICA_POLL_INTERVAL);
+
+            let mut polled = Vec::with_capacity(replicas.len());
+            for (replica, mut connection) in replicas {
+                if self.is_caught_up(primary, &replica) {
+                    polled.push((replica, connection));
+                    continue;
+                }
+                match query_replication_offset(&mut connection, "slave_repl_offset").await {
+                    Ok(offset) => {
+                        self.state
+                            .lock()
+                            .unwrap()
+                            .replica_offsets
+                            .insert(replica.clone(), offset);
+                        polled.push((replica, connection));
+                    }
+                    Err(err) => debug!("Failed to poll the replication offset of {replica}: {err}"),
+                }
+            }
+            replicas = polled;
+        }
+    }
+
+    /// Drop the offsets of all nodes for which `keep` returns false.
+    pub fn retain(&self, mut keep: impl FnMut(&str) -> bool) {
+        let mut state = self.state.lock().unwrap();
+        state.write_offsets.retain(|address, _| keep(address));
+        state.replica_offsets.retain(|address, _| keep(address));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::RedisFuture;
+    use crate::{Cmd, Pipeline};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    /// A replica connection that reports the offset it's set to.
+    struct ReplicaConnection(Arc<AtomicU64>);
+
+    impl ConnectionLike for ReplicaConnection {
+        fn req_packed_command<'a>(&'a mut self, _cmd: &'a Cmd) -> RedisFuture<'a, Value> {
+            let info = format!(
+                "# Replication\r\nrole:slave\r\nslave_repl_offset:{}\r\n",
+                self.0.load(Ordering::Relaxed)
+            );
+            Box::pin(async move { Ok(Value::BulkString(info.into_bytes())) })
+        }
+
+        fn req_packed_commands<'a>(
+            &'a mut self,
+            _cmd: &'a Pipeline,
+            _offset: usize,
+            _count: usize,
+            _pipeline_retry_strategy: Option<crate::PipelineRetryStrategy>,
+        ) -> RedisFuture<'a, Vec<Value>> {
+            unimplemented!()
+        }
+
+        fn get_db(&self) -> i64 {
+            0
+        }
+
+        fn is_closed(&self) -> bool {
+            false
+        }
+    }
+
+    const PRIMARY_INFO: &str =
+        "# Replication\r\nrole:master\r\nconnected_slaves:1\r\nmaster_repl_offset:120\r\n";
+
+    #[test]
+    fn test_replication_offset_is_parsed_from_info() {
+        assert_eq!(
+            replication_offset(PRIMARY_INFO, "master_repl_offset"),
+            Some(120)
+        );
+        assert_eq!(replication_offset(PRIMARY_INFO, "slave_repl_offset"), None);
+    }
+
+    #[test]
+    fn test_replicas_are_caught_up_once_they_reach_the_write_offset() {
+        let tracker = ReadYourWrites::new();
+        assert!(tracker.is_caught_up("primary", "replica"));
+
+        let set_replica_offset = |offset: u64| {
+            let mut state = tracker.state.lock().unwrap();
+            state.replica_offsets.insert("replica".to_string(), offset);
+        };
+        let mut state = tracker.state.lock().unwrap();
+        state.write_offsets.insert("primary".to_string(), 120);
+        drop(state);
+        assert!(!tracker.is_caught_up("primary", "replica"));
+        assert!(tracker.is_caught_up("other-primary", "replica"));
+
+        set_replica_offset(100);
+        assert!(!tracker.is_caught_up("primary", "replica"));
+
+        set_replica_offset(120);
+        assert!(tracker.is_caught_up("primary", "replica"));
+
+        // Once the primary's offset is dropped, e.g. after a failover, reads go to any replica.
+        set_replica_offset(0);
+        tracker.retain(|address| address != "primary");
+        assert!(tracker.is_caught_up("primary", "replica"));
+    }
+
+    #[test]
+    fn test_pinned_primary_is_read_from_regardless_of_replica_offsets() {
+        let tracker = ReadYourWrites::new();
+        tracker
+            .state
+            .lock()
+            .unwrap()
+            .replica_offsets
+            .insert("replica".to_string(), u64::MAX - 1);
+        tracker.pin_to_primary("primary");
+        assert!(!tracker.is_caught_up("primary", "replica"));
+        assert!(tracker.is_caught_up("other-primary", "replica"));
+    }
+
+    #[tokio::test]
+    async fn test_caught_up_replicas_are_polled_again_after_a_later_write() {
+        let tracker = Arc::new(ReadYourWrites::new());
+        let set_write_offset = |offset: u64| {
+            let mut state = tracker.state.lock().unwrap();
+            state.write_offsets.insert("primary".to_string(), offset);
+            state.polled_primaries.insert("primary".to_string());
+        };
+        let fast_offset = Arc::new(AtomicU64::new(100));
+        let slow_offset = Arc::new(AtomicU64::new(0));
+        set_write_offset(100);
+        let poll = tokio::spawn({
+            let tracker = tracker.clone();
+            let replicas = vec![
+                ("fast".to_string(), ReplicaConnection(fast_offset.clone())),
+                ("slow".to_string(), ReplicaConnection(slow_offset.clone())),
+            ];
+            async move { tracker.poll_replicas("primary", replicas).await }
+        });
+        let wait_until_caught_up = |replica: &'static str| {
+            let tracker = tracker.clone();
+            async move {
+                while !tracker.is_caught_up("primary", replica) {
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                }
+            }
+        };
+        wait_until_caught_up("fast").await;
+
+        // A later write, recorded while the slow replica is still polled.
+        set_write_offset(200);
+        fast_offset.store(200, Ordering::Relaxed);
+        assert!(!tracker.is_caught_up("primary", "fast"));
+        tokio::time::timeout(Duration::from_secs(1), wait_until_caught_up("fast"))
+            .await
+            .expect("The caught up replica wasn't polled again");
+
+        slow_offset.store(200, Ordering::Relaxed);
+        tokio::time::timeout(Duration::from_secs(5), poll)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(tracker.is_caught_up("primary", "slow"));
+        assert!(tracker.state.lock().unwrap().polled_primaries.is_empty());
+    }
+}
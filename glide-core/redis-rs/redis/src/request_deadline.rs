@@ -0,0 +1,171 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use crate::types::RedisError;
+use logger_core::log_error;
+use std::fmt;
+use std::io;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use telemetrylib::GlideOpenTelemetry;
+
+/// A stage of a request, in which the time until its deadline is spent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RequestStage {
+    /// Sending the request to a node and waiting for its reply.
+    Server,
+    /// Waiting for the slot map to be updated after a redirect or a missing route.
+    Topology,
+    /// Waiting for connections to be reconnected.
+    Reconnect,
+    /// Waiting before a failed request is retried.
+    RetryBackoff,
+}
+
+impl RequestStage {
+    const ALL: [RequestStage; 4] = [
+        RequestStage::Server,
+        RequestStage::Topology,
+        RequestStage::Reconnect,
+        RequestStage::RetryBackoff,
+    ];
+}
+
+impl fmt::Display for RequestStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestStage::Server => write!(f, "waiting for the server"),
+            RequestStage::Topology => write!(f, "waiting for topology"),
+            RequestStage::Reconnect => write!(f, "reconnecting"),
+            RequestStage::RetryBackoff => write!(f, "backing off before retrying"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct StageTimes {
+    current: RequestStage,
+    entered_at: Instant,
+    spent: [Duration; RequestStage::ALL.len()],
+}
+
+/// The time by which a request must complete, carried through redirects and retries so that they stop
+/// once the deadline is too close.
+///
+/// The deadline records how long the request spent in each [`RequestStage`], so that its timeout error
+/// says where the time went.
+#[derive(Debug)]
+pub struct RequestDeadline {
+    started_at: Instant,
+    deadline: Instant,
+    stages: Mutex<StageTimes>,
+}
+
+impl RequestDeadline {
+    /// Create a deadline `timeout` from now, for a request that is being sent to the server.
+    pub fn new(timeout: Duration) -> Self {
+        let now = Instant::now();
+        Self {
+            started_at: now,
+            deadline: now + timeout,
+            stages: Mutex::new(StageTimes {
+                current: RequestStage::Server,
+                entered_at: now,
+                spent: Default::default(),
+            }),
+        }
+    }
+
+    /// Returns the time left until the deadline.
+    pub fn remaining(&self) -> Duration {
+        self.deadline.saturating_duration_since(Instant::now())
+    }
+
+    /// Returns true if the deadline will pass before waiting `wait` completes, so the request shouldn't wait for it.
+    pub fn is_too_close(&self, wait: Duration) -> bool {
+        self.remaining() <= wait
+    }
+
+    /// Marks that the request moved to `stage`.
+    pub fn enter(&self, stage: RequestStage) {
+        let mut stages = self.stages.lock().unwrap();
+        if stages.current == stage {
+            return;
+        }
+        let now = Instant::now();
+        let (current, entered_at) = (stages.current as usize, stages.entered_at);
+        stages.spent[current] += now.duration_since(entered_at);
+        stages.current = stage;
+        stages.entered_at = now;
+    }
+
+    fn time_per_stage(&self) -> [Duration; RequestStage::ALL.len()] {
+        let stages = self.stages.lock().unwrap();
+        let mut spent = stages.spent;
+        spent[stages.current as usize] += stages.entered_at.elapsed();
+        spent
+    }
+
+    /// Returns a timeout error that says how long the request spent in each stage, and records the timeout.
+    pub fn timeout_error(&self) -> RedisError {
+        if let Err(e) = GlideOpenTelemetry::record_timeout_error() {
+            log_error(
+                "OpenTelemetry:timeout_error",
+                format!("Failed to record timeout error: {e}"),
+            );
+        }
+        let spent = self.time_per_stage();
+        let (longest_stage, _) = RequestStage::ALL
+            .iter()
+            .zip(spent)
+            .max_by_key(|(_, duration)| *duration)
+            .unwrap();
+        let breakdown = RequestStage::ALL
+            .iter()
+            .zip(spent)
+            .map(|(stage, duration)| format!("{stage}: {duration:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!(
+                "Request timed out after {:?}, most of it {longest_stage} ({breakdown})",
+                self.started_at.elapsed()
+            ),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_is_attributed_to_the_current_stage() {
+        let deadline = RequestDeadline::new(Duration::from_secs(1));
+        deadline.stages.lock().unwrap().entered_at -= Duration::from_millis(10);
+        deadline.enter(RequestStage::Topology);
+        deadline.stages.lock().unwrap().entered_at -= Duration::from_millis(50);
+        deadline.enter(RequestStage::Server);
+
+        let spent = deadline.time_per_stage();
+        assert!(spent[RequestStage::Server as usize] >= Duration::from_millis(10));
+        assert!(spent[RequestStage::Topology as usize] >= Duration::from_millis(50));
+        assert_eq!(spent[RequestStage::Reconnect as usize], Duration::ZERO);
+
+        let err = deadline.timeout_error();
+        assert!(err.is_timeout());
+        assert!(
+            err.to_string().contains("most of it waiting for topology"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_deadline_is_too_close_for_longer_waits() {
+        let deadline = RequestDeadline::new(Duration::from_millis(100));
+        assert!(!deadline.is_too_close(Duration::ZERO));
+        assert!(deadline.is_too_close(Duration::from_secs(1)));
+        assert!(RequestDeadline::new(Duration::ZERO).is_too_close(Duration::ZERO));
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use crate::adaptive_timeout::AdaptiveTimeout;
+use crate::circuit_breaker::cancel_permits;
+use crate::cmd::Cmd;
+use crate::latency_tracker::LatencyWindow;
+use std::future::{poll_fn, Future};
+use std::pin::{pin, Pin};
+use std::sync::Mutex;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+// === Default constants ===
+pub(crate) const DEFAULT_MAX_HEDGE_PERCENTAGE: u32 = 5;
+/// The number of hedges that may accumulate in the budget while reads aren't hedged.
+pub(crate) const MAX_HEDGE_BUDGET: f64 = 10.0;
+
+/// How long a read waits for its reply before it's hedged.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HedgeDelay {
+    /// Hedge reads that haven't completed after a fixed delay.
+    Fixed(Duration),
+    /// Hedge reads that haven't completed after the given percentile (between 0 and 100) of recent read latencies.
+    Percentile(f64),
+}
+
+/// Configuration of a [`RequestHedger`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RequestHedgingConfig {
+    /// How long a read waits for its reply before it's sent to another node.
+    pub delay: HedgeDelay,
+    /// The maximal percentage of reads that may be hedged.
+    pub max_hedge_percentage: u32,
+}
+
+impl Default for RequestHedgingConfig {
+    fn default() -> Self {
+        Self {
+            delay: HedgeDelay::Percentile(95.0),
+            max_hedge_percentage: DEFAULT_MAX_HEDGE_PERCENTAGE,
+        }
+    }
+}
+
+/// Sends a duplicate of slow reads to another node, and returns the first successful reply.
+///
+/// Each read adds `max_hedge_percentage / 100` to a hedge budget, and each hedge consumes 1 from it,
+/// so that a slow node doesn't double the load on the rest of the nodes.
+#[derive(Debug)]
+pub struct RequestHedger {
+    config: RequestHedgingConfig,
+    budget: Mutex<f64>,
+    latencies: Mutex<LatencyWindow>,
+}
+
+impl RequestHedger {
+    /// Create a hedger with an empty hedge budget.
+    pub fn new(config: RequestHedgingConfig) -> Self {
+        Self {
+            config,
+            budget: Mutex::new(0.0),
+            latencies: Default::default(),
+        }
+    }
+
+    /// Returns whether `cmd` may be hedged.
+    /// Blocking and known-slow commands aren't, since a hedge would hold a second node for as long as the first,
+    /// and spend the hedge budget on reads that are expected to be slow.
+    pub fn applies_to(cmd: &Cmd) -> bool {
+        AdaptiveTimeout::applies_to(cmd)
+    }
+
+    /// Returns the delay after which a read is hedged, and adds the read to the hedge budget.
+    /// Returns `None` while too few latencies were recorded to compute the percentile delay.
+    fn hedge_delay(&self) -> Option<Duration> {
+        {
+            let mut budget = self.budget.lock().unwrap();
+            *budget = (*budget + f64::from(self.config.max_hedge_percentage) / 100.0)
+                .min(MAX_HEDGE_BUDGET);
+        }
+        match self.config.delay {
+            HedgeDelay::Fixed(delay) => Some(delay),
+            HedgeDelay::Percentile(_) => self.latencies.lock().unwrap().percentile(),
+        }
+    }
+
+    /// Consumes a hedge from the budget. Returns false if the budget is exhausted.
+    fn try_consume_hedge(&self) -> bool {
+        let mut budget = self.budget.lock().unwrap();
+        if *budget < 1.0 {
+            return false;
+        }
+        *budget -= 1.0;
+        true
+    }
+
+    fn record_latency(&self, latency: Duration) {
+        let HedgeDelay::Percentile(percentile) = self.config.delay else {
+            return;
+        };
+        self.latencies.lock().unwrap().record(latency, percentile);
+    }
+
+    /// Sends `request`, and if it doesn't complete within the hedge delay, also sends the request created by `hedge_request`.
+    /// The first successful reply is returned, and the other request is cancelled: it's dropped without counting as a failure
+    /// in the circuit breaker.
+    /// If both requests fail, the error of `request` is returned.
+    ///
+    /// `hedge_request` returns `None` if there's no other node to send the request to.
+    pub async fn run<T, E, R, H>(
+        &self,
+        request: R,
+        hedge_request: impl FnOnce() -> Option<H>,
+    ) -> Result<T, E>
+    where
+        R: Future<Output = Result<T, E>>,
+        H: Future<Output = Result<T, E>>,
+    {
+        let start = Instant::now();
+        let mut request = pin!(Some(request));
+        let first_result = match self.hedge_delay() {
+            Some(delay) => {
+                let mut sleep = pin!(tokio::time::sleep(delay));
+                poll_fn(|cx| match poll_request(request.as_mut(), cx) {
+                    Poll::Ready(result) => Poll::Ready(Some(result)),
+                    Poll::Pending => sleep.as_mut().poll(cx).map(|_| None),
+                })
+                .await
+            }
+            None => Some(poll_fn(|cx| poll_request(request.as_mut(), cx)).await),
+        };
+
+        let result = match first_result {
+            Some(result) => result,
+            None => match self.try_consume_hedge().then(hedge_request).flatten() {
+                Some(hedge) => {
+                    debug!("Hedging a read that didn't complete within the hedge delay");
+                    let mut hedge = pin!(Some(hedge));
+                    let mut request_result = None;
+                    let mut hedge_failed = false;
+                    let result = poll_fn(|cx| {
+                        if request_result.is_none() {
+                            if let Poll::Ready(result) = poll_request(request.as_mut(), cx) {
+                                if result.is_ok() {
+                                    return Poll::Ready(result);
+                                }
+                                request_result = Some(result);
+                            }
+                        }
+                        if !hedge_failed {
+                            if let Poll::Ready(result) = poll_request(hedge.as_mut(), cx) {
+                                if result.is_ok() {
+                                    return Poll::Ready(result);
+                                }
+                                hedge_failed = true;
+                            }
+                        }
+                        match request_result.take() {
+                            Some(result) if hedge_failed => Poll::Ready(result),
+                            result => {
+                                request_result = result;
+                                Poll::Pending
+                            }
+                        }
+                    })
+                    .await;
+                    // The request that didn't complete is no longer needed.
+                    cancel_permits(|| {
+                        request.set(None);
+                        hedge.set(None);
+                    });
+                    result
+                }
+                None => poll_fn(|cx| poll_request(request.as_mut(), cx)).await,
+            },
+        };
+        if result.is_ok() {
+            self.record_latency(start.elapsed());
+        }
+        result
+    }
+}
+
+/// Polls a request which didn't complete yet.
+fn poll_request<F: Future>(request: Pin<&mut Option<F>>, cx: &mut Context<'_>) -> Poll<F::Output> {
+    request
+        .as_pin_mut()
+        .expect("A cancelled request isn't polled")
+        .poll(cx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::latency_tracker::MIN_LATENCY_SAMPLES;
+    use crate::{CircuitBreaker, CircuitBreakerConfig, CircuitState, RedisError};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    type Reply = Result<&'static str, &'static str>;
+
+    fn fixed_delay_hedger(max_hedge_percentage: u32) -> RequestHedger {
+        RequestHedger::new(RequestHedgingConfig {
+            delay: HedgeDelay::Fixed(Duration::from_millis(10)),
+            max_hedge_percentage,
+        })
+    }
+
+    async fn reply_after(delay: Duration, reply: Reply) -> Reply {
+        tokio::time::sleep(delay).await;
+        reply
+    }
+
+    #[tokio::test]
+    async fn test_fast_request_is_not_hedged() {
+        let hedger = fixed_delay_hedger(100);
+        let hedged = AtomicBool::new(false);
+        let result = hedger
+            .run(reply_after(Duration::ZERO, Ok("first")), || {
+                hedged.store(true, Ordering::Relaxed);
+                Some(reply_after(Duration::ZERO, Ok("hedge")))
+            })
+            .await;
+        assert_eq!(result, Ok("first"));
+        assert!(!hedged.load(Ordering::Relaxed));
+    }
+
+    #[tokio::test]
+    async fn test_slow_request_is_hedged() {
+        let hedger = fixed_delay_hedger(100);
+        let result = hedger
+            .run(reply_after(Duration::from_secs(10), Ok("first")), || {
+                Some(reply_after(Duration::ZERO, Ok("hedge")))
+            })
+            .await;
+        assert_eq!(result, Ok("hedge"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_hedge_waits_for_request() {
+        let hedger = fixed_delay_hedger(100);
+        let result = hedger
+            .run(reply_after(Duration::from_millis(50), Ok("first")), || {
+                Some(reply_after(Duration::ZERO, Err("hedge")))
+            })
+            .await;
+        assert_eq!(result, Ok("first"));
+
+        let result = hedger
+            .run(reply_after(Duration::from_millis(50), Err("first")), || {
+                Some(reply_after(Duration::ZERO, Err("hedge")))
+            })
+            .await;
+        assert_eq!(result, Err("first"));
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_request_is_not_a_circuit_breaker_failure() {
+        let hedger = fixed_delay_hedger(100);
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            ..Default::default()
+        });
+        let request = async {
+            let permit = breaker.try_acquire("slow:6379").unwrap();
+            let result = reply_after(Duration::from_secs(10), Ok("first")).await;
+            permit.record(&Ok::<_, RedisError>(()));
+            result
+        };
+        let result = hedger
+            .run(request, || Some(reply_after(Duration::ZERO, Ok("hedge"))))
+            .await;
+        assert_eq!(result, Ok("hedge"));
+        assert_eq!(breaker.state("slow:6379"), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_hedges_are_limited_by_budget() {
+        let hedger = fixed_delay_hedger(50);
+        let mut hedges = 0;
+        for _ in 0..4 {
+            let result = hedger
+                .run(reply_after(Duration::from_millis(30), Ok("first")), || {
+                    hedges += 1;
+                    Some(reply_after(Duration::ZERO, Ok("hedge")))
+                })
+                .await;
+            assert!(result.is_ok());
+        }
+        assert_eq!(hedges, 2);
+    }
+
+    #[test]
+    fn test_percentile_delay_requires_samples() {
+        let hedger = RequestHedger::new(RequestHedgingConfig {
+            delay: HedgeDelay::Percentile(90.0),
+            max_hedge_percentage: 10,
+        });
+        for millis in 1..MIN_LATENCY_SAMPLES as u64 {
+            hedger.record_latency(Duration::from_millis(millis));
+        }
+        assert_eq!(hedger.hedge_delay(), None);
+        hedger.record_latency(Duration::from_millis(MIN_LATENCY_SAMPLES as u64));
+        assert_eq!(hedger.hedge_delay(), Some(Duration::from_millis(90)));
+    }
+
+    #[test]
+    fn test_blocking_reads_are_not_hedged() {
+        assert!(RequestHedger::applies_to(crate::cmd("GET").arg("foo")));
+        assert!(RequestHedger::applies_to(
+            crate::cmd("XREAD").arg("STREAMS").arg("s").arg("0")
+        ));
+        assert!(!RequestHedger::applies_to(
+            crate::cmd("XREAD")
+                .arg("BLOCK")
+                .arg(0)
+                .arg("STREAMS")
+                .arg("s")
+                .arg("$")
+        ));
+        assert!(!RequestHedger::applies_to(
+            crate::cmd("BLMPOP").arg(0).arg(1).arg("list").arg("LEFT")
+        ));
+    }
+}
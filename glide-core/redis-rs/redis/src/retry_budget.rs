@@ -0,0 +1,155 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use logger_core::log_error;
+use std::sync::Mutex;
+use std::time::Instant;
+use telemetrylib::GlideOpenTelemetry;
+use tracing::{info, warn};
+
+// === Default constants ===
+pub(crate) const DEFAULT_RETRY_PERCENTAGE: u32 = 10;
+pub(crate) const DEFAULT_MIN_RETRIES_PER_SECOND: u32 = 10;
+/// The number of retries that may accumulate in the budget while requests succeed.
+pub(crate) const MAX_RETRY_BUDGET: f64 = 100.0;
+
+/// Configuration of a [`RetryBudget`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryBudgetConfig {
+    /// The maximal number of retries, as a percentage of the recent requests.
+    pub retry_percentage: u32,
+    /// The number of retries per second that are allowed regardless of the number of requests,
+    /// so that clients with little traffic can still retry.
+    pub min_retries_per_second: u32,
+}
+
+impl Default for RetryBudgetConfig {
+    fn default() -> Self {
+        Self {
+            retry_percentage: DEFAULT_RETRY_PERCENTAGE,
+            min_retries_per_second: DEFAULT_MIN_RETRIES_PER_SECOND,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct BudgetState {
+    tokens: f64,
+    last_refill: Instant,
+    exhausted: bool,
+}
+
+/// A token bucket that caps the retries of a client, so that a partial outage doesn't multiply the load on the remaining nodes.
+///
+/// Each request adds `retry_percentage / 100` to the budget, and each retry consumes 1 from it.
+/// When the budget is exhausted, failed requests return their error instead of being retried.
+#[derive(Debug)]
+pub struct RetryBudget {
+    config: RetryBudgetConfig,
+    state: Mutex<BudgetState>,
+}
+
+impl RetryBudget {
+    /// Create a budget that starts with `min_retries_per_second` retries.
+    pub fn new(config: RetryBudgetConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(BudgetState {
+                tokens: f64::from(config.min_retries_per_second).min(MAX_RETRY_BUDGET),
+                last_refill: Instant::now(),
+                exhausted: false,
+            }),
+        }
+    }
+
+    fn refill(&self, state: &mut BudgetState) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * f64::from(self.config.min_retries_per_second))
+            .min(MAX_RETRY_BUDGET);
+        state.last_refill = now;
+    }
+
+    /// Adds a request to the budget.
+    pub fn record_request(&self) {
+        let mut state = self.state.lock().unwrap();
+        self.refill(&mut state);
+        state.tokens =
+            (state.tokens + f64::from(self.config.retry_percentage) / 100.0).min(MAX_RETRY_BUDGET);
+    }
+
+    /// Consumes a retry from the budget. Returns false if the budget is exhausted, in which case the request shouldn't be retried.
+    pub fn try_withdraw(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        self.refill(&mut state);
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            if state.exhausted {
+                state.exhausted = false;
+                info!("Retry budget is no longer exhausted, failed requests are retried");
+            }
+            return true;
+        }
+        if !state.exhausted {
+            state.exhausted = true;
+            warn!("Retry budget exhausted, failed requests are returned without being retried");
+        }
+        drop(state);
+        if let Err(e) = GlideOpenTelemetry::record_retry_budget_exhausted() {
+            log_error(
+                "OpenTelemetry:retry_budget_error",
+                format!("Failed to record retry budget exhaustion: {e}"),
+            );
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn budget(retry_percentage: u32, min_retries_per_second: u32) -> RetryBudget {
+        RetryBudget::new(RetryBudgetConfig {
+            retry_percentage,
+            min_retries_per_second,
+        })
+    }
+
+    #[test]
+    fn test_retries_are_limited_to_percentage_of_requests() {
+        let budget = budget(10, 0);
+        assert!(!budget.try_withdraw());
+        for _ in 0..20 {
+            budget.record_request();
+        }
+        assert!(budget.try_withdraw());
+        assert!(budget.try_withdraw());
+        assert!(!budget.try_withdraw());
+    }
+
+    #[test]
+    fn test_min_retries_are_refilled_over_time() {
+        let budget = budget(0, 2);
+        assert!(budget.try_withdraw());
+        assert!(budget.try_withdraw());
+        assert!(!budget.try_withdraw());
+
+        budget.state.lock().unwrap().last_refill -= Duration::from_secs(1);
+        assert!(budget.try_withdraw());
+        assert!(budget.try_withdraw());
+        assert!(!budget.try_withdraw());
+    }
+
+    #[test]
+    fn test_budget_is_capped() {
+        let budget = budget(100, 0);
+        for _ in 0..(MAX_RETRY_BUDGET as usize * 2) {
+            budget.record_request();
+        }
+        for _ in 0..MAX_RETRY_BUDGET as usize {
+            assert!(budget.try_withdraw());
+        }
+        assert!(!budget.try_withdraw());
+    }
+}
@@ -18,13 +18,17 @@ pub struct ClientTlsConfig {
 /// Structure to hold TLS certificates
 /// - `client_tls`: binaries of clientkey and certificate within a `ClientTlsConfig` structure if mTLS is used
 /// - `root_cert`: binary CA certificate in PEM format if CA is not in local truststore
+/// - `server_name`: server name to use for SNI and certificate verification instead of the host
 ///
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct TlsCertificates {
     /// 'ClientTlsConfig' containing client certificate and key if mTLS is to be used
     pub client_tls: Option<ClientTlsConfig>,
     /// root certificate byte stream in PEM format if the local truststore is *not* to be used
     pub root_cert: Option<Vec<u8>>,
+    /// server name to send in the SNI extension and to verify the server certificate against,
+    /// if it differs from the host used to connect
+    pub server_name: Option<String>,
 }
 
 pub(crate) fn inner_build_with_tls(
@@ -56,12 +60,12 @@ pub(crate) fn inner_build_with_tls(
     Ok(Client { connection_info })
 }
 
-pub(crate) fn retrieve_tls_certificates(
-    certificates: TlsCertificates,
-) -> RedisResult<TlsConnParams> {
+/// Parses the PEM encoded certificates and key into the parameters used by TLS connections.
+pub fn retrieve_tls_certificates(certificates: TlsCertificates) -> RedisResult<TlsConnParams> {
     let TlsCertificates {
         client_tls,
         root_cert,
+        server_name,
     } = certificates;
 
     let client_tls_params = if let Some(ClientTlsConfig {
@@ -110,6 +114,7 @@ pub(crate) fn retrieve_tls_certificates(
     Ok(TlsConnParams {
         client_tls_params,
         root_cert_store,
+        server_name,
     })
 }
 
@@ -135,8 +140,20 @@ impl Clone for ClientTlsParams {
     }
 }
 
+/// Parsed TLS parameters of a connection, created with [`retrieve_tls_certificates`].
 #[derive(Debug, Clone)]
 pub struct TlsConnParams {
     pub(crate) client_tls_params: Option<ClientTlsParams>,
     pub(crate) root_cert_store: Option<RootCertStore>,
+    pub(crate) server_name: Option<String>,
+}
+
+impl TlsConnParams {
+    /// Returns the name to use for SNI and certificate verification when connecting to `host`.
+    pub(crate) fn server_name<'a>(tls_params: &'a Option<TlsConnParams>, host: &'a str) -> &'a str {
+        tls_params
+            .as_ref()
+            .and_then(|tls_params| tls_params.server_name.as_deref())
+            .unwrap_or(host)
+    }
 }
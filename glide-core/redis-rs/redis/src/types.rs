@@ -160,6 +160,9 @@ pub enum ErrorKind {
     /// Used when an error occurs on when user perform wrong usage of management operation.
     /// E.g. not allowed configuration change.
     UserOperationError,
+
+    /// The request was rejected without being sent, because the circuit breaker of its node is open.
+    CircuitOpen,
 }
 
 #[derive(PartialEq, Debug, Clone, Display, Copy)]
@@ -977,6 +980,7 @@ impl RedisError {
             ErrorKind::ParseError => "parse error",
             ErrorKind::NotAllSlotsCovered => "not all slots are covered",
             ErrorKind::UserOperationError => "Wrong usage of management operation",
+            ErrorKind::CircuitOpen => "circuit breaker open",
         }
     }
 
@@ -1183,6 +1187,7 @@ impl RedisError {
             ErrorKind::FatalReceiveError => RetryMethod::Reconnect,
             ErrorKind::FatalSendError => RetryMethod::ReconnectAndRetry,
             ErrorKind::UserOperationError => RetryMethod::NoRetry,
+            ErrorKind::CircuitOpen => RetryMethod::NoRetry,
         }
     }
 }
@@ -770,6 +770,7 @@ fn load_certs_from_file(tls_file_paths: &TlsFilePaths) -> TlsCertificates {
             client_key: client_key_vec,
         }),
         root_cert: Some(root_cert_vec),
+        server_name: None,
     }
 }
 
@@ -9,7 +9,7 @@ mod cluster_async {
         net::{IpAddr, SocketAddr},
         str::from_utf8,
         sync::{
-            atomic::{self, AtomicBool, AtomicI32, AtomicU16, AtomicU32, Ordering},
+            atomic::{self, AtomicBool, AtomicI32, AtomicU16, AtomicU32, AtomicU64, Ordering},
             Arc,
         },
         time::Duration,
@@ -1770,6 +1770,47 @@ mod cluster_async {
         assert_eq!(requests.load(atomic::Ordering::SeqCst), 3);
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_retries_stop_when_deadline_is_too_close() {
+        let name = "tryagain_deadline";
+
+        let requests = Arc::new(atomic::AtomicUsize::new(0));
+
+        let MockEnv {
+            runtime,
+            async_connection: mut connection,
+            handler: _handler,
+            ..
+        } = MockEnv::with_client_builder(
+            ClusterClient::builder(vec![&*format!("redis://{name}")])
+                .retries(5)
+                .min_retry_wait(1000),
+            name,
+            {
+                let requests = requests.clone();
+                move |cmd: &[u8], _| {
+                    respond_startup(name, cmd)?;
+                    requests.fetch_add(1, atomic::Ordering::SeqCst);
+                    Err(parse_redis_value(b"-TRYAGAIN mock\r\n"))
+                }
+            },
+        );
+
+        let deadline = Arc::new(redis::RequestDeadline::new(Duration::from_millis(500)));
+        let result = runtime.block_on(connection.route_command_with_deadline(
+            cmd("GET").arg("test"),
+            RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random),
+            deadline,
+        ));
+
+        // The retry would have waited past the deadline, so the request fails without waiting for it.
+        let err = result.unwrap_err();
+        assert!(err.is_timeout(), "{err}");
+        assert!(err.to_string().contains("waiting for the server"), "{err}");
+        assert_eq!(requests.load(atomic::Ordering::SeqCst), 1);
+    }
+
     // Obtain the view index associated with the node with [called_port] port
     fn get_node_view_index(num_of_views: usize, ports: &Vec<u16>, called_port: u16) -> usize {
         let port_index = ports
@@ -3408,6 +3449,106 @@ mod cluster_async {
         assert_eq!(value, Ok(Some(Value::SimpleString("OK".to_owned()))));
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_read_your_writes_falls_back_to_primary_until_replica_catches_up() {
+        let name = "node";
+        let replica_offset = Arc::new(AtomicU64::new(0));
+        let read_ports = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let replica_offset_clone = replica_offset.clone();
+        let read_ports_clone = read_ports.clone();
+        let MockEnv {
+            runtime,
+            async_connection: mut connection,
+            handler: _handler,
+            ..
+        } = MockEnv::with_client_builder(
+            ClusterClient::builder(vec![&*format!("redis://{name}")])
+                .retries(0)
+                .read_from_replicas()
+                .read_your_writes(),
+            name,
+            move |cmd: &[u8], port| {
+                respond_startup_with_replica(name, cmd)?;
+                if contains_slice(cmd, b"REPLICATION") {
+                    let info = match port {
+                        6379 => "role:master\r\nmaster_repl_offset:100\r\n".to_string(),
+                        _ => format!(
+                            "role:slave\r\nslave_repl_offset:{}\r\n",
+                            replica_offset_clone.load(atomic::Ordering::SeqCst)
+                        ),
+                    };
+                    return Err(Ok(Value::BulkString(info.into_bytes())));
+                }
+                if contains_slice(cmd, b"GET") {
+                    read_ports_clone.lock().unwrap().push(port);
+                    return Err(Ok(Value::BulkString(b"123".to_vec())));
+                }
+                Err(Ok(Value::Okay))
+            },
+        );
+
+        runtime.block_on(async {
+            let get = || cmd("GET").arg("test").clone();
+            get().query_async::<_, i32>(&mut connection).await.unwrap();
+            cmd("SET")
+                .arg("test")
+                .arg("123")
+                .query_async::<_, ()>(&mut connection)
+                .await
+                .unwrap();
+            // The replica didn't replicate the write yet.
+            get().query_async::<_, i32>(&mut connection).await.unwrap();
+
+            replica_offset.store(100, atomic::Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            get().query_async::<_, i32>(&mut connection).await.unwrap();
+        });
+
+        assert_eq!(*read_ports.lock().unwrap(), vec![6380, 6379, 6380]);
+    }
+
+    #[test]
+    fn test_async_cluster_pin_route_resolves_the_serving_node() {
+        let name = "node";
+        let MockEnv {
+            runtime,
+            async_connection: mut connection,
+            handler: _handler,
+            ..
+        } = MockEnv::with_client_builder(
+            ClusterClient::builder(vec![&*format!("redis://{name}")]).read_from_replicas(),
+            name,
+            move |cmd: &[u8], _| {
+                respond_startup_with_replica(name, cmd)?;
+                Err(Ok(Value::Okay))
+            },
+        );
+
+        runtime.block_on(async {
+            assert_eq!(
+                connection
+                    .pin_route(Route::new(0, SlotAddr::Master))
+                    .await
+                    .unwrap(),
+                SingleNodeRoutingInfo::ByAddress {
+                    host: name.to_string(),
+                    port: 6379,
+                }
+            );
+            assert_eq!(
+                connection
+                    .pin_route(Route::new(0, SlotAddr::ReplicaRequired))
+                    .await
+                    .unwrap(),
+                SingleNodeRoutingInfo::ByAddress {
+                    host: name.to_string(),
+                    port: 6380,
+                }
+            );
+        });
+    }
+
     fn test_async_cluster_fan_out(
         command: &'static str,
         expected_ports: Vec<u16>,
@@ -0,0 +1,437 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use logger_core::log_error;
+use redis::cluster_routing::{Routable, is_readonly_cmd, key_arg_indices};
+use redis::{ClientTrackingMode, Cmd, PushInfo, PushKind, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicI64, Ordering};
+use telemetrylib::GlideOpenTelemetry;
+
+use super::ClientSideCacheConfiguration;
+
+const DEFAULT_MAX_ENTRIES: usize = 10_000;
+const LOCK_ERR: &str = "Failed to acquire the client side cache lock";
+
+/// Single key read commands whose responses are cached. The key is always the first argument.
+const CACHEABLE_COMMANDS: &[&[u8]] = &[
+    b"GET",
+    b"GETRANGE",
+    b"STRLEN",
+    b"HGET",
+    b"HGETALL",
+    b"HMGET",
+    b"HEXISTS",
+    b"HKEYS",
+    b"HVALS",
+    b"HLEN",
+    b"HSTRLEN",
+    b"LINDEX",
+    b"LLEN",
+    b"LRANGE",
+    b"SCARD",
+    b"SISMEMBER",
+    b"SMEMBERS",
+    b"SMISMEMBER",
+    b"ZCARD",
+    b"ZSCORE",
+    b"ZMSCORE",
+    b"ZRANK",
+    b"ZREVRANK",
+    b"TYPE",
+];
+
+/// Identifies a cached response by the database that was selected and the packed command that produced it.
+type EntryKey = (i64, Vec<u8>);
+
+struct CacheEntry {
+    key: Vec<u8>,
+    value: Value,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct CacheState {
+    /// Cached responses, by the database and packed command that produced them.
+    entries: HashMap<EntryKey, CacheEntry>,
+    /// The entries cached for each key in any database, used to evict all responses of an invalidated key.
+    /// Invalidations don't name the database of the key, so the key is evicted from all of them.
+    commands_by_key: HashMap<Vec<u8>, HashSet<EntryKey>>,
+    /// The entries by their last use, least recently used first.
+    lru: BTreeMap<u64, EntryKey>,
+    tick: u64,
+    /// Incremented on every invalidation, so responses that raced with an invalidation aren't cached.
+    generation: u64,
+}
+
+impl CacheState {
+    fn remove(&mut self, entry_key: &EntryKey) {
+        let Some(entry) = self.entries.remove(entry_key) else {
+            return;
+        };
+        self.lru.remove(&entry.last_used);
+        if let Some(commands) = self.commands_by_key.get_mut(&entry.key) {
+            commands.remove(entry_key);
+            if commands.is_empty() {
+                self.commands_by_key.remove(&entry.key);
+            }
+        }
+    }
+
+    fn invalidate_key(&mut self, key: &[u8]) {
+        if let Some(commands) = self.commands_by_key.remove(key) {
+            for entry_key in commands {
+                if let Some(entry) = self.entries.remove(&entry_key) {
+                    self.lru.remove(&entry.last_used);
+                }
+            }
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+/// A size bounded, least recently used cache of read command responses.
+/// Entries are evicted when the server sends a `CLIENT TRACKING` invalidation for their key,
+/// and the whole cache is flushed when a connection is lost or the topology changes.
+/// The client's own writes evict their keys as soon as they complete, without waiting for the invalidation.
+pub(super) struct ClientSideCache {
+    max_entries: usize,
+    tracking_mode: ClientTrackingMode,
+    state: Mutex<CacheState>,
+    /// The database selected by the client, which responses are cached for.
+    db: AtomicI64,
+}
+
+/// The result of a cache lookup.
+pub(super) enum CacheLookup {
+    /// The command isn't cacheable.
+    NotCacheable,
+    Hit(Value),
+    /// The command is cacheable, but wasn't found. Holds the cache generation to pass to [`ClientSideCache::insert`].
+    Miss(u64),
+}
+
+impl ClientSideCache {
+    pub(super) fn new(configuration: &ClientSideCacheConfiguration, db: i64) -> Self {
+        Self {
+            max_entries: match configuration.max_entries {
+                0 => DEFAULT_MAX_ENTRIES,
+                max_entries => max_entries,
+            },
+            tracking_mode: configuration.tracking_mode.clone(),
+            state: Default::default(),
+            db: AtomicI64::new(db),
+        }
+    }
+
+    fn entry_key(&self, cmd: &Cmd) -> EntryKey {
+        (self.db.load(Ordering::Relaxed), cmd.get_packed_command())
+    }
+
+    /// Records the database selected by a successful `SELECT`, so the following reads are looked up in it.
+    pub(super) fn select_db(&self, db: i64) {
+        self.db.store(db, Ordering::Relaxed);
+    }
+
+    /// Returns the key of the command, if the command's response can be cached.
+    fn cacheable_key<'a>(&self, cmd: &'a Cmd) -> Option<&'a [u8]> {
+        let command = cmd.command()?;
+        if !CACHEABLE_COMMANDS.contains(&command.as_slice()) {
+            return None;
+        }
+        let key = cmd.arg_idx(1)?;
+        match &self.tracking_mode {
+            ClientTrackingMode::Broadcast { prefixes } if !prefixes.is_empty() => prefixes
+                .iter()
+                .any(|prefix| key.starts_with(prefix.as_bytes()))
+                .then_some(key),
+            _ => Some(key),
+        }
+    }
+
+    pub(super) fn get(&self, cmd: &Cmd) -> CacheLookup {
+        if self.cacheable_key(cmd).is_none() {
+            return CacheLookup::NotCacheable;
+        }
+        let entry_key = self.entry_key(cmd);
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        let tick = state.next_tick();
+        let state = &mut *state;
+        let lookup = match state.entries.get_mut(&entry_key) {
+            Some(entry) => {
+                state.lru.remove(&entry.last_used);
+                state.lru.insert(tick, entry_key);
+                entry.last_used = tick;
+                CacheLookup::Hit(entry.value.clone())
+            }
+            None => CacheLookup::Miss(state.generation),
+        };
+        let record_result = match lookup {
+            CacheLookup::Hit(_) => GlideOpenTelemetry::record_cache_hit(),
+            _ => GlideOpenTelemetry::record_cache_miss(),
+        };
+        if let Err(e) = record_result {
+            log_error(
+                "OpenTelemetry:client_side_cache",
+                format!("Failed to record cache lookup: {}", e),
+            );
+        }
+        lookup
+    }
+
+    /// Caches the response of a command which missed the cache.
+    /// The response is dropped if an invalidation was received since the lookup, since it might already be stale.
+    pub(super) fn insert(&self, cmd: &Cmd, value: Value, generation: u64) {
+        let Some(key) = self.cacheable_key(cmd) else {
+            return;
+        };
+        let entry_key = self.entry_key(cmd);
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        if state.generation != generation {
+            return;
+        }
+        state.remove(&entry_key);
+        while state.entries.len() >= self.max_entries {
+            let Some((_, oldest)) = state.lru.pop_first() else {
+                break;
+            };
+            state.remove(&oldest);
+        }
+        let tick = state.next_tick();
+        state
+            .commands_by_key
+            .entry(key.to_vec())
+            .or_default()
+            .insert(entry_key.clone());
+        state.lru.insert(tick, entry_key.clone());
+        state.entries.insert(
+            entry_key,
+            CacheEntry {
+                key: key.to_vec(),
+                value,
+                last_used: tick,
+            },
+        );
+    }
+
+    pub(super) fn flush(&self) {
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        state.generation += 1;
+        state.entries.clear();
+        state.commands_by_key.clear();
+        state.lru.clear();
+    }
+
+    /// Evicts the keys written by a command the client sent, since the server doesn't notify the client of its own writes
+    /// before their response.
+    pub(super) fn invalidate_written_keys(&self, cmd: &Cmd) {
+        let Some(command) = cmd.command() else {
+            return;
+        };
+        match command.as_slice() {
+            b"FLUSHALL" | b"FLUSHDB" | b"SWAPDB" => self.flush(),
+            command if is_readonly_cmd(command) => {}
+            _ => {
+                let keys = key_arg_indices(cmd);
+                if keys.is_empty() {
+                    return;
+                }
+                let mut state = self.state.lock().expect(LOCK_ERR);
+                state.generation += 1;
+                for key in keys.into_iter().filter_map(|index| cmd.arg_idx(index)) {
+                    state.invalidate_key(key);
+                }
+            }
+        }
+    }
+
+    /// Applies invalidation and disconnection notifications to the cache.
+    /// An invalidation without keys, sent when the server flushes its data or the topology changes, flushes the whole cache.
+    pub(super) fn handle_push(&self, push: &PushInfo) {
+        match push.kind {
+            PushKind::Disconnection => self.flush(),
+            PushKind::Invalidate => match push.data.first() {
+                Some(Value::Array(keys)) => {
+                    let mut state = self.state.lock().expect(LOCK_ERR);
+                    state.generation += 1;
+                    for key in keys {
+                        if let Value::BulkString(key) = key {
+                            state.invalidate_key(key);
+                        }
+                    }
+                }
+                _ => self.flush(),
+            },
+            _ => {}
+        }
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.state.lock().expect(LOCK_ERR).entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache(max_entries: usize, tracking_mode: ClientTrackingMode) -> ClientSideCache {
+        ClientSideCache::new(
+            &ClientSideCacheConfiguration {
+                max_entries,
+                tracking_mode,
+            },
+            0,
+        )
+    }
+
+    fn get(key: &str) -> Cmd {
+        let mut cmd = redis::cmd("GET");
+        cmd.arg(key);
+        cmd
+    }
+
+    fn insert(cache: &ClientSideCache, cmd: &Cmd, value: Value) {
+        let CacheLookup::Miss(generation) = cache.get(cmd) else {
+            panic!("Expected a cache miss");
+        };
+        cache.insert(cmd, value, generation);
+    }
+
+    fn invalidate(keys: &[&str]) -> PushInfo {
+        PushInfo {
+            kind: PushKind::Invalidate,
+            data: vec![Value::Array(
+                keys.iter()
+                    .map(|key| Value::BulkString(key.as_bytes().to_vec()))
+                    .collect(),
+            )],
+        }
+    }
+
+    #[test]
+    fn test_cache_hit_after_insert() {
+        let cache = cache(10, ClientTrackingMode::Default);
+        insert(&cache, &get("foo"), Value::BulkString(b"bar".to_vec()));
+        assert!(
+            matches!(cache.get(&get("foo")), CacheLookup::Hit(Value::BulkString(value)) if value == b"bar")
+        );
+        assert!(matches!(cache.get(&get("baz")), CacheLookup::Miss(_)));
+        assert!(matches!(
+            cache.get(redis::cmd("SET").arg("foo").arg("bar")),
+            CacheLookup::NotCacheable
+        ));
+    }
+
+    #[test]
+    fn test_invalidation_evicts_all_commands_of_key() {
+        let cache = cache(10, ClientTrackingMode::Default);
+        let mut hget = redis::cmd("HGET");
+        hget.arg("hash").arg("field");
+        let mut hgetall = redis::cmd("HGETALL");
+        hgetall.arg("hash");
+        insert(&cache, &hget, Value::BulkString(b"value".to_vec()));
+        insert(&cache, &hgetall, Value::Map(vec![]));
+        insert(&cache, &get("foo"), Value::Okay);
+
+        cache.handle_push(&invalidate(&["hash"]));
+        assert!(matches!(cache.get(&hget), CacheLookup::Miss(_)));
+        assert!(matches!(cache.get(&hgetall), CacheLookup::Miss(_)));
+        assert!(matches!(cache.get(&get("foo")), CacheLookup::Hit(_)));
+
+        cache.handle_push(&PushInfo {
+            kind: PushKind::Invalidate,
+            data: vec![Value::Nil],
+        });
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_response_racing_with_invalidation_is_not_cached() {
+        let cache = cache(10, ClientTrackingMode::Default);
+        let CacheLookup::Miss(generation) = cache.get(&get("foo")) else {
+            panic!("Expected a cache miss");
+        };
+        cache.handle_push(&invalidate(&["foo"]));
+        cache.insert(&get("foo"), Value::Okay, generation);
+        assert!(matches!(cache.get(&get("foo")), CacheLookup::Miss(_)));
+    }
+
+    #[test]
+    fn test_entries_are_cached_per_database() {
+        let cache = cache(10, ClientTrackingMode::Default);
+        insert(&cache, &get("foo"), Value::BulkString(b"db0".to_vec()));
+        cache.select_db(1);
+        assert!(matches!(cache.get(&get("foo")), CacheLookup::Miss(_)));
+        insert(&cache, &get("foo"), Value::BulkString(b"db1".to_vec()));
+        cache.select_db(0);
+        assert!(
+            matches!(cache.get(&get("foo")), CacheLookup::Hit(Value::BulkString(value)) if value == b"db0")
+        );
+
+        // Invalidations don't name the database, so the key is evicted from all of them.
+        cache.handle_push(&invalidate(&["foo"]));
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_own_writes_evict_their_keys() {
+        let cache = cache(10, ClientTrackingMode::Default);
+        insert(&cache, &get("a"), Value::Okay);
+        insert(&cache, &get("b"), Value::Okay);
+        insert(&cache, &get("c"), Value::Okay);
+
+        cache.invalidate_written_keys(redis::cmd("GET").arg("a"));
+        assert_eq!(cache.len(), 3);
+        cache.invalidate_written_keys(redis::cmd("MSET").arg("a").arg("1").arg("b").arg("2"));
+        assert!(matches!(cache.get(&get("a")), CacheLookup::Miss(_)));
+        assert!(matches!(cache.get(&get("b")), CacheLookup::Miss(_)));
+        assert!(matches!(cache.get(&get("c")), CacheLookup::Hit(_)));
+
+        cache.invalidate_written_keys(&redis::cmd("FLUSHDB"));
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_least_recently_used_entry_is_evicted() {
+        let cache = cache(2, ClientTrackingMode::Default);
+        insert(&cache, &get("a"), Value::Okay);
+        insert(&cache, &get("b"), Value::Okay);
+        assert!(matches!(cache.get(&get("a")), CacheLookup::Hit(_)));
+        insert(&cache, &get("c"), Value::Okay);
+        assert_eq!(cache.len(), 2);
+        assert!(matches!(cache.get(&get("a")), CacheLookup::Hit(_)));
+        assert!(matches!(cache.get(&get("b")), CacheLookup::Miss(_)));
+    }
+
+    #[test]
+    fn test_disconnection_flushes_cache() {
+        let cache = cache(10, ClientTrackingMode::Default);
+        insert(&cache, &get("foo"), Value::Okay);
+        cache.handle_push(&PushInfo {
+            kind: PushKind::Disconnection,
+            data: vec![],
+        });
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_broadcast_mode_only_caches_tracked_prefixes() {
+        let cache = cache(
+            10,
+            ClientTrackingMode::Broadcast {
+                prefixes: vec!["user:".to_string()],
+            },
+        );
+        assert!(matches!(cache.get(&get("user:1")), CacheLookup::Miss(_)));
+        assert!(matches!(
+            cache.get(&get("order:1")),
+            CacheLookup::NotCacheable
+        ));
+    }
+}
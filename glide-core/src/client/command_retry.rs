@@ -0,0 +1,165 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use redis::cluster_routing::{Routable, is_readonly_cmd};
+use redis::{Arg, Cmd, ErrorKind, RedisError};
+
+/// Write commands that reply the same and leave the same state when they're applied more than once.
+/// Writes that reply with the number of changed elements, such as `DEL` or `SADD`, reply differently when they're resent
+/// after they were applied, so they aren't retried.
+const REPLAYABLE_WRITE_COMMANDS: &[&[u8]] = &[
+    b"SET",
+    b"SETEX",
+    b"PSETEX",
+    b"MSET",
+    b"HMSET",
+    b"LSET",
+    b"EXPIREAT",
+    b"PEXPIREAT",
+];
+
+/// Options of `SET` that make its reply or effect depend on the existing value:
+/// `NX` and `XX` fail when resent after they were applied, `GET` returns the value that the command set,
+/// `KEEPTTL` keeps a TTL that might have changed since, and `IFEQ` reports a mismatch since the value was already replaced.
+const CONDITIONAL_SET_OPTIONS: &[&[u8]] = &[b"NX", b"XX", b"GET", b"KEEPTTL", b"IFEQ"];
+
+/// Returns true if resending the command can't change its reply or apply it more than once,
+/// i.e. it's read only or a write that replies the same when it's applied again.
+fn is_idempotent(cmd: &Cmd) -> bool {
+    let Some(command) = cmd.command() else {
+        return false;
+    };
+    match command.as_slice() {
+        // `FT.CURSOR READ` advances the cursor, so a resent read would skip a page of results.
+        b"FT.CURSOR READ" => false,
+        // A resent `FT.AGGREGATE ... WITHCURSOR` creates another cursor, and the cursor of the lost reply is never released.
+        b"FT.AGGREGATE" if cmd.position(b"WITHCURSOR").is_some() => false,
+        command if is_readonly_cmd(command) => true,
+        // The options follow the key and the value.
+        b"SET" => !cmd.args_iter().skip(3).any(|arg| {
+            matches!(arg, Arg::Simple(arg) if CONDITIONAL_SET_OPTIONS
+                .iter()
+                .any(|option| arg.eq_ignore_ascii_case(option)))
+        }),
+        command => REPLAYABLE_WRITE_COMMANDS.contains(&command),
+    }
+}
+
+/// Returns true if `cmd` can be resent after it failed with `error`.
+///
+/// Errors that guarantee the command wasn't applied allow retrying any command.
+/// Errors after which the command may have been applied, such as timeouts and lost connections,
+/// only allow retrying idempotent commands.
+pub(super) fn is_safe_to_retry(cmd: &Cmd, error: &RedisError) -> bool {
+    match error.kind() {
+        ErrorKind::TryAgain | ErrorKind::FatalSendError => true,
+        ErrorKind::FatalReceiveError | ErrorKind::IoError => is_idempotent(cmd),
+        _ if error.is_timeout() || error.is_connection_dropped() => is_idempotent(cmd),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    fn timeout_error() -> RedisError {
+        io::Error::from(io::ErrorKind::TimedOut).into()
+    }
+
+    #[test]
+    fn test_read_only_commands_are_retried_after_timeout() {
+        let mut cmd = redis::cmd("GET");
+        cmd.arg("foo");
+        assert!(is_safe_to_retry(&cmd, &timeout_error()));
+        assert!(is_safe_to_retry(
+            &cmd,
+            &(ErrorKind::FatalReceiveError, "receive failed").into()
+        ));
+
+        // Resending a cursor read would skip a page of results.
+        let mut cmd = redis::cmd("FT.CURSOR");
+        cmd.arg("READ").arg("idx").arg(4567);
+        assert!(!is_safe_to_retry(&cmd, &timeout_error()));
+
+        let mut cmd = redis::cmd("FT.AGGREGATE");
+        cmd.arg("idx").arg("*");
+        assert!(is_safe_to_retry(&cmd, &timeout_error()));
+        cmd.arg("WITHCURSOR");
+        assert!(!is_safe_to_retry(&cmd, &timeout_error()));
+    }
+
+    #[test]
+    fn test_non_idempotent_writes_are_only_retried_if_not_applied() {
+        let mut cmd = redis::cmd("INCR");
+        cmd.arg("foo");
+        assert!(!is_safe_to_retry(&cmd, &timeout_error()));
+        assert!(!is_safe_to_retry(
+            &cmd,
+            &(ErrorKind::FatalReceiveError, "receive failed").into()
+        ));
+        assert!(is_safe_to_retry(
+            &cmd,
+            &(ErrorKind::TryAgain, "TRYAGAIN").into()
+        ));
+        assert!(is_safe_to_retry(
+            &cmd,
+            &(ErrorKind::FatalSendError, "send failed").into()
+        ));
+    }
+
+    #[test]
+    fn test_idempotent_writes_are_retried_after_timeout() {
+        let mut cmd = redis::cmd("SET");
+        cmd.arg("foo").arg("bar").arg("EX").arg(10);
+        assert!(is_safe_to_retry(&cmd, &timeout_error()));
+
+        let mut cmd = redis::cmd("MSET");
+        cmd.arg("foo").arg("bar").arg("baz").arg("qux");
+        assert!(is_safe_to_retry(&cmd, &timeout_error()));
+
+        // The key and value aren't options.
+        let mut cmd = redis::cmd("SET");
+        cmd.arg("NX").arg("GET");
+        assert!(is_safe_to_retry(&cmd, &timeout_error()));
+    }
+
+    #[test]
+    fn test_writes_with_changing_replies_are_not_retried_after_timeout() {
+        for option in ["NX", "xx", "GET", "KEEPTTL"] {
+            let mut cmd = redis::cmd("SET");
+            cmd.arg("foo").arg("bar").arg(option);
+            assert!(!is_safe_to_retry(&cmd, &timeout_error()), "{option}");
+        }
+
+        let mut cmd = redis::cmd("SET");
+        cmd.arg("foo").arg("baz").arg("IFEQ").arg("bar");
+        assert!(!is_safe_to_retry(&cmd, &timeout_error()));
+
+        for command in [
+            "DEL", "UNLINK", "SADD", "SREM", "HDEL", "HSET", "ZREM", "PERSIST",
+        ] {
+            let mut cmd = redis::cmd(command);
+            cmd.arg("foo").arg("bar");
+            assert!(!is_safe_to_retry(&cmd, &timeout_error()), "{command}");
+        }
+
+        let mut cmd = redis::cmd("ZADD");
+        cmd.arg("foo").arg(1).arg("bar");
+        assert!(!is_safe_to_retry(&cmd, &timeout_error()));
+
+        let mut cmd = redis::cmd("DELIFEQ");
+        cmd.arg("foo").arg("bar");
+        assert!(!is_safe_to_retry(&cmd, &timeout_error()));
+    }
+
+    #[test]
+    fn test_response_errors_are_not_retried() {
+        let mut cmd = redis::cmd("GET");
+        cmd.arg("foo");
+        assert!(!is_safe_to_retry(
+            &cmd,
+            &(ErrorKind::ResponseError, "WRONGTYPE").into()
+        ));
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use logger_core::{log_debug, log_warn};
+use redis::RedisResult;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use super::{AuthenticationInfo, ClientWrapper};
+
+/// Credentials are refreshed once this fraction of their lifetime has passed.
+const REFRESH_AT_LIFETIME_RATIO: f64 = 0.8;
+/// The delay between attempts to fetch credentials, after the provider failed.
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Credentials returned by a [`CredentialProvider`].
+#[derive(Clone, Debug)]
+pub struct Credentials {
+    /// The username to authenticate with. It's expected to stay the same across refreshes.
+    pub username: Option<String>,
+    /// The password or token to authenticate with.
+    pub token: String,
+    /// How long after being returned the token expires. `None` means it doesn't expire, so it's never refreshed.
+    pub expires_in: Option<Duration>,
+}
+
+impl From<Credentials> for AuthenticationInfo {
+    fn from(credentials: Credentials) -> Self {
+        AuthenticationInfo {
+            username: credentials.username,
+            password: Some(credentials.token),
+        }
+    }
+}
+
+/// Provides short-lived credentials, such as IAM authentication tokens.
+/// The client fetches the initial credentials before connecting, and fetches new ones before they expire.
+/// On every refresh, the new token replaces the password used for reconnections, and every connection is re-authenticated with `AUTH`.
+#[async_trait]
+pub trait CredentialProvider: Send + Sync {
+    async fn get_credentials(&self) -> RedisResult<Credentials>;
+}
+
+impl std::fmt::Debug for dyn CredentialProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CredentialProvider")
+    }
+}
+
+/// Refreshes the credentials of the client before they expire, until the client is dropped.
+pub(super) fn start_credentials_refresh(
+    provider: Arc<dyn CredentialProvider>,
+    internal_client: Weak<RwLock<ClientWrapper>>,
+    initial_expires_in: Option<Duration>,
+    request_timeout: Duration,
+) {
+    let Some(expires_in) = initial_expires_in else {
+        return;
+    };
+    tokio::spawn(async move {
+        let mut delay = expires_in.mul_f64(REFRESH_AT_LIFETIME_RATIO);
+        loop {
+            tokio::time::sleep(delay).await;
+            let Some(internal_client) = internal_client.upgrade() else {
+                log_debug(
+                    "credential provider",
+                    "credentials refresh stopped after client was dropped",
+                );
+                return;
+            };
+            let credentials = match provider.get_credentials().await {
+                Ok(credentials) => credentials,
+                Err(err) => {
+                    log_warn(
+                        "credential provider",
+                        format!("Failed to fetch credentials, retrying: {err}"),
+                    );
+                    delay = RETRY_INTERVAL;
+                    continue;
+                }
+            };
+            let next_expires_in = credentials.expires_in;
+            if let Err(err) =
+                super::reauthenticate(&internal_client, credentials.into(), request_timeout).await
+            {
+                // The connections that failed to re-authenticate use the new token once they reconnect.
+                log_warn(
+                    "credential provider",
+                    format!("Failed to re-authenticate with the refreshed credentials: {err}"),
+                );
+            }
+            let Some(expires_in) = next_expires_in else {
+                return;
+            };
+            delay = expires_in.mul_f64(REFRESH_AT_LIFETIME_RATIO);
+        }
+    });
+}
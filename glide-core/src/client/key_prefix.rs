@@ -0,0 +1,260 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use redis::cluster_routing::{Routable, key_arg_indices};
+use redis::{Arg, ClusterScanArgs, Cmd, Pipeline, Value};
+
+/// Characters that have a special meaning in glob-style patterns, and are escaped when the prefix is added to a pattern.
+const GLOB_SPECIAL_CHARS: &[u8] = b"*?[]\\";
+
+/// Namespaces the keys of a client, by prepending a prefix to the keys of its commands and stripping it from the keys in their replies.
+///
+/// Keys are prefixed before the command is routed, so that slots are computed on the prefixed keys.
+pub(super) struct KeyPrefix {
+    prefix: Vec<u8>,
+}
+
+impl KeyPrefix {
+    pub(super) fn new(prefix: impl Into<Vec<u8>>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+
+    pub(super) fn prefix_key(&self, key: &[u8]) -> Vec<u8> {
+        [self.prefix.as_slice(), key].concat()
+    }
+
+    /// Returns a glob-style pattern that matches the keys in the namespace that `pattern` matches.
+    fn prefix_pattern(&self, pattern: &[u8]) -> Vec<u8> {
+        let mut prefixed = Vec::with_capacity(self.prefix.len() * 2 + pattern.len());
+        for byte in &self.prefix {
+            if GLOB_SPECIAL_CHARS.contains(byte) {
+                prefixed.push(b'\\');
+            }
+            prefixed.push(*byte);
+        }
+        prefixed.extend_from_slice(pattern);
+        prefixed
+    }
+
+    /// Returns `cmd` with the prefix prepended to its keys, and to the patterns of `KEYS` and `SCAN`.
+    /// `SCAN` without a `MATCH` pattern is limited to the keys in the namespace.
+    pub(super) fn prefix_command(&self, cmd: &Cmd) -> Cmd {
+        let mut args = Vec::with_capacity(cmd.args_iter().len());
+        for arg in cmd.args_iter() {
+            match arg {
+                Arg::Simple(arg) => args.push(arg.to_vec()),
+                // Cursor arguments aren't used by the core, and can't be copied to another command.
+                Arg::Cursor => return cmd.clone(),
+            }
+        }
+
+        match cmd.command().as_deref() {
+            Some(b"KEYS") => {
+                if let Some(pattern) = args.get_mut(1) {
+                    *pattern = self.prefix_pattern(pattern);
+                }
+            }
+            Some(b"SCAN") => match cmd.position(b"MATCH") {
+                Some(match_position) if match_position + 1 < args.len() => {
+                    let pattern = &mut args[match_position + 1];
+                    *pattern = self.prefix_pattern(pattern);
+                }
+                _ => {
+                    args.push(b"MATCH".to_vec());
+                    args.push(self.prefix_pattern(b"*"));
+                }
+            },
+            _ => {
+                for key_index in key_arg_indices(cmd) {
+                    args[key_index] = self.prefix_key(&args[key_index]);
+                }
+            }
+        }
+
+        let mut prefixed = Cmd::new();
+        for arg in args {
+            prefixed.arg(arg);
+        }
+        prefixed
+            .set_span(cmd.span())
+            .set_no_response(cmd.is_no_response());
+        prefixed
+    }
+
+    /// Returns `pipeline` with the keys of all of its commands prefixed.
+    pub(super) fn prefix_pipeline(&self, pipeline: &Pipeline) -> Pipeline {
+        let mut prefixed = Pipeline::with_capacity(pipeline.len());
+        for cmd in pipeline.cmd_iter() {
+            prefixed.add_command(self.prefix_command(cmd));
+        }
+        if pipeline.is_atomic() {
+            prefixed.atomic();
+        }
+        prefixed.set_pipeline_span(pipeline.span());
+        prefixed
+    }
+
+    /// Limits a cluster scan to the keys in the namespace.
+    pub(super) fn prefix_scan_args(&self, args: &mut ClusterScanArgs) {
+        let pattern = args.match_pattern.as_deref().unwrap_or(b"*");
+        args.match_pattern = Some(self.prefix_pattern(pattern));
+    }
+
+    /// Strips the prefix from `key`. Keys outside the namespace are returned as is.
+    pub(super) fn strip_key(&self, key: Value) -> Value {
+        match key {
+            Value::BulkString(key) if key.starts_with(&self.prefix) => {
+                Value::BulkString(key[self.prefix.len()..].to_vec())
+            }
+            key => key,
+        }
+    }
+
+    fn strip_keys(&self, keys: Value) -> Value {
+        match keys {
+            Value::Array(keys) => {
+                Value::Array(keys.into_iter().map(|key| self.strip_key(key)).collect())
+            }
+            keys => keys,
+        }
+    }
+
+    /// Strips the prefix from the first element of a reply, such as the key that `BLPOP` popped from.
+    fn strip_first_key(&self, reply: Value) -> Value {
+        match reply {
+            Value::Array(mut values) if !values.is_empty() => {
+                let key = std::mem::replace(&mut values[0], Value::Nil);
+                values[0] = self.strip_key(key);
+                Value::Array(values)
+            }
+            reply => reply,
+        }
+    }
+
+    /// Strips the prefix from the keys in the reply of `cmd`.
+    pub(super) fn strip_reply(&self, cmd: &Cmd, reply: Value) -> Value {
+        match cmd.command().as_deref() {
+            Some(b"KEYS") => self.strip_keys(reply),
+            Some(b"RANDOMKEY") => self.strip_key(reply),
+            Some(b"SCAN") => match reply {
+                Value::Array(mut values) if values.len() == 2 => {
+                    let keys = values.pop().unwrap();
+                    values.push(self.strip_keys(keys));
+                    Value::Array(values)
+                }
+                reply => reply,
+            },
+            Some(
+                b"BLPOP" | b"BRPOP" | b"BZPOPMIN" | b"BZPOPMAX" | b"LMPOP" | b"BLMPOP" | b"ZMPOP"
+                | b"BZMPOP",
+            ) => self.strip_first_key(reply),
+            Some(b"XREAD" | b"XREADGROUP") => match reply {
+                Value::Array(streams) => Value::Array(
+                    streams
+                        .into_iter()
+                        .map(|stream| self.strip_first_key(stream))
+                        .collect(),
+                ),
+                Value::Map(streams) => Value::Map(
+                    streams
+                        .into_iter()
+                        .map(|(key, entries)| (self.strip_key(key), entries))
+                        .collect(),
+                ),
+                reply => reply,
+            },
+            _ => reply,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::cluster_routing::RoutingInfo;
+
+    fn command(args: &[&str]) -> Cmd {
+        let mut cmd = redis::cmd(args[0]);
+        for arg in &args[1..] {
+            cmd.arg(*arg);
+        }
+        cmd
+    }
+
+    fn bulk(value: &str) -> Value {
+        Value::BulkString(value.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_keys_are_prefixed_before_routing() {
+        let key_prefix = KeyPrefix::new("app:");
+        let prefixed = key_prefix.prefix_command(&command(&["MSET", "foo", "1", "bar", "2"]));
+        assert_eq!(
+            prefixed.get_packed_command(),
+            command(&["MSET", "app:foo", "1", "app:bar", "2"]).get_packed_command()
+        );
+
+        let prefixed = key_prefix.prefix_command(&command(&["EVAL", "script", "1", "foo", "bar"]));
+        assert_eq!(
+            prefixed.get_packed_command(),
+            command(&["EVAL", "script", "1", "app:foo", "bar"]).get_packed_command()
+        );
+        assert_eq!(
+            RoutingInfo::for_routable(&prefixed),
+            RoutingInfo::for_routable(&command(&["SET", "app:foo", "1"]))
+        );
+    }
+
+    #[test]
+    fn test_patterns_are_limited_to_the_namespace() {
+        let key_prefix = KeyPrefix::new("app*:");
+        let prefixed = key_prefix.prefix_command(&command(&["KEYS", "foo*"]));
+        assert_eq!(
+            prefixed.get_packed_command(),
+            command(&["KEYS", "app\\*:foo*"]).get_packed_command()
+        );
+
+        let prefixed = key_prefix.prefix_command(&command(&["SCAN", "0", "MATCH", "foo*"]));
+        assert_eq!(
+            prefixed.get_packed_command(),
+            command(&["SCAN", "0", "MATCH", "app\\*:foo*"]).get_packed_command()
+        );
+
+        let prefixed = key_prefix.prefix_command(&command(&["SCAN", "0", "COUNT", "10"]));
+        assert_eq!(
+            prefixed.get_packed_command(),
+            command(&["SCAN", "0", "COUNT", "10", "MATCH", "app\\*:*"]).get_packed_command()
+        );
+    }
+
+    #[test]
+    fn test_prefix_is_stripped_from_replies() {
+        let key_prefix = KeyPrefix::new("app:");
+        assert_eq!(
+            key_prefix.strip_reply(
+                &command(&["KEYS", "*"]),
+                Value::Array(vec![bulk("app:foo"), bulk("app:bar")])
+            ),
+            Value::Array(vec![bulk("foo"), bulk("bar")])
+        );
+        assert_eq!(
+            key_prefix.strip_reply(
+                &command(&["SCAN", "0"]),
+                Value::Array(vec![bulk("0"), Value::Array(vec![bulk("app:foo")])])
+            ),
+            Value::Array(vec![bulk("0"), Value::Array(vec![bulk("foo")])])
+        );
+        assert_eq!(
+            key_prefix.strip_reply(
+                &command(&["BLPOP", "foo", "0"]),
+                Value::Array(vec![bulk("app:foo"), bulk("app:value")])
+            ),
+            Value::Array(vec![bulk("foo"), bulk("app:value")])
+        );
+        assert_eq!(
+            key_prefix.strip_reply(&command(&["GET", "foo"]), bulk("app:value")),
+            bulk("app:value")
+        );
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use redis::aio::{ConnectionLike, MultiplexedConnection};
+use redis::{Cmd, ErrorKind, Pipeline, RedisError, RedisResult, Value};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::key_prefix::KeyPrefix;
+use super::value_conversion::{convert_to_expected_type, expected_type_for_cmd};
+use super::{Client, get_request_timeout, run_with_timeout};
+
+/// The outcome of a transaction executed on a [`LeasedConnection`].
+#[derive(Debug, PartialEq)]
+pub enum TransactionOutcome {
+    /// `EXEC` ran the transaction, and returned the responses of its commands.
+    Committed(Value),
+    /// `EXEC` discarded the transaction, because one of the watched keys was modified.
+    Aborted,
+}
+
+/// A connection to a single node, leased by [`Client::lease_connection`] for the exclusive use of its holder.
+///
+/// Connection state such as `WATCH` is kept per connection, so commands that depend on it can't share the client's multiplexed connections.
+/// The connection isn't reconnected or redirected, and it's closed when the lease is dropped, which also discards its watches.
+pub struct LeasedConnection {
+    connection: MultiplexedConnection,
+    request_timeout: Duration,
+    key_prefix: Option<Arc<KeyPrefix>>,
+}
+
+impl LeasedConnection {
+    pub(super) fn new(
+        connection: MultiplexedConnection,
+        request_timeout: Duration,
+        key_prefix: Option<Arc<KeyPrefix>>,
+    ) -> Self {
+        Self {
+            connection,
+            request_timeout,
+            key_prefix,
+        }
+    }
+
+    /// Send a command on the leased connection, such as `WATCH`, `UNWATCH` or the reads that a transaction depends on.
+    pub async fn send_command(&mut self, cmd: &Cmd) -> RedisResult<Value> {
+        let prefixed_cmd = self
+            .key_prefix
+            .as_ref()
+            .map(|key_prefix| key_prefix.prefix_command(cmd));
+        let cmd = prefixed_cmd.as_ref().unwrap_or(cmd);
+        let request_timeout = get_request_timeout(cmd, self.request_timeout)?;
+        let expected_type = expected_type_for_cmd(cmd);
+        let value =
+            run_with_timeout(request_timeout, self.connection.send_packed_command(cmd)).await?;
+        let value = match &self.key_prefix {
+            Some(key_prefix) => key_prefix.strip_reply(cmd, value),
+            None => value,
+        };
+        convert_to_expected_type(value, expected_type)
+    }
+
+    /// Execute the atomic `pipeline` with `MULTI`/`EXEC` on the leased connection.
+    /// Returns [`TransactionOutcome::Aborted`] if a key watched on this connection was modified since it was watched.
+    pub async fn exec_transaction(
+        &mut self,
+        pipeline: &Pipeline,
+        raise_on_error: bool,
+    ) -> RedisResult<TransactionOutcome> {
+        if !pipeline.is_atomic() {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "Only atomic pipelines can be executed as transactions",
+            )));
+        }
+        let prefixed_pipeline = self
+            .key_prefix
+            .as_ref()
+            .map(|key_prefix| key_prefix.prefix_pipeline(pipeline));
+        let pipeline = prefixed_pipeline.as_ref().unwrap_or(pipeline);
+        let command_count = pipeline.cmd_iter().count();
+        // Skip the responses of `MULTI` and of each queued command, and keep only the response of `EXEC`.
+        let offset = command_count + 1;
+        let values = run_with_timeout(
+            Some(self.request_timeout),
+            self.connection
+                .req_packed_commands(pipeline, offset, 1, None),
+        )
+        .await?;
+        match Client::get_transaction_values(
+            pipeline,
+            values,
+            command_count,
+            offset,
+            raise_on_error,
+            self.key_prefix.as_deref(),
+        )? {
+            Value::Nil => Ok(TransactionOutcome::Aborted),
+            value => Ok(TransactionOutcome::Committed(value)),
+        }
+    }
+}
@@ -3,6 +3,7 @@
 mod types;
 
 use crate::cluster_scan_container::insert_cluster_scan_cursor;
+use crate::ft_cursor_container::{FtCursor, get_ft_cursor, insert_ft_cursor, remove_ft_cursor};
 use crate::scripts_container::get_script;
 use futures::FutureExt;
 use logger_core::{log_error, log_info, log_warn};
@@ -10,25 +11,40 @@ use once_cell::sync::OnceCell;
 use redis::aio::ConnectionLike;
 use redis::cluster_async::ClusterConnection;
 use redis::cluster_routing::{
-    MultipleNodeRoutingInfo, ResponsePolicy, Routable, RoutingInfo, SingleNodeRoutingInfo,
+    MultipleNodeRoutingInfo, ResponsePolicy, Routable, Route, RoutingInfo, SingleNodeRoutingInfo,
+    SlotAddr,
 };
 use redis::cluster_slotmap::ReadFromReplicaStrategy;
 use redis::{
-    ClusterScanArgs, Cmd, ErrorKind, FromRedisValue, PipelineRetryStrategy, PushInfo, RedisError,
-    RedisResult, RetryStrategy, ScanStateRC, Value,
+    AdaptiveTimeout, ClusterScanArgs, Cmd, ErrorKind, FromRedisValue, PipelineRetryStrategy,
+    PubSubChannelOrPattern, PubSubSubscriptionKind, PushInfo, RedisError, RedisResult,
+    RequestDeadline, RetryBudget, RetryStrategy, ScanStateRC, Value,
 };
 pub use standalone_client::StandaloneClient;
 use std::io;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicIsize, Ordering};
+use std::sync::atomic::{AtomicIsize, AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
 use std::thread;
 use std::thread::JoinHandle;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::runtime::{Builder, Handle};
 pub use types::*;
 
+use self::client_side_cache::{CacheLookup, ClientSideCache};
+use self::command_retry::is_safe_to_retry;
+use self::key_prefix::KeyPrefix;
 use self::value_conversion::{convert_to_expected_type, expected_type_for_cmd, get_value_type};
+mod client_side_cache;
+mod command_retry;
+mod credential_provider;
+mod key_prefix;
+pub use credential_provider::{CredentialProvider, Credentials};
+mod connection_uri;
+pub use connection_uri::parse_connection_uri;
+mod leased_connection;
+pub use leased_connection::{LeasedConnection, TransactionOutcome};
 mod reconnecting_connection;
+mod sentinel;
 mod standalone_client;
 mod value_conversion;
 use redis::InfoDict;
@@ -44,6 +60,8 @@ pub const DEFAULT_PERIODIC_TOPOLOGY_CHECKS_INTERVAL: Duration = Duration::from_s
 /// Note: If you change the default value, make sure to change the documentation in *all* wrappers.
 pub const DEFAULT_CONNECTION_TIMEOUT: Duration = Duration::from_millis(250);
 pub const FINISHED_SCAN_CURSOR: &str = "finished";
+/// The id returned in place of an FT cursor once its results are exhausted, like the server does.
+pub const FINISHED_FT_CURSOR: &str = "0";
 
 /// The value of 1000 for the maximum number of inflight requests is determined based on Little's Law in queuing theory:
 ///
@@ -132,6 +150,14 @@ pub(super) fn get_port(address: &NodeAddress) -> u16 {
     }
 }
 
+/// Returns the address in the format reported by [`reconnecting_connection::ReconnectingConnection::node_address`].
+pub(super) fn format_node_address(address: &NodeAddress) -> String {
+    match &address.unix_socket_path {
+        Some(path) => path.clone(),
+        None => format!("{}:{}", address.host, get_port(address)),
+    }
+}
+
 pub(super) fn get_redis_connection_info(
     connection_request: &ConnectionRequest,
 ) -> redis::RedisConnectionInfo {
@@ -139,6 +165,10 @@ pub(super) fn get_redis_connection_info(
     let db = connection_request.database_id;
     let client_name = connection_request.client_name.clone();
     let pubsub_subscriptions = connection_request.pubsub_subscriptions.clone();
+    let client_tracking = connection_request
+        .client_side_cache
+        .as_ref()
+        .map(|client_side_cache| client_side_cache.tracking_mode.clone());
     match &connection_request.authentication_info {
         Some(info) => redis::RedisConnectionInfo {
             db,
@@ -147,28 +177,91 @@ pub(super) fn get_redis_connection_info(
             protocol,
             client_name,
             pubsub_subscriptions,
+            client_tracking,
         },
         None => redis::RedisConnectionInfo {
             db,
             protocol,
             client_name,
             pubsub_subscriptions,
+            client_tracking,
             ..Default::default()
         },
     }
 }
 
+/// Returns the certificates of the request's TLS configuration.
+fn get_tls_certificates(
+    request: &ConnectionRequest,
+) -> RedisResult<Option<redis::TlsCertificates>> {
+    let Some(tls_configuration) = &request.tls_configuration else {
+        return Ok(None);
+    };
+    if request.tls_mode.unwrap_or_default() == TlsMode::NoTls {
+        return Err(RedisError::from((
+            ErrorKind::InvalidClientConfig,
+            "TLS configuration was provided, but TLS is disabled",
+        )));
+    }
+    let client_tls = match (
+        tls_configuration.client_cert.clone(),
+        tls_configuration.client_key.clone(),
+    ) {
+        (Some(client_cert), Some(client_key)) => Some(redis::ClientTlsConfig {
+            client_cert,
+            client_key,
+        }),
+        (None, None) => None,
+        _ => {
+            return Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "Mutual TLS requires both a client certificate and a client key",
+            )));
+        }
+    };
+    let root_cert = if tls_configuration.root_certs.is_empty() {
+        None
+    } else {
+        Some(tls_configuration.root_certs.join(&b'\n'))
+    };
+    Ok(Some(redis::TlsCertificates {
+        client_tls,
+        root_cert,
+        server_name: tls_configuration.server_name.clone(),
+    }))
+}
+
+/// Parses the certificates of the request's TLS configuration into the parameters used by the connections.
+pub(super) fn get_tls_params(
+    request: &ConnectionRequest,
+) -> RedisResult<Option<redis::TlsConnParams>> {
+    get_tls_certificates(request)?
+        .map(|certificates| {
+            redis::retrieve_tls_certificates(certificates).map_err(|err| {
+                RedisError::from((
+                    ErrorKind::InvalidClientConfig,
+                    "Invalid TLS configuration",
+                    err.to_string(),
+                ))
+            })
+        })
+        .transpose()
+}
+
 pub(super) fn get_connection_info(
     address: &NodeAddress,
     tls_mode: TlsMode,
+    tls_params: Option<redis::TlsConnParams>,
     redis_connection_info: redis::RedisConnectionInfo,
 ) -> redis::ConnectionInfo {
-    let addr = if tls_mode != TlsMode::NoTls {
+    let addr = if let Some(path) = &address.unix_socket_path {
+        redis::ConnectionAddr::Unix(path.into())
+    } else if tls_mode != TlsMode::NoTls {
         redis::ConnectionAddr::TcpTls {
             host: address.host.to_string(),
             port: get_port(address),
             insecure: tls_mode == TlsMode::InsecureTls,
-            tls_params: None,
+            tls_params,
         }
     } else {
         redis::ConnectionAddr::Tcp(address.host.to_string(), get_port(address))
@@ -196,9 +289,43 @@ pub struct LazyClient {
 #[derive(Clone)]
 pub struct Client {
     internal_client: Arc<RwLock<ClientWrapper>>,
+    state: Arc<ClientState>,
+}
+
+/// The state of a [`Client`] that's shared by all of its clones, besides its connections.
+struct ClientState {
     request_timeout: Duration,
     // Setting this counter to limit the inflight requests, in case of any queue is blocked, so we return error to the customer.
-    inflight_requests_allowed: Arc<AtomicIsize>,
+    inflight_requests_allowed: AtomicIsize,
+    inflight_overflow_policy: InflightOverflowPolicy,
+    // Wakes up requests waiting for a free inflight request slot.
+    inflight_request_released: Notify,
+    inflight_requests_waiting: AtomicUsize,
+    client_side_cache: Option<Arc<ClientSideCache>>,
+    command_retry_strategy: Option<RetryStrategy>,
+    // Caps the retries of the client, shared with the cluster client which retries internally.
+    retry_budget: Option<Arc<RetryBudget>>,
+    // Derives the request timeout from the latencies that the underlying client records per node.
+    adaptive_timeout: Option<Arc<AdaptiveTimeout>>,
+    // Namespaces the keys of the client's commands.
+    key_prefix: Option<Arc<KeyPrefix>>,
+}
+
+/// A handle to a [`Client`] that doesn't keep its connections open, for state that may outlive the client, such as FT cursors.
+#[derive(Clone)]
+pub(crate) struct WeakClient {
+    internal_client: Weak<RwLock<ClientWrapper>>,
+    state: Arc<ClientState>,
+}
+
+impl WeakClient {
+    /// Returns the client, unless it was dropped.
+    pub(crate) fn upgrade(&self) -> Option<Client> {
+        Some(Client {
+            internal_client: self.internal_client.upgrade()?,
+            state: self.state.clone(),
+        })
+    }
 }
 
 async fn run_with_timeout<T>(
@@ -223,6 +350,19 @@ async fn run_with_timeout<T>(
     }
 }
 
+/// Runs `future` until `deadline`, and fails with the deadline's timeout error, which says in which stage the time was spent.
+async fn run_with_deadline<T>(
+    deadline: Option<Arc<RequestDeadline>>,
+    future: impl futures::Future<Output = RedisResult<T>> + Send,
+) -> redis::RedisResult<T> {
+    match deadline {
+        Some(deadline) => tokio::time::timeout(deadline.remaining(), future)
+            .await
+            .unwrap_or_else(|_| Err(deadline.timeout_error())),
+        None => future.await,
+    }
+}
+
 /// Extension to the request timeout for blocking commands to ensure we won't return with timeout error before the server responded
 const BLOCKING_CMD_TIMEOUT_EXTENSION: f64 = 0.5; // seconds
 
@@ -305,6 +445,53 @@ fn get_timeout_from_cmd_arg(
     }
 }
 
+async fn send_command_to_client(
+    client: ClientWrapper,
+    cmd: &Cmd,
+    routing: Option<RoutingInfo>,
+    deadline: Option<Arc<RequestDeadline>>,
+) -> RedisResult<Value> {
+    match client {
+        ClientWrapper::Standalone(mut client) => client.send_command(cmd).await,
+        ClientWrapper::Cluster { mut client } => {
+            let final_routing = if let Some(RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::Random,
+            )) = routing
+            {
+                let cmd_name = cmd.command().unwrap_or_default();
+                let cmd_name = String::from_utf8_lossy(&cmd_name);
+                if redis::cluster_routing::is_readonly_cmd(cmd_name.as_bytes()) {
+                    // A read-only command, go ahead and send it to a random node
+                    RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random)
+                } else {
+                    // A "Random" node was selected, but the command is a "@write" command
+                    // change the routing to "RandomPrimary"
+                    log_warn(
+                        "send_command",
+                        format!(
+                            "User provided 'Random' routing which is not suitable for the writeable command '{cmd_name}'. Changing it to 'RandomPrimary'"
+                        ),
+                    );
+                    RoutingInfo::SingleNode(SingleNodeRoutingInfo::RandomPrimary)
+                }
+            } else {
+                routing
+                    .or_else(|| RoutingInfo::for_routable(cmd))
+                    .unwrap_or(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random))
+            };
+            match deadline {
+                Some(deadline) => {
+                    client
+                        .route_command_with_deadline(cmd, final_routing, deadline)
+                        .await
+                }
+                None => client.route_command(cmd, final_routing).await,
+            }
+        }
+        ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
+    }
+}
+
 fn get_request_timeout(cmd: &Cmd, default_timeout: Duration) -> RedisResult<Option<Duration>> {
     let command = cmd.command().unwrap_or_default();
     let timeout = match command.as_slice() {
@@ -330,6 +517,14 @@ fn get_request_timeout(cmd: &Cmd, default_timeout: Duration) -> RedisResult<Opti
 }
 
 impl Client {
+    /// Returns a handle to the client that doesn't keep its connections open.
+    pub(crate) fn downgrade(&self) -> WeakClient {
+        WeakClient {
+            internal_client: Arc::downgrade(&self.internal_client),
+            state: self.state.clone(),
+        }
+    }
+
     async fn get_or_initialize_client(&self) -> RedisResult<ClientWrapper> {
         {
             let guard = self.internal_client.read().await;
@@ -351,19 +546,30 @@ impl Client {
             // Create the appropriate client based on configuration
             let real_client = if config.cluster_mode_enabled {
                 // Create cluster client
-                let client = create_cluster_client(config, push_sender).await?;
+                let client = create_cluster_client(
+                    config,
+                    push_sender,
+                    self.state.retry_budget.clone(),
+                    self.state.adaptive_timeout.clone(),
+                )
+                .await?;
                 ClientWrapper::Cluster { client }
             } else {
                 // Create standalone client
-                let client = StandaloneClient::create_client(config, push_sender)
-                    .await
-                    .map_err(|e| {
-                        RedisError::from((
-                            ErrorKind::IoError,
-                            "Standalone connect failed",
-                            format!("{e:?}"),
-                        ))
-                    })?;
+                let client = StandaloneClient::create_client_with_shared_state(
+                    config,
+                    push_sender,
+                    self.state.adaptive_timeout.clone(),
+                    self.state.retry_budget.clone(),
+                )
+                .await
+                .map_err(|e| {
+                    RedisError::from((
+                        ErrorKind::IoError,
+                        "Standalone connect failed",
+                        format!("{e:?}"),
+                    ))
+                })?;
                 ClientWrapper::Standalone(client)
             };
 
@@ -374,61 +580,305 @@ impl Client {
         Ok(guard.clone()) // ✅ Return clone of the now-initialized wrapper
     }
 
+    /// Returns the timeout of `cmd` if it isn't blocking: `timeout` if it's set and not zero, and otherwise the client's request timeout,
+    /// which is derived from the observed latencies when adaptive timeouts are enabled, unless `cmd` is known to be slow.
+    fn command_request_timeout(&self, cmd: &Cmd, timeout: Option<u32>) -> Duration {
+        match (
+            timeout.filter(|timeout| *timeout != 0),
+            &self.state.adaptive_timeout,
+        ) {
+            (None, Some(adaptive_timeout)) if AdaptiveTimeout::applies_to(cmd) => {
+                adaptive_timeout.timeout()
+            }
+            _ => to_duration(timeout, self.state.request_timeout),
+        }
+    }
+
+    /// Strips the key prefix from the keys in the reply of the prefixed `cmd`.
+    fn strip_key_prefix(&self, cmd: &Cmd, value: Value) -> Value {
+        match &self.state.key_prefix {
+            Some(key_prefix) => key_prefix.strip_reply(cmd, value),
+            None => value,
+        }
+    }
+
     /// Send a command to the server.
     /// This function will route the command to the correct node, and retry if needed.
     pub fn send_command<'a>(
         &'a mut self,
         cmd: &'a Cmd,
         routing: Option<RoutingInfo>,
+    ) -> redis::RedisFuture<'a, Value> {
+        self.send_command_with_timeout(cmd, routing, None)
+    }
+
+    /// Send a command to the server, like [`Client::send_command`].
+    /// `timeout` is in milliseconds, and overrides the client's request timeout for this command only. A zero `timeout` uses the client's request timeout.
+    /// Blocking commands still derive their timeout from their own arguments.
+    pub fn send_command_with_timeout<'a>(
+        &'a mut self,
+        cmd: &'a Cmd,
+        routing: Option<RoutingInfo>,
+        timeout: Option<u32>,
+    ) -> redis::RedisFuture<'a, Value> {
+        self.send_command_with_options(cmd, routing, timeout, false)
+    }
+
+    /// Send a command to the server, like [`Client::send_command_with_timeout`].
+    /// If `disable_retries` is set, the command isn't retried by the client's command retry strategy.
+    pub fn send_command_with_options<'a>(
+        &'a mut self,
+        cmd: &'a Cmd,
+        routing: Option<RoutingInfo>,
+        timeout: Option<u32>,
+        disable_retries: bool,
+    ) -> redis::RedisFuture<'a, Value> {
+        Box::pin(async move {
+            match cmd.command().as_deref() {
+                Some(b"FT.AGGREGATE") if cmd.position(b"WITHCURSOR").is_some() => {
+                    self.send_ft_aggregate_with_cursor(cmd, routing, timeout, disable_retries)
+                        .await
+                }
+                Some(b"FT.CURSOR READ") | Some(b"FT.CURSOR DEL") => {
+                    self.send_ft_cursor_command(cmd, timeout, disable_retries)
+                        .await
+                }
+                _ => {
+                    self.send_single_command(cmd, routing, timeout, disable_retries)
+                        .await
+                }
+            }
+        })
+    }
+
+    /// Sends a command like [`Client::send_command_with_options`], without managing the FT cursors it creates or uses.
+    pub(crate) fn send_single_command<'a>(
+        &'a mut self,
+        cmd: &'a Cmd,
+        routing: Option<RoutingInfo>,
+        timeout: Option<u32>,
+        disable_retries: bool,
     ) -> redis::RedisFuture<'a, Value> {
         Box::pin(async move {
+            // The keys are prefixed before the command is looked up in the cache and routed.
+            let prefixed_cmd = self
+                .state
+                .key_prefix
+                .as_ref()
+                .map(|key_prefix| key_prefix.prefix_command(cmd));
+            let cmd = prefixed_cmd.as_ref().unwrap_or(cmd);
+
+            // Multi-node commands aggregate responses from several nodes, so they aren't served from the cache.
+            let cache_lookup = match &self.state.client_side_cache {
+                Some(cache) if !matches!(routing, Some(RoutingInfo::MultiNode(_))) => {
+                    cache.get(cmd)
+                }
+                _ => CacheLookup::NotCacheable,
+            };
+            if let CacheLookup::Hit(value) = cache_lookup {
+                return Ok(value);
+            }
+
             let client = self.get_or_initialize_client().await?;
+            // The cluster client adds its requests to the retry budget.
+            if let (Some(budget), ClientWrapper::Standalone(_)) =
+                (&self.state.retry_budget, &client)
+            {
+                budget.record_request();
+            }
 
             let expected_type = expected_type_for_cmd(cmd);
-            let request_timeout = match get_request_timeout(cmd, self.request_timeout) {
-                Ok(request_timeout) => request_timeout,
-                Err(err) => return Err(err),
+            let request_timeout =
+                match get_request_timeout(cmd, self.command_request_timeout(cmd, timeout)) {
+                    Ok(request_timeout) => request_timeout,
+                    Err(err) => return Err(err),
+                };
+
+            let mut retry_backoff = self
+                .state
+                .command_retry_strategy
+                .filter(|_| !disable_retries)
+                .map(|strategy| strategy.get_bounded_backoff_dur_iterator());
+            // Retries share the request timeout, so a retried command fails within the same time as any other command.
+            let request_deadline = request_timeout.map(|timeout| Instant::now() + timeout);
+            let result = loop {
+                let deadline = request_deadline.map(|request_deadline| {
+                    Arc::new(RequestDeadline::new(
+                        request_deadline.saturating_duration_since(Instant::now()),
+                    ))
+                });
+                let result = run_with_deadline(
+                    deadline.clone(),
+                    send_command_to_client(client.clone(), cmd, routing.clone(), deadline),
+                )
+                .await
+                .map(|value| self.strip_key_prefix(cmd, value))
+                .and_then(|value| convert_to_expected_type(value, expected_type));
+                match result {
+                    Err(err) if is_safe_to_retry(cmd, &err) => {
+                        let Some(backoff) = retry_backoff.as_mut().and_then(Iterator::next) else {
+                            break Err(err);
+                        };
+                        if request_deadline.is_some_and(|request_deadline| {
+                            Instant::now() + backoff >= request_deadline
+                        }) {
+                            break Err(err);
+                        }
+                        if self
+                            .state
+                            .retry_budget
+                            .as_ref()
+                            .is_some_and(|budget| !budget.try_withdraw())
+                        {
+                            break Err(err);
+                        }
+                        log_warn(
+                            "send_command",
+                            format!("Retrying command in {backoff:?} after it failed with `{err}`"),
+                        );
+                        if let Err(e) = GlideOpenTelemetry::record_retry_attempt() {
+                            log_error(
+                                "OpenTelemetry:retry_error",
+                                format!("Failed to record retry attempt: {e}"),
+                            );
+                        }
+                        tokio::time::sleep(backoff).await;
+                    }
+                    result => break result,
+                }
             };
 
-            let value = run_with_timeout(request_timeout, async move {
-                match client {
-                    ClientWrapper::Standalone(mut client) => client.send_command(cmd).await,
-                    ClientWrapper::Cluster {mut client } => {
-                        let final_routing =
-                            if let Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random)) =
-                                routing
-                            {
-                                let cmd_name = cmd.command().unwrap_or_default();
-                                let cmd_name = String::from_utf8_lossy(&cmd_name);
-                                if redis::cluster_routing::is_readonly_cmd(cmd_name.as_bytes()) {
-                                // A read-only command, go ahead and send it to a random node
-                                    RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random)
-                                } else {
-                                // A "Random" node was selected, but the command is a "@write" command
-                                // change the routing to "RandomPrimary"
-                                    log_warn(
-                                        "send_command",
-                                        format!(
-                                            "User provided 'Random' routing which is not suitable for the writeable command '{cmd_name}'. Changing it to 'RandomPrimary'"
-                                        ),
-                                    );
-                                    RoutingInfo::SingleNode(SingleNodeRoutingInfo::RandomPrimary)
-                                }
-                            } else {
-                                routing
-                                    .or_else(|| RoutingInfo::for_routable(cmd))
-                                    .unwrap_or(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random))
-                            };
-                        client.route_command(cmd, final_routing).await
-                    },
-                    ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
+            if let Some(cache) = &self.state.client_side_cache {
+                match (cache_lookup, &result) {
+                    (CacheLookup::Miss(generation), Ok(value)) => {
+                        cache.insert(cmd, value.clone(), generation)
+                    }
+                    (CacheLookup::Miss(_), Err(_)) => {}
+                    // A failed write might still have been applied.
+                    (_, Err(_)) => cache.invalidate_written_keys(cmd),
+                    (_, Ok(_)) if cmd.command().as_deref() == Some(b"SELECT") => {
+                        if let Some(db) = cmd
+                            .arg_idx(1)
+                            .and_then(|db| std::str::from_utf8(db).ok())
+                            .and_then(|db| db.parse().ok())
+                        {
+                            cache.select_db(db);
+                        }
+                    }
+                    (_, Ok(_)) => cache.invalidate_written_keys(cmd),
                 }
-                .and_then(|value| convert_to_expected_type(value, expected_type))
-            })
+            }
+            result
+        })
+    }
+
+    /// Returns the routing of a command that creates an FT cursor.
+    /// The cursor is kept by the node that serves the command, so in cluster mode the routing is resolved to that node's address,
+    /// and the following commands of the cursor are sent there, even if the topology changes.
+    /// A standalone client sends the commands of cursors to the primary.
+    async fn pin_ft_cursor_routing(
+        &self,
+        cmd: &Cmd,
+        routing: Option<RoutingInfo>,
+    ) -> RedisResult<Option<RoutingInfo>> {
+        let ClientWrapper::Cluster { mut client } = self.get_or_initialize_client().await? else {
+            return Ok(routing);
+        };
+        let route = match &routing {
+            Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress { .. })) => {
+                return Ok(routing);
+            }
+            Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(route))) => *route,
+            Some(RoutingInfo::MultiNode(_)) => {
+                return Err(RedisError::from((
+                    ErrorKind::ClientError,
+                    "An FT cursor can only be created on a single node",
+                )));
+            }
+            // Random nodes are replaced by the node serving the index, like commands without routing.
+            _ => match RoutingInfo::for_routable(cmd) {
+                Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(route))) => route,
+                _ => return Ok(routing),
+            },
+        };
+        let node = client.pin_route(route).await?;
+        Ok(Some(RoutingInfo::SingleNode(node)))
+    }
+
+    /// Sends `FT.AGGREGATE ... WITHCURSOR`, and stores the cursor in the FT cursor container if its results aren't exhausted.
+    /// The cursor id in the reply is replaced with the id of the stored cursor, which the following `FT.CURSOR` commands take.
+    async fn send_ft_aggregate_with_cursor(
+        &mut self,
+        cmd: &Cmd,
+        routing: Option<RoutingInfo>,
+        timeout: Option<u32>,
+        disable_retries: bool,
+    ) -> RedisResult<Value> {
+        let routing = self.pin_ft_cursor_routing(cmd, routing).await?;
+        let value = self
+            .send_single_command(cmd, routing.clone(), timeout, disable_retries)
             .await?;
+        let Value::Array(mut page) = value else {
+            return Ok(value);
+        };
+        if let Some(last) = page.last_mut() {
+            let cursor = match *last {
+                Value::Int(0) => FINISHED_FT_CURSOR.to_string(),
+                Value::Int(cursor_id) => {
+                    let index = cmd.arg_idx(1).unwrap_or_default().to_vec();
+                    insert_ft_cursor(FtCursor::new(self.downgrade(), routing, index, cursor_id))
+                }
+                _ => return Ok(Value::Array(page)),
+            };
+            *last = Value::BulkString(cursor.into());
+        }
+        Ok(Value::Array(page))
+    }
 
-            Ok(value)
-        })
+    /// Sends `FT.CURSOR READ` or `FT.CURSOR DEL` for a cursor stored in the FT cursor container, to the node that holds the cursor.
+    /// The cursor is removed from the container once it's exhausted or deleted.
+    async fn send_ft_cursor_command(
+        &mut self,
+        cmd: &Cmd,
+        timeout: Option<u32>,
+        disable_retries: bool,
+    ) -> RedisResult<Value> {
+        let (Some(subcommand), Some(id)) = (cmd.arg_idx(1), cmd.arg_idx(3)) else {
+            return Err(RedisError::from((
+                ErrorKind::ClientError,
+                "FT.CURSOR requires an index name and a cursor id",
+            )));
+        };
+        let id = String::from_utf8_lossy(id).into_owned();
+        let cursor = get_ft_cursor(&id)?;
+        let args = (4..cmd.args_iter().len()).filter_map(|idx| cmd.arg_idx(idx));
+        let server_cmd = cursor.command(&subcommand.to_ascii_uppercase(), args);
+
+        if subcommand.eq_ignore_ascii_case(b"DEL") {
+            cursor.mark_released();
+            remove_ft_cursor(id);
+            return self
+                .send_single_command(&server_cmd, cursor.routing(), timeout, disable_retries)
+                .await;
+        }
+
+        let value = self
+            .send_single_command(&server_cmd, cursor.routing(), timeout, disable_retries)
+            .await?;
+        let Value::Array(mut page) = value else {
+            return Ok(value);
+        };
+        if let Some(last @ Value::Int(_)) = page.last_mut() {
+            *last = if *last == Value::Int(0) {
+                // The server releases a cursor once its results are exhausted.
+                cursor.mark_released();
+                remove_ft_cursor(id);
+                Value::BulkString(FINISHED_FT_CURSOR.into())
+            } else {
+                Value::BulkString(id.into())
+            };
+        }
+        Ok(Value::Array(page))
     }
 
     // Cluster scan is not passed to redis-rs as a regular command, so we need to handle it separately.
@@ -451,7 +901,10 @@ impl Client {
     ) -> RedisResult<Value> {
         // Clone arguments before the async block (ScanStateRC is Arc, clone is cheap)
         let scan_state_cursor_clone = scan_state_cursor.clone();
-        let cluster_scan_args_clone = cluster_scan_args.clone(); // Assuming ClusterScanArgs is Clone
+        let mut cluster_scan_args_clone = cluster_scan_args.clone(); // Assuming ClusterScanArgs is Clone
+        if let Some(key_prefix) = &self.state.key_prefix {
+            key_prefix.prefix_scan_args(&mut cluster_scan_args_clone);
+        }
 
         // Check and initialize if lazy *inside* the async block
         let client = self.get_or_initialize_client().await?;
@@ -464,6 +917,13 @@ impl Client {
                 let (cursor, keys) = client
                     .cluster_scan(scan_state_cursor_clone, cluster_scan_args_clone) // Use clones
                     .await?;
+                let keys = match &self.state.key_prefix {
+                    Some(key_prefix) => keys
+                        .into_iter()
+                        .map(|key| key_prefix.strip_key(key))
+                        .collect(),
+                    None => keys,
+                };
                 let cluster_cursor_id = if cursor.is_finished() {
                     Value::BulkString(FINISHED_SCAN_CURSOR.into()) // Use constant
                 } else {
@@ -482,6 +942,7 @@ impl Client {
         command_count: usize,
         offset: usize,
         raise_on_error: bool,
+        key_prefix: Option<&KeyPrefix>,
     ) -> RedisResult<Value> {
         assert_eq!(values.len(), 1);
         let value = values.pop();
@@ -515,6 +976,7 @@ impl Client {
             values,
             command_count,
             raise_on_error,
+            key_prefix,
         )
     }
 
@@ -523,6 +985,7 @@ impl Client {
         values: Vec<Value>,
         command_count: usize,
         raise_on_error: bool,
+        key_prefix: Option<&KeyPrefix>,
     ) -> RedisResult<Value> {
         let values = values
             .into_iter()
@@ -533,12 +996,14 @@ impl Client {
                     Ok(value)
                 }
             })
-            .zip(
-                pipeline
-                    .cmd_iter()
-                    .map(|cmd| expected_type_for_cmd(cmd.as_ref())),
-            )
-            .map(|(value, expected_type)| convert_to_expected_type(value?, expected_type))
+            .zip(pipeline.cmd_iter())
+            .map(|(value, cmd)| {
+                let value = match key_prefix {
+                    Some(key_prefix) => key_prefix.strip_reply(cmd, value?),
+                    None => value?,
+                };
+                convert_to_expected_type(value, expected_type_for_cmd(cmd.as_ref()))
+            })
             .try_fold(
                 Vec::with_capacity(command_count),
                 |mut acc, result| -> RedisResult<_> {
@@ -561,6 +1026,13 @@ impl Client {
     ) -> redis::RedisFuture<'a, Value> {
         Box::pin(async move {
             let client = self.get_or_initialize_client().await?;
+            let prefixed_pipeline = self
+                .state
+                .key_prefix
+                .as_ref()
+                .map(|key_prefix| key_prefix.prefix_pipeline(pipeline));
+            let pipeline = prefixed_pipeline.as_ref().unwrap_or(pipeline);
+            let key_prefix = self.state.key_prefix.as_deref();
 
             let command_count = pipeline.cmd_iter().count();
             // The offset is set to command_count + 1 to account for:
@@ -571,7 +1043,7 @@ impl Client {
             let offset = command_count + 1;
 
             run_with_timeout(
-                Some(to_duration(transaction_timeout, self.request_timeout)),
+                Some(to_duration(transaction_timeout, self.state.request_timeout)),
                 async move {
                     match client {
                         ClientWrapper::Standalone(mut client) => {
@@ -582,6 +1054,7 @@ impl Client {
                                 command_count,
                                 offset,
                                 raise_on_error,
+                                key_prefix,
                             )
                         }
                         ClientWrapper::Cluster { mut client } => {
@@ -603,6 +1076,7 @@ impl Client {
                                 command_count,
                                 offset,
                                 raise_on_error,
+                                key_prefix,
                             )
                         }
                         ClientWrapper::Lazy(_) => {
@@ -638,6 +1112,13 @@ impl Client {
     ) -> redis::RedisFuture<'a, Value> {
         Box::pin(async move {
             let client = self.get_or_initialize_client().await?;
+            let prefixed_pipeline = self
+                .state
+                .key_prefix
+                .as_ref()
+                .map(|key_prefix| key_prefix.prefix_pipeline(pipeline));
+            let pipeline = prefixed_pipeline.as_ref().unwrap_or(pipeline);
+            let key_prefix = self.state.key_prefix.as_deref();
 
             let command_count = pipeline.cmd_iter().count();
             if pipeline.is_empty() {
@@ -648,7 +1129,7 @@ impl Client {
             }
 
             run_with_timeout(
-                Some(to_duration(pipeline_timeout, self.request_timeout)),
+                Some(to_duration(pipeline_timeout, self.state.request_timeout)),
                 async move {
                     let values = match client {
                         ClientWrapper::Standalone(mut client) => {
@@ -688,6 +1169,7 @@ impl Client {
                         values,
                         command_count,
                         raise_on_error,
+                        key_prefix,
                     )
                 },
             )
@@ -695,17 +1177,22 @@ impl Client {
         })
     }
 
+    /// Invoke a script by its hash, loading it to the server first if it isn't cached there.
+    /// `timeout` is in milliseconds, and overrides the client's request timeout for each of the sent commands. A zero `timeout` uses the client's request timeout.
     pub async fn invoke_script<'a>(
         &'a mut self,
         hash: &'a str,
         keys: &Vec<&[u8]>,
         args: &Vec<&[u8]>,
         routing: Option<RoutingInfo>,
+        timeout: Option<u32>,
     ) -> redis::RedisResult<Value> {
         let _ = self.get_or_initialize_client().await?;
 
         let eval = eval_cmd(hash, keys, args);
-        let result = self.send_command(&eval, routing.clone()).await;
+        let result = self
+            .send_command_with_timeout(&eval, routing.clone(), timeout)
+            .await;
         let Err(err) = result else {
             return result;
         };
@@ -714,30 +1201,64 @@ impl Client {
                 return Err(err);
             };
             let load = load_cmd(&code);
-            self.send_command(&load, None).await?;
-            self.send_command(&eval, routing).await
+            self.send_command_with_timeout(&load, None, timeout).await?;
+            self.send_command_with_timeout(&eval, routing, timeout)
+                .await
         } else {
             Err(err)
         }
     }
 
+    /// Lease a dedicated connection, for commands that depend on per-connection state, such as `WATCH`-based optimistic transactions.
+    /// In cluster mode the connection is to the primary owning the slot of `key`, which is required.
+    /// In standalone mode the connection is to the primary, and `key` is ignored.
+    pub async fn lease_connection(&self, key: Option<&[u8]>) -> RedisResult<LeasedConnection> {
+        let connection = match self.get_or_initialize_client().await? {
+            ClientWrapper::Standalone(client) => client.create_dedicated_connection().await?,
+            ClientWrapper::Cluster { mut client } => {
+                let Some(key) = key else {
+                    return Err(RedisError::from((
+                        ErrorKind::ClientError,
+                        "A key is required to lease a connection in cluster mode",
+                    )));
+                };
+                let slot = match &self.state.key_prefix {
+                    Some(key_prefix) => {
+                        redis::cluster_topology::get_slot(&key_prefix.prefix_key(key))
+                    }
+                    None => redis::cluster_topology::get_slot(key),
+                };
+                let route = Route::new(slot, SlotAddr::Master);
+                client.create_dedicated_connection(route).await?
+            }
+            ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
+        };
+        Ok(LeasedConnection::new(
+            connection,
+            self.state.request_timeout,
+            self.state.key_prefix.clone(),
+        ))
+    }
+
     pub fn reserve_inflight_request(&self) -> bool {
         // We use this approach of checking the `inflight_requests_allowed` value
         // twice, before and after decrementing, to prevent it from reaching negative
         // values. Allowing the `inflight_requests_allowed` value to go below zero
         // could lead to a race condition where tasks might not be able to run even
         // when there are available slots.
-        if self.inflight_requests_allowed.load(Ordering::SeqCst) <= 0 {
+        if self.state.inflight_requests_allowed.load(Ordering::SeqCst) <= 0 {
             false
         } else {
             // The value is being checked again because it might have changed
             // during the intervening period since the load by other tasks.
             if self
+                .state
                 .inflight_requests_allowed
                 .fetch_sub(1, Ordering::SeqCst)
                 <= 0
             {
-                self.inflight_requests_allowed
+                self.state
+                    .inflight_requests_allowed
                     .fetch_add(1, Ordering::SeqCst);
                 return false;
             }
@@ -745,9 +1266,74 @@ impl Client {
         }
     }
 
+    /// Reserve an inflight request slot according to the client's [`InflightOverflowPolicy`].
+    /// Unlike [`Client::reserve_inflight_request`], this may wait for an inflight request to be released.
+    /// Returns false if no slot was reserved.
+    pub async fn acquire_inflight_request(&self) -> bool {
+        if self.reserve_inflight_request() {
+            return true;
+        }
+        let max_wait = match self.state.inflight_overflow_policy {
+            InflightOverflowPolicy::FailFast => return false,
+            InflightOverflowPolicy::Wait(max_wait) => {
+                self.state
+                    .inflight_requests_waiting
+                    .fetch_add(1, Ordering::SeqCst);
+                max_wait
+            }
+            InflightOverflowPolicy::Queue(max_queue_length) => {
+                if self
+                    .state
+                    .inflight_requests_waiting
+                    .fetch_add(1, Ordering::SeqCst)
+                    >= max_queue_length as usize
+                {
+                    self.state
+                        .inflight_requests_waiting
+                        .fetch_sub(1, Ordering::SeqCst);
+                    return false;
+                }
+                self.state.request_timeout
+            }
+        };
+        record_inflight_queue_depth_change(1);
+
+        let wait_start = Instant::now();
+        let reserved = tokio::time::timeout(max_wait, async {
+            loop {
+                // Register for a notification before checking, so a release between the check and the wait isn't missed.
+                let released = self.state.inflight_request_released.notified();
+                tokio::pin!(released);
+                released.as_mut().enable();
+                if self.reserve_inflight_request() {
+                    return;
+                }
+                released.await;
+            }
+        })
+        .await
+        .is_ok();
+
+        self.state
+            .inflight_requests_waiting
+            .fetch_sub(1, Ordering::SeqCst);
+        record_inflight_queue_depth_change(-1);
+        if let Err(e) = GlideOpenTelemetry::record_inflight_queue_wait_time(wait_start.elapsed()) {
+            log_error(
+                "OpenTelemetry:inflight_queue_wait_time",
+                format!("Failed to record inflight queue wait time: {e}"),
+            );
+        }
+        reserved
+    }
+
     pub fn release_inflight_request(&self) -> isize {
-        self.inflight_requests_allowed
-            .fetch_add(1, Ordering::SeqCst)
+        let previous = self
+            .state
+            .inflight_requests_allowed
+            .fetch_add(1, Ordering::SeqCst);
+        self.state.inflight_request_released.notify_one();
+        previous
     }
 
     /// Update the password used to authenticate with the servers.
@@ -760,7 +1346,7 @@ impl Client {
         password: Option<String>,
         immediate_auth: bool,
     ) -> RedisResult<Value> {
-        let timeout = self.request_timeout;
+        let timeout = self.state.request_timeout;
         // The password update operation is wrapped in a timeout to prevent it from blocking indefinitely.
         // If the operation times out, an error is returned.
         // Since the password update operation is not a command that go through the regular command pipeline,
@@ -793,6 +1379,71 @@ impl Client {
         }
     }
 
+    /// Subscribe to the given channels or patterns on the live client.
+    /// The subscriptions are added to the client's desired subscriptions, so they are restored after reconnections and topology changes.
+    pub async fn subscribe(
+        &mut self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<Value> {
+        self.update_pubsub_subscriptions(kind, channels_or_patterns, true)
+            .await
+    }
+
+    /// Unsubscribe from the given channels or patterns on the live client, and remove them from the client's desired subscriptions.
+    pub async fn unsubscribe(
+        &mut self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<Value> {
+        self.update_pubsub_subscriptions(kind, channels_or_patterns, false)
+            .await
+    }
+
+    async fn update_pubsub_subscriptions(
+        &mut self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+        subscribe: bool,
+    ) -> RedisResult<Value> {
+        let timeout = self.state.request_timeout;
+        // Like password updates, subscription updates don't go through the regular command pipeline, so the timeout is handled here.
+        match tokio::time::timeout(timeout, async {
+            let mut client = self.get_or_initialize_client().await?;
+            match client {
+                ClientWrapper::Standalone(ref client) if subscribe => {
+                    client
+                        .add_pubsub_subscriptions(kind, channels_or_patterns)
+                        .await
+                }
+                ClientWrapper::Standalone(ref client) => {
+                    client
+                        .remove_pubsub_subscriptions(kind, channels_or_patterns)
+                        .await
+                }
+                ClientWrapper::Cluster { ref mut client } if subscribe => {
+                    client
+                        .add_pubsub_subscriptions(kind, channels_or_patterns)
+                        .await
+                }
+                ClientWrapper::Cluster { ref mut client } => {
+                    client
+                        .remove_pubsub_subscriptions(kind, channels_or_patterns)
+                        .await
+                }
+                ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
+            }
+        })
+        .await
+        {
+            Ok(result) => result,
+            Err(_elapsed) => Err(RedisError::from((
+                ErrorKind::IoError,
+                "PubSub subscriptions update timed out, please check the connection",
+            ))),
+        }
+    }
+
     async fn send_immediate_auth(&mut self, password: Option<String>) -> RedisResult<Value> {
         match &password {
             Some(pw) if pw.is_empty() => Err(RedisError::from((
@@ -804,16 +1455,16 @@ impl Client {
                 "No password provided for authentication",
             ))),
             Some(password) => {
-                let routing = RoutingInfo::MultiNode((
-                    MultipleNodeRoutingInfo::AllNodes,
-                    Some(ResponsePolicy::AllSucceeded),
-                ));
-                let mut cmd = redis::cmd("AUTH");
-                if let Some(username) = self.get_username().await? {
-                    cmd.arg(username);
-                }
-                cmd.arg(password);
-                self.send_command(&cmd, Some(routing)).await
+                let cmd = auth_command(&AuthenticationInfo {
+                    username: self.get_username().await?,
+                    password: Some(password.clone()),
+                });
+                let client = self.get_or_initialize_client().await?;
+                run_with_timeout(
+                    Some(self.state.request_timeout),
+                    send_to_all_nodes(client, &cmd),
+                )
+                .await
             }
         }
     }
@@ -861,8 +1512,10 @@ fn eval_cmd(hash: &str, keys: &Vec<&[u8]>, args: &Vec<&[u8]>) -> Cmd {
     cmd
 }
 
+/// Converts a timeout in milliseconds to a duration. A missing or zero timeout uses `default`.
 fn to_duration(time_in_millis: Option<u32>, default: Duration) -> Duration {
     time_in_millis
+        .filter(|val| *val != 0)
         .map(|val| Duration::from_millis(val as u64))
         .unwrap_or(default)
 }
@@ -870,14 +1523,42 @@ fn to_duration(time_in_millis: Option<u32>, default: Duration) -> Duration {
 async fn create_cluster_client(
     request: ConnectionRequest,
     push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+    retry_budget: Option<Arc<RetryBudget>>,
+    adaptive_timeout: Option<Arc<AdaptiveTimeout>>,
 ) -> RedisResult<redis::cluster_async::ClusterConnection> {
+    if request.sentinel_configuration.is_some() {
+        return Err(RedisError::from((
+            ErrorKind::InvalidClientConfig,
+            "Sentinel configuration is only supported in standalone mode",
+        )));
+    }
+    // Cluster nodes are discovered by their IP and port, so a Unix domain socket can't be used to reach them.
+    if request
+        .addresses
+        .iter()
+        .any(|address| address.unix_socket_path.is_some())
+    {
+        return Err(RedisError::from((
+            ErrorKind::InvalidClientConfig,
+            "Unix domain sockets are only supported in standalone mode",
+        )));
+    }
     // TODO - implement timeout for each connection attempt
     let tls_mode = request.tls_mode.unwrap_or_default();
+    let tls_certificates = get_tls_certificates(&request)?;
+    let tls_params = get_tls_params(&request)?;
     let redis_connection_info = get_redis_connection_info(&request);
     let initial_nodes: Vec<_> = request
         .addresses
         .into_iter()
-        .map(|address| get_connection_info(&address, tls_mode, redis_connection_info.clone()))
+        .map(|address| {
+            get_connection_info(
+                &address,
+                tls_mode,
+                tls_params.clone(),
+                redis_connection_info.clone(),
+            )
+        })
         .collect();
     let periodic_topology_checks = match request.periodic_checks {
         Some(PeriodicCheck::Disabled) => None,
@@ -896,15 +1577,37 @@ async fn create_cluster_client(
             ReadFromReplicaStrategy::AZAffinityReplicasAndPrimary(az)
         }
         ReadFrom::PreferReplica => ReadFromReplicaStrategy::RoundRobin,
+        ReadFrom::LowestLatency => ReadFromReplicaStrategy::LowestLatency,
         ReadFrom::Primary => ReadFromReplicaStrategy::AlwaysFromPrimary,
     });
     if let Some(interval_duration) = periodic_topology_checks {
         builder = builder.periodic_topology_checks(interval_duration);
     }
+    if let Some(circuit_breaker) = request.circuit_breaker {
+        builder = builder.circuit_breaker(circuit_breaker);
+    }
+    if let Some(request_hedging) = request.request_hedging {
+        builder = builder.request_hedging(request_hedging);
+    }
+    if let Some(retry_budget) = retry_budget {
+        builder = builder.retry_budget(retry_budget);
+    }
+    if let Some(adaptive_timeout) = adaptive_timeout {
+        builder = builder.adaptive_timeout(adaptive_timeout);
+    }
+    if request.read_your_writes {
+        builder = builder.read_your_writes();
+    }
     builder = builder.use_protocol(request.protocol.unwrap_or_default());
     if let Some(client_name) = redis_connection_info.client_name {
         builder = builder.client_name(client_name);
     }
+    if let Some(client_tracking) = redis_connection_info.client_tracking {
+        builder = builder.client_tracking(client_tracking);
+    }
+    if let Some(tls_certificates) = tls_certificates {
+        builder = builder.certs(tls_certificates);
+    }
     if tls_mode != TlsMode::NoTls {
         let tls = if tls_mode == TlsMode::SecureTls {
             redis::cluster::TlsMode::Secure
@@ -989,6 +1692,8 @@ pub enum ConnectionError {
     Cluster(redis::RedisError),
     Timeout,
     IoError(std::io::Error),
+    /// The credential provider failed to return the initial credentials.
+    CredentialProvider(redis::RedisError),
 }
 
 impl std::fmt::Debug for ConnectionError {
@@ -997,6 +1702,9 @@ impl std::fmt::Debug for ConnectionError {
             Self::Standalone(arg0) => f.debug_tuple("Standalone").field(arg0).finish(),
             Self::Cluster(arg0) => f.debug_tuple("Cluster").field(arg0).finish(),
             Self::IoError(arg0) => f.debug_tuple("IoError").field(arg0).finish(),
+            Self::CredentialProvider(arg0) => {
+                f.debug_tuple("CredentialProvider").field(arg0).finish()
+            }
             Self::Timeout => write!(f, "Timeout"),
         }
     }
@@ -1009,6 +1717,9 @@ impl std::fmt::Display for ConnectionError {
             ConnectionError::Cluster(err) => write!(f, "{err}"),
             ConnectionError::IoError(err) => write!(f, "{err}"),
             ConnectionError::Timeout => f.write_str("connection attempt timed out"),
+            ConnectionError::CredentialProvider(err) => {
+                write!(f, "failed to fetch the initial credentials: {err}")
+            }
         }
     }
 }
@@ -1028,7 +1739,7 @@ fn sanitized_request_string(request: &ConnectionRequest) -> String {
     let addresses = request
         .addresses
         .iter()
-        .map(|address| format!("{}:{}", address.host, address.port))
+        .map(format_node_address)
         .collect::<Vec<_>>()
         .join(", ");
     let tls_mode = request
@@ -1062,6 +1773,7 @@ fn sanitized_request_string(request: &ConnectionRequest) -> String {
                 match rfr {
                     ReadFrom::Primary => "Only primary",
                     ReadFrom::PreferReplica => "Prefer replica",
+                    ReadFrom::LowestLatency => "Lowest latency replica",
                     ReadFrom::AZAffinity(_) => "Prefer replica in user's availability zone",
                     ReadFrom::AZAffinityReplicasAndPrimary(_) =>
                         "Prefer replica and primary in user's availability zone",
@@ -1108,9 +1820,109 @@ fn sanitized_request_string(request: &ConnectionRequest) -> String {
         "\nInflight requests limit: {}",
         request.inflight_requests_limit,
     );
+    let inflight_overflow_policy = match request.inflight_overflow_policy {
+        InflightOverflowPolicy::FailFast => String::new(),
+        policy => format!("\nInflight overflow policy: {policy:?}"),
+    };
+
+    let sentinel_configuration = request
+        .sentinel_configuration
+        .as_ref()
+        .map(|sentinel_configuration| {
+            format!(
+                "\nSentinels: {}, master name: {}",
+                sentinel_configuration
+                    .addresses
+                    .iter()
+                    .map(|address| format!("{}:{}", address.host, address.port))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                sentinel_configuration.master_name
+            )
+        })
+        .unwrap_or_default();
+
+    let client_side_cache = request
+        .client_side_cache
+        .as_ref()
+        .map(|client_side_cache| {
+            format!(
+                "\nClient side cache: max entries: {}, tracking mode: {:?}",
+                client_side_cache.max_entries, client_side_cache.tracking_mode
+            )
+        })
+        .unwrap_or_default();
+
+    let circuit_breaker = request
+        .circuit_breaker
+        .as_ref()
+        .map(|circuit_breaker| format!("\nCircuit breaker: {circuit_breaker:?}"))
+        .unwrap_or_default();
+
+    let command_retry_strategy = request
+        .command_retry_strategy
+        .as_ref()
+        .map(|strategy| format!("\nCommand retry strategy: {strategy:?}"))
+        .unwrap_or_default();
+
+    let request_hedging = request
+        .request_hedging
+        .as_ref()
+        .map(|request_hedging| format!("\nRequest hedging: {request_hedging:?}"))
+        .unwrap_or_default();
+
+    let retry_budget = request
+        .retry_budget
+        .as_ref()
+        .map(|retry_budget| format!("\nRetry budget: {retry_budget:?}"))
+        .unwrap_or_default();
+
+    let adaptive_timeout = request
+        .adaptive_timeout
+        .as_ref()
+        .map(|adaptive_timeout| format!("\nAdaptive timeout: {adaptive_timeout:?}"))
+        .unwrap_or_default();
+
+    let key_prefix = request
+        .key_prefix
+        .as_ref()
+        .map(|key_prefix| format!("\nKey prefix: {key_prefix}"))
+        .unwrap_or_default();
+
+    let read_your_writes = if request.read_your_writes {
+        "\nRead your writes: enabled"
+    } else {
+        ""
+    };
+
+    let primary_check_interval = request
+        .primary_check_interval
+        .filter(|_| !request.cluster_mode_enabled)
+        .map(|interval| format!("\nPrimary check interval: {interval:?}"))
+        .unwrap_or_default();
+
+    // Only whether certificates were provided is logged, never their content.
+    let tls_configuration = request
+        .tls_configuration
+        .as_ref()
+        .map(|tls_configuration| {
+            format!(
+                "\nTLS configuration: custom root certificates: {}, client certificate: {}, server name: {}",
+                tls_configuration.root_certs.len(),
+                tls_configuration.client_cert.is_some(),
+                tls_configuration.server_name.as_deref().unwrap_or("<host>")
+            )
+        })
+        .unwrap_or_default();
+
+    let credential_provider = if request.credential_provider.is_some() {
+        "\nCredential provider: enabled"
+    } else {
+        ""
+    };
 
     format!(
-        "\nAddresses: {addresses}{tls_mode}{cluster_mode}{request_timeout}{connection_timeout}{rfr_strategy}{connection_retry_strategy}{database_id}{protocol}{client_name}{periodic_checks}{pubsub_subscriptions}{inflight_requests_limit}",
+        "\nAddresses: {addresses}{tls_mode}{tls_configuration}{credential_provider}{cluster_mode}{request_timeout}{connection_timeout}{rfr_strategy}{connection_retry_strategy}{database_id}{protocol}{client_name}{periodic_checks}{pubsub_subscriptions}{inflight_requests_limit}{inflight_overflow_policy}{sentinel_configuration}{client_side_cache}{circuit_breaker}{request_hedging}{command_retry_strategy}{retry_budget}{adaptive_timeout}{key_prefix}{read_your_writes}{primary_check_interval}",
     )
 }
 
@@ -1129,33 +1941,100 @@ impl Client {
         let inflight_requests_limit = request
             .inflight_requests_limit
             .unwrap_or(DEFAULT_MAX_INFLIGHT_REQUESTS);
-        let inflight_requests_allowed = Arc::new(AtomicIsize::new(
-            inflight_requests_limit.try_into().unwrap(),
-        ));
+        let inflight_requests_allowed =
+            AtomicIsize::new(inflight_requests_limit.try_into().unwrap());
+        let inflight_overflow_policy = request.inflight_overflow_policy;
+        let client_side_cache = request.client_side_cache.as_ref().map(|configuration| {
+            Arc::new(ClientSideCache::new(configuration, request.database_id))
+        });
+        let command_retry_strategy = request.command_retry_strategy.map(|strategy| {
+            RetryStrategy::new(
+                strategy.exponent_base,
+                strategy.factor,
+                strategy.number_of_retries,
+                strategy.jitter_percent,
+            )
+        });
+        let retry_budget = request
+            .retry_budget
+            .map(|config| Arc::new(RetryBudget::new(config)));
+        let adaptive_timeout = request
+            .adaptive_timeout
+            .map(|config| Arc::new(AdaptiveTimeout::new(config, request_timeout)));
+        let key_prefix = request
+            .key_prefix
+            .as_ref()
+            .map(|prefix| Arc::new(KeyPrefix::new(prefix.as_str())));
+        let push_sender = match &client_side_cache {
+            Some(cache) => Some(forward_pushes_through_cache(cache.clone(), push_sender)),
+            None => push_sender,
+        };
 
         tokio::time::timeout(DEFAULT_CLIENT_CREATION_TIMEOUT, async move {
+            let mut request = request;
+            let credential_provider = request.credential_provider.clone();
+            let mut credentials_expire_in = None;
+            if let Some(provider) = &credential_provider {
+                let credentials = provider
+                    .get_credentials()
+                    .await
+                    .map_err(ConnectionError::CredentialProvider)?;
+                credentials_expire_in = credentials.expires_in;
+                request.authentication_info = Some(credentials.into());
+            }
+
             let internal_client = if request.lazy_connect {
                 ClientWrapper::Lazy(Box::new(LazyClient {
                     config: request,
                     push_sender,
                 }))
             } else if request.cluster_mode_enabled {
-                let client = create_cluster_client(request, push_sender)
-                    .await
-                    .map_err(ConnectionError::Cluster)?;
+                let client = create_cluster_client(
+                    request,
+                    push_sender,
+                    retry_budget.clone(),
+                    adaptive_timeout.clone(),
+                )
+                .await
+                .map_err(ConnectionError::Cluster)?;
                 ClientWrapper::Cluster { client }
             } else {
                 ClientWrapper::Standalone(
-                    StandaloneClient::create_client(request, push_sender)
-                        .await
-                        .map_err(ConnectionError::Standalone)?,
+                    StandaloneClient::create_client_with_shared_state(
+                        request,
+                        push_sender,
+                        adaptive_timeout.clone(),
+                        retry_budget.clone(),
+                    )
+                    .await
+                    .map_err(ConnectionError::Standalone)?,
                 )
             };
 
+            let internal_client = Arc::new(RwLock::new(internal_client));
+            if let Some(provider) = credential_provider {
+                credential_provider::start_credentials_refresh(
+                    provider,
+                    Arc::downgrade(&internal_client),
+                    credentials_expire_in,
+                    request_timeout,
+                );
+            }
+
             Ok(Self {
-                internal_client: Arc::new(RwLock::new(internal_client)),
-                request_timeout,
-                inflight_requests_allowed,
+                internal_client,
+                state: Arc::new(ClientState {
+                    request_timeout,
+                    inflight_requests_allowed,
+                    inflight_overflow_policy,
+                    inflight_request_released: Notify::new(),
+                    inflight_requests_waiting: AtomicUsize::new(0),
+                    client_side_cache,
+                    command_retry_strategy,
+                    retry_budget,
+                    adaptive_timeout,
+                    key_prefix,
+                }),
             })
         })
         .await
@@ -1163,6 +2042,96 @@ impl Client {
     }
 }
 
+fn record_inflight_queue_depth_change(delta: i64) {
+    if let Err(e) = GlideOpenTelemetry::record_inflight_queue_depth_change(delta) {
+        log_error(
+            "OpenTelemetry:inflight_queue_depth",
+            format!("Failed to record inflight queue depth: {e}"),
+        );
+    }
+}
+
+fn auth_command(authentication_info: &AuthenticationInfo) -> Cmd {
+    let mut cmd = redis::cmd("AUTH");
+    if let Some(username) = &authentication_info.username {
+        cmd.arg(username);
+    }
+    cmd.arg(authentication_info.password.as_deref().unwrap_or_default());
+    cmd
+}
+
+/// Sends the command to every node of the client, including replicas.
+async fn send_to_all_nodes(client: ClientWrapper, cmd: &Cmd) -> RedisResult<Value> {
+    match client {
+        ClientWrapper::Standalone(mut client) => client.send_command_to_all_nodes(cmd).await,
+        ClientWrapper::Cluster { mut client } => {
+            client
+                .route_command(
+                    cmd,
+                    RoutingInfo::MultiNode((
+                        MultipleNodeRoutingInfo::AllNodes,
+                        Some(ResponsePolicy::AllSucceeded),
+                    )),
+                )
+                .await
+        }
+        ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
+    }
+}
+
+/// Replaces the password used for reconnections, and re-authenticates all the connections of the client.
+/// A lazy client that isn't connected yet only stores the credentials for its first connection.
+async fn reauthenticate(
+    internal_client: &RwLock<ClientWrapper>,
+    authentication_info: AuthenticationInfo,
+    request_timeout: Duration,
+) -> RedisResult<Value> {
+    let client = {
+        let mut guard = internal_client.write().await;
+        if let ClientWrapper::Lazy(lazy_client) = &mut *guard {
+            lazy_client.config.authentication_info = Some(authentication_info);
+            return Ok(Value::Okay);
+        }
+        guard.clone()
+    };
+    let cmd = auth_command(&authentication_info);
+    run_with_timeout(Some(request_timeout), async move {
+        match &client {
+            ClientWrapper::Standalone(client) => {
+                client
+                    .update_connection_password(authentication_info.password)
+                    .await?;
+            }
+            ClientWrapper::Cluster { client } => {
+                client
+                    .clone()
+                    .update_connection_password(authentication_info.password)
+                    .await?;
+            }
+            ClientWrapper::Lazy(_) => unreachable!("Lazy client should have been initialized"),
+        }
+        send_to_all_nodes(client, &cmd).await
+    })
+    .await
+}
+
+/// Returns a push sender which applies invalidations to the client side cache, and then forwards the pushes to `push_sender`.
+fn forward_pushes_through_cache(
+    cache: Arc<ClientSideCache>,
+    push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+) -> mpsc::UnboundedSender<PushInfo> {
+    let (sender, mut receiver) = mpsc::unbounded_channel::<PushInfo>();
+    tokio::spawn(async move {
+        while let Some(push) = receiver.recv().await {
+            cache.handle_push(&push);
+            if let Some(push_sender) = &push_sender {
+                let _ = push_sender.send(push);
+            }
+        }
+    });
+    sender
+}
+
 pub trait GlideClientForTests {
     fn send_command<'a>(
         &'a mut self,
@@ -1198,10 +2167,14 @@ mod tests {
     use redis::Cmd;
 
     use crate::client::{
-        BLOCKING_CMD_TIMEOUT_EXTENSION, RequestTimeoutOption, TimeUnit, get_request_timeout,
+        BLOCKING_CMD_TIMEOUT_EXTENSION, ConnectionRequest, NodeAddress, RequestTimeoutOption,
+        TimeUnit, TlsConfiguration, TlsMode, get_request_timeout,
     };
 
-    use super::get_timeout_from_cmd_arg;
+    use super::{
+        create_cluster_client, format_node_address, get_connection_info, get_timeout_from_cmd_arg,
+        get_tls_certificates,
+    };
 
     #[test]
     fn test_get_timeout_from_cmd_returns_correct_duration_int() {
@@ -1350,4 +2323,101 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), Some(Duration::from_millis(100)));
     }
+
+    fn tls_request(tls_mode: TlsMode, tls_configuration: TlsConfiguration) -> ConnectionRequest {
+        ConnectionRequest {
+            tls_mode: Some(tls_mode),
+            tls_configuration: Some(tls_configuration),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_tls_certificates_joins_root_certs_and_keeps_server_name() {
+        let request = tls_request(
+            TlsMode::SecureTls,
+            TlsConfiguration {
+                root_certs: vec![b"first".to_vec(), b"second".to_vec()],
+                client_cert: Some(b"cert".to_vec()),
+                client_key: Some(b"key".to_vec()),
+                server_name: Some("cache.internal".to_string()),
+            },
+        );
+        let certificates = get_tls_certificates(&request).unwrap().unwrap();
+        assert_eq!(certificates.root_cert.unwrap(), b"first\nsecond");
+        let client_tls = certificates.client_tls.unwrap();
+        assert_eq!(client_tls.client_cert, b"cert");
+        assert_eq!(client_tls.client_key, b"key");
+        assert_eq!(certificates.server_name.as_deref(), Some("cache.internal"));
+    }
+
+    #[test]
+    fn test_get_tls_certificates_returns_err_on_invalid_configuration() {
+        let request = tls_request(TlsMode::NoTls, TlsConfiguration::default());
+        assert!(get_tls_certificates(&request).is_err());
+
+        let request = tls_request(
+            TlsMode::SecureTls,
+            TlsConfiguration {
+                client_cert: Some(b"cert".to_vec()),
+                ..Default::default()
+            },
+        );
+        let Err(err) = get_tls_certificates(&request) else {
+            panic!("Expected a missing client key to fail");
+        };
+        assert!(err.to_string().contains("client key"), "{err}");
+
+        let request = ConnectionRequest::default();
+        assert!(get_tls_certificates(&request).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_get_connection_info_uses_unix_socket_path() {
+        let address = NodeAddress {
+            host: String::new(),
+            port: 0,
+            unix_socket_path: Some("/tmp/valkey.sock".to_string()),
+        };
+        let connection_info =
+            get_connection_info(&address, TlsMode::NoTls, None, Default::default());
+        assert_eq!(
+            connection_info.addr,
+            redis::ConnectionAddr::Unix("/tmp/valkey.sock".into())
+        );
+        assert_eq!(
+            format_node_address(&address),
+            connection_info.addr.to_string()
+        );
+
+        let address = NodeAddress {
+            host: "localhost".to_string(),
+            port: 0,
+            unix_socket_path: None,
+        };
+        let connection_info =
+            get_connection_info(&address, TlsMode::NoTls, None, Default::default());
+        assert_eq!(format_node_address(&address), "localhost:6379");
+        assert_eq!(
+            format_node_address(&address),
+            connection_info.addr.to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_cluster_client_rejects_unix_socket_path() {
+        let request = ConnectionRequest {
+            cluster_mode_enabled: true,
+            addresses: vec![NodeAddress {
+                host: String::new(),
+                port: 0,
+                unix_socket_path: Some("/tmp/valkey.sock".to_string()),
+            }],
+            ..Default::default()
+        };
+        let Err(err) = create_cluster_client(request, None, None, None).await else {
+            panic!("Expected the Unix domain socket to be rejected in cluster mode");
+        };
+        assert_eq!(err.kind(), redis::ErrorKind::InvalidClientConfig);
+    }
 }
@@ -6,7 +6,8 @@ use futures_intrusive::sync::ManualResetEvent;
 use logger_core::{log_debug, log_error, log_trace, log_warn};
 use redis::aio::{DisconnectNotifier, MultiplexedConnection};
 use redis::{
-    GlideConnectionOptions, PushInfo, RedisConnectionInfo, RedisError, RedisResult, RetryStrategy,
+    ErrorKind, GlideConnectionOptions, ProtocolVersion, PubSubSubscriptionInfo, PushInfo,
+    RedisConnectionInfo, RedisError, RedisResult, RetryBudget, RetryStrategy,
 };
 use std::fmt;
 use std::sync::Arc;
@@ -123,6 +124,7 @@ async fn create_connection(
     push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
     discover_az: bool,
     connection_timeout: Duration,
+    retry_budget: Option<Arc<RetryBudget>>,
 ) -> Result<ReconnectingConnection, (ReconnectingConnection, RedisError)> {
     let client = {
         let guard = connection_backend
@@ -140,6 +142,7 @@ async fn create_connection(
         discover_az,
         connection_timeout: Some(connection_timeout),
         connection_retry_strategy: Some(retry_strategy),
+        retry_budget,
     };
 
     let action = || async {
@@ -196,11 +199,13 @@ async fn create_connection(
 fn get_client(
     address: &NodeAddress,
     tls_mode: TlsMode,
+    tls_params: Option<redis::TlsConnParams>,
     redis_connection_info: redis::RedisConnectionInfo,
 ) -> redis::Client {
     redis::Client::open(super::get_connection_info(
         address,
         tls_mode,
+        tls_params,
         redis_connection_info,
     ))
     .unwrap() // can unwrap, because [open] fails only on trying to convert input to ConnectionInfo, and we pass ConnectionInfo.
@@ -214,21 +219,24 @@ impl ConnectionBackend {
 }
 
 impl ReconnectingConnection {
+    #[allow(clippy::too_many_arguments)]
     pub(super) async fn new(
         address: &NodeAddress,
         connection_retry_strategy: RetryStrategy,
         redis_connection_info: RedisConnectionInfo,
         tls_mode: TlsMode,
+        tls_params: Option<redis::TlsConnParams>,
         push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
         discover_az: bool,
         connection_timeout: Duration,
+        retry_budget: Option<Arc<RetryBudget>>,
     ) -> Result<ReconnectingConnection, (ReconnectingConnection, RedisError)> {
         log_debug(
             "connection creation",
             format!("Attempting connection to {address}"),
         );
 
-        let connection_info = get_client(address, tls_mode, redis_connection_info);
+        let connection_info = get_client(address, tls_mode, tls_params, redis_connection_info);
         let backend = ConnectionBackend {
             connection_info: RwLock::new(connection_info),
             connection_available_signal: ManualResetEvent::new(true),
@@ -240,10 +248,32 @@ impl ReconnectingConnection {
             push_sender,
             discover_az,
             connection_timeout,
+            retry_budget,
         )
         .await
     }
 
+    /// Creates a new connection to the node, which isn't shared with other requests and isn't reconnected.
+    /// Pubsub subscriptions and client tracking aren't applied to it.
+    pub(super) async fn create_dedicated_connection(&self) -> RedisResult<MultiplexedConnection> {
+        let client = {
+            let mut connection_info = self
+                .inner
+                .backend
+                .get_backend_client()
+                .get_connection_info()
+                .clone();
+            connection_info.redis.pubsub_subscriptions = None;
+            connection_info.redis.client_tracking = None;
+            redis::Client::open(connection_info)?
+        };
+        let connection_options = GlideConnectionOptions {
+            connection_timeout: self.connection_options.connection_timeout,
+            ..Default::default()
+        };
+        get_multiplexed_connection(&client, &connection_options).await
+    }
+
     pub(crate) fn node_address(&self) -> String {
         self.inner
             .backend
@@ -325,6 +355,7 @@ impl ReconnectingConnection {
                 .connection_retry_strategy
                 .unwrap()
                 .get_infinite_backoff_dur_iterator();
+            let mut first_attempt = true;
             for sleep_duration in infinite_backoff_dur_iterator {
                 if connection_clone.is_dropped() {
                     log_debug(
@@ -334,6 +365,24 @@ impl ReconnectingConnection {
                     // Client was dropped, reconnection attempts can stop
                     return;
                 }
+                // Only the first attempt is free, so that a node that's down doesn't drain the retries of the requests.
+                if !first_attempt
+                    && connection_clone
+                        .connection_options
+                        .retry_budget
+                        .as_ref()
+                        .is_some_and(|budget| !budget.try_withdraw())
+                {
+                    log_debug(
+                        "reconnect",
+                        format!(
+                            "retry budget exhausted, postponing the reconnect by {sleep_duration:?}"
+                        ),
+                    );
+                    tokio::time::sleep(sleep_duration).await;
+                    continue;
+                }
+                first_attempt = false;
                 match get_multiplexed_connection(&client, &connection_clone.connection_options)
                     .await
                 {
@@ -394,6 +443,37 @@ impl ReconnectingConnection {
         client.update_password(new_password);
     }
 
+    /// Updates the pubsub subscriptions that are saved inside connection_info, that will be restored in case of disconnection from the server.
+    /// Fails if the connection doesn't use RESP3, since subscriptions are only restored with RESP3.
+    pub(crate) fn update_pubsub_subscriptions(
+        &self,
+        update: impl FnOnce(&mut PubSubSubscriptionInfo),
+    ) -> RedisResult<()> {
+        let mut client = self
+            .inner
+            .backend
+            .connection_info
+            .write()
+            .expect(WRITE_LOCK_ERR);
+        let redis_connection_info = &client.get_connection_info().redis;
+        if redis_connection_info.protocol != ProtocolVersion::RESP3 {
+            return Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "PubSub subscriptions require RESP3",
+            )));
+        }
+        let mut pubsub_subscriptions = redis_connection_info
+            .pubsub_subscriptions
+            .clone()
+            .unwrap_or_default();
+        update(&mut pubsub_subscriptions);
+        pubsub_subscriptions.retain(|_, channels_or_patterns| !channels_or_patterns.is_empty());
+        client.update_pubsub_subscriptions(
+            (!pubsub_subscriptions.is_empty()).then_some(pubsub_subscriptions),
+        );
+        Ok(())
+    }
+
     /// Returns the username if one was configured during client creation. Otherwise, returns None.
     pub(crate) fn get_username(&self) -> Option<String> {
         let client = self.inner.backend.get_backend_client();
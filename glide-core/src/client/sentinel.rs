@@ -0,0 +1,141 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use super::{NodeAddress, SentinelConfiguration, TlsMode, get_connection_info};
+use redis::sentinel::{Sentinel, SentinelNodeConnectionInfo};
+use redis::{ConnectionAddr, ConnectionInfo, Msg, RedisConnectionInfo, RedisResult, TlsConnParams};
+
+/// The sentinel channel on which primary failovers are published.
+pub(super) const SWITCH_MASTER_CHANNEL: &str = "+switch-master";
+
+/// Returns the connection info of each of the configured sentinels.
+pub(super) fn sentinels_connection_info(
+    sentinel_configuration: &SentinelConfiguration,
+) -> Vec<ConnectionInfo> {
+    let redis_connection_info = match &sentinel_configuration.authentication_info {
+        Some(info) => RedisConnectionInfo {
+            username: info.username.clone(),
+            password: info.password.clone(),
+            ..Default::default()
+        },
+        None => RedisConnectionInfo::default(),
+    };
+    let tls_mode = sentinel_configuration.tls_mode.unwrap_or_default();
+    sentinel_configuration
+        .addresses
+        .iter()
+        .map(|address| get_connection_info(address, tls_mode, None, redis_connection_info.clone()))
+        .collect()
+}
+
+/// Discovers the primary and the replicas monitored by the sentinels under the configured master name.
+/// The primary is the first returned address.
+/// `node_tls_mode`, `node_tls_params` and `node_connection_info` are used to verify the role of the discovered nodes.
+pub(super) async fn discover_nodes(
+    sentinel_configuration: &SentinelConfiguration,
+    node_tls_mode: TlsMode,
+    node_tls_params: Option<TlsConnParams>,
+    mut node_connection_info: RedisConnectionInfo,
+) -> RedisResult<Vec<NodeAddress>> {
+    let mut sentinel = Sentinel::build(sentinels_connection_info(sentinel_configuration))?;
+    node_connection_info.pubsub_subscriptions = None;
+    node_connection_info.client_tracking = None;
+    let node_connection_info = SentinelNodeConnectionInfo {
+        tls_mode: match node_tls_mode {
+            TlsMode::NoTls => None,
+            TlsMode::SecureTls => Some(redis::TlsMode::Secure),
+            TlsMode::InsecureTls => Some(redis::TlsMode::Insecure),
+        },
+        tls_params: node_tls_params,
+        redis_connection_info: Some(node_connection_info),
+    };
+    let master_name = sentinel_configuration.master_name.as_str();
+
+    let primary = sentinel
+        .async_find_master_address(master_name, &node_connection_info)
+        .await?;
+    let replicas = sentinel
+        .async_find_valid_replica_addresses(master_name, &node_connection_info)
+        .await?;
+    Ok(std::iter::once(primary)
+        .chain(replicas)
+        .filter_map(|connection_info| node_address(&connection_info.addr))
+        .collect())
+}
+
+fn node_address(addr: &ConnectionAddr) -> Option<NodeAddress> {
+    match addr {
+        ConnectionAddr::Tcp(host, port) | ConnectionAddr::TcpTls { host, port, .. } => {
+            Some(NodeAddress {
+                host: host.clone(),
+                port: *port,
+                unix_socket_path: None,
+            })
+        }
+        ConnectionAddr::Unix(_) => None,
+    }
+}
+
+/// Opens a pubsub connection to the given sentinel, subscribed to primary failover notifications.
+pub(super) async fn subscribe_to_switch_master(
+    connection_info: &ConnectionInfo,
+) -> RedisResult<redis::aio::PubSub> {
+    let client = redis::Client::open(connection_info.clone())?;
+    let mut pubsub = client.get_async_pubsub().await?;
+    pubsub.subscribe(SWITCH_MASTER_CHANNEL).await?;
+    Ok(pubsub)
+}
+
+/// Parses a `+switch-master` message, returning the address of the new primary if the message refers to `master_name`.
+/// The message payload has the format `<master name> <old ip> <old port> <new ip> <new port>`.
+pub(super) fn parse_switch_master(msg: &Msg, master_name: &str) -> Option<NodeAddress> {
+    let payload: String = msg.get_payload().ok()?;
+    let mut parts = payload.split_whitespace();
+    if parts.next()? != master_name {
+        return None;
+    }
+    let mut parts = parts.skip(2);
+    let host = parts.next()?.to_string();
+    let port = parts.next()?.parse().ok()?;
+    Some(NodeAddress {
+        host,
+        port,
+        unix_socket_path: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::Value;
+
+    fn switch_master_message(payload: &str) -> Msg {
+        Msg::from_value(&Value::Array(vec![
+            Value::BulkString(b"message".to_vec()),
+            Value::BulkString(SWITCH_MASTER_CHANNEL.as_bytes().to_vec()),
+            Value::BulkString(payload.as_bytes().to_vec()),
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_switch_master() {
+        let msg = switch_master_message("mymaster 10.0.0.1 6379 10.0.0.2 6380");
+        let address = parse_switch_master(&msg, "mymaster").unwrap();
+        assert_eq!(address.host, "10.0.0.2");
+        assert_eq!(address.port, 6380);
+    }
+
+    #[test]
+    fn test_parse_switch_master_ignores_other_masters() {
+        let msg = switch_master_message("othermaster 10.0.0.1 6379 10.0.0.2 6380");
+        assert!(parse_switch_master(&msg, "mymaster").is_none());
+    }
+
+    #[test]
+    fn test_parse_switch_master_ignores_malformed_payload() {
+        let msg = switch_master_message("mymaster 10.0.0.1 6379 10.0.0.2");
+        assert!(parse_switch_master(&msg, "mymaster").is_none());
+        let msg = switch_master_message("mymaster 10.0.0.1 6379 10.0.0.2 port");
+        assert!(parse_switch_master(&msg, "mymaster").is_none());
+    }
+}
@@ -1,20 +1,27 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
-use super::get_redis_connection_info;
 use super::reconnecting_connection::{ReconnectReason, ReconnectingConnection};
-use super::{ConnectionRequest, NodeAddress, TlsMode};
+use super::sentinel;
+use super::{ConnectionRequest, NodeAddress, SentinelConfiguration, TlsMode};
 use super::{DEFAULT_CONNECTION_TIMEOUT, to_duration};
+use super::{format_node_address, get_redis_connection_info, get_tls_params};
 use crate::client::types::ReadFrom as ClientReadFrom;
+use crate::ft_cursor_container::is_ft_cursor_command;
 use futures::{StreamExt, future, stream};
 use logger_core::log_debug;
 use logger_core::log_warn;
 use rand::Rng;
 use redis::aio::ConnectionLike;
 use redis::cluster_routing::{self, ResponsePolicy, Routable, RoutingInfo, is_readonly_cmd};
-use redis::{PushInfo, RedisError, RedisResult, RetryStrategy, Value};
-use std::sync::Arc;
+use redis::{
+    AdaptiveTimeout, CircuitBreaker, CircuitPermit, ErrorKind, LatencyTracker,
+    PubSubChannelOrPattern, PubSubSubscriptionKind, PushInfo, PushKind, ReadYourWrites, RedisError,
+    RedisResult, RequestHedger, RetryBudget, RetryStrategy, Value, cancel_permits,
+};
+use std::pin::pin;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
+use std::sync::{Arc, Weak};
 use std::time::Duration;
 use telemetrylib::Telemetry;
 use tokio::sync::mpsc;
@@ -26,6 +33,10 @@ enum ReadFrom {
     PreferReplica {
         latest_read_replica_index: Arc<AtomicUsize>,
     },
+    LowestLatency {
+        latest_read_replica_index: Arc<AtomicUsize>,
+        latency_tracker: Arc<LatencyTracker>,
+    },
     AZAffinity {
         client_az: String,
         last_read_replica_index: Arc<AtomicUsize>,
@@ -38,10 +49,136 @@ enum ReadFrom {
 
 #[derive(Debug)]
 struct DropWrapper {
-    /// Connection to the primary node in the client.
-    primary_index: usize,
+    /// Connection to the primary node in the client. Updated when a failover is detected.
+    primary_index: AtomicUsize,
     nodes: Vec<ReconnectingConnection>,
+    /// The node on which the pubsub subscriptions are applied.
+    pubsub_node_index: usize,
     read_from: ReadFrom,
+    /// Serializes primary elections, so that concurrent failures trigger a single election.
+    primary_election_lock: tokio::sync::Mutex<()>,
+    /// Bounds the role query sent to each node in a primary election, so that a hung node doesn't block the election.
+    connection_timeout: Duration,
+    /// Set when client tracking is enabled, to invalidate all tracked keys when the primary moves.
+    topology_change_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+    circuit_breaker: Option<CircuitBreaker>,
+    request_hedger: Option<RequestHedger>,
+    /// Records the latency of requests to each node, from which the client derives its request timeout.
+    adaptive_timeout: Option<Arc<AdaptiveTimeout>>,
+    /// Set when reads are sent to replicas only after they replicated the client's writes.
+    read_your_writes: Option<Arc<ReadYourWrites>>,
+}
+
+impl DropWrapper {
+    fn primary_index(&self) -> usize {
+        self.primary_index.load(Ordering::Acquire)
+    }
+
+    /// Sends an invalidation of all keys, since the new primary doesn't track the keys that were read from the previous one.
+    fn notify_topology_change(&self) {
+        if let Some(sender) = &self.topology_change_sender {
+            let _ = sender.send(PushInfo {
+                kind: PushKind::Invalidate,
+                data: vec![Value::Nil],
+            });
+        }
+    }
+
+    /// Queries the replication role of all nodes, and moves the primary to the node that reports itself as the primary.
+    /// Returns true if the primary was moved since `observed_primary_index` was read.
+    async fn elect_primary(&self, observed_primary_index: usize) -> bool {
+        let _guard = self.primary_election_lock.lock().await;
+        let current_primary_index = self.primary_index();
+        if current_primary_index != observed_primary_index {
+            // Another task already elected a new primary.
+            return true;
+        }
+
+        let roles = future::join_all(self.nodes.iter().map(|node| async move {
+            let mut connection = node.try_get_connection().await?;
+            let info = redis::cmd("INFO").arg("REPLICATION").to_owned();
+            tokio::time::timeout(
+                self.connection_timeout,
+                connection.send_packed_command(&info),
+            )
+            .await
+            .ok()?
+            .ok()
+        }))
+        .await;
+        let primaries: Vec<usize> = roles
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, replication_status)| {
+                replication_status
+                    .is_some_and(is_primary_replication_status)
+                    .then_some(index)
+            })
+            .collect();
+
+        match primaries.as_slice() {
+            [new_primary_index] if *new_primary_index != current_primary_index => {
+                log_warn(
+                    "primary election",
+                    format!(
+                        "Primary moved from `{}` to `{}`",
+                        self.nodes[current_primary_index].node_address(),
+                        self.nodes[*new_primary_index].node_address()
+                    ),
+                );
+                self.primary_index
+                    .store(*new_primary_index, Ordering::Release);
+                self.notify_topology_change();
+                true
+            }
+            [_] => false,
+            [] => {
+                log_warn("primary election", "No primary node found");
+                false
+            }
+            _ => {
+                log_warn(
+                    "primary election",
+                    "More than one primary found, keeping the current primary",
+                );
+                false
+            }
+        }
+    }
+
+    /// Moves the primary to the node with the given address, as reported by a sentinel.
+    /// Falls back to a primary election if the address doesn't belong to any of the nodes.
+    async fn move_primary_to(&self, address: &NodeAddress) {
+        let observed_primary_index = self.primary_index();
+        let new_primary_address = format_node_address(address);
+        let Some(new_primary_index) = self
+            .nodes
+            .iter()
+            .position(|node| node.node_address() == new_primary_address)
+        else {
+            log_warn(
+                "primary election",
+                format!("Unknown primary `{new_primary_address}` reported by sentinel"),
+            );
+            self.elect_primary(observed_primary_index).await;
+            return;
+        };
+
+        let _guard = self.primary_election_lock.lock().await;
+        let current_primary_index = self.primary_index();
+        if current_primary_index != new_primary_index {
+            log_warn(
+                "primary election",
+                format!(
+                    "Sentinel moved primary from `{}` to `{new_primary_address}`",
+                    self.nodes[current_primary_index].node_address()
+                ),
+            );
+            self.primary_index
+                .store(new_primary_index, Ordering::Release);
+            self.notify_topology_change();
+        }
+    }
 }
 
 impl Drop for DropWrapper {
@@ -117,10 +254,52 @@ impl StandaloneClient {
         connection_request: ConnectionRequest,
         push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
     ) -> Result<Self, StandaloneClientConnectionError> {
+        Self::create_client_with_shared_state(connection_request, push_sender, None, None).await
+    }
+
+    /// Creates a client like [`StandaloneClient::create_client`], that records the latency of its requests to each node in `adaptive_timeout`,
+    /// and whose reconnect attempts draw from `retry_budget`.
+    pub(super) async fn create_client_with_shared_state(
+        mut connection_request: ConnectionRequest,
+        push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+        adaptive_timeout: Option<Arc<AdaptiveTimeout>>,
+        retry_budget: Option<Arc<RetryBudget>>,
+    ) -> Result<Self, StandaloneClientConnectionError> {
+        let tls_params = get_tls_params(&connection_request)
+            .map_err(|err| StandaloneClientConnectionError::FailedConnection(vec![(None, err)]))?;
+        if let Some(sentinel_configuration) = &connection_request.sentinel_configuration {
+            let addresses = sentinel::discover_nodes(
+                sentinel_configuration,
+                connection_request.tls_mode.unwrap_or_default(),
+                tls_params.clone(),
+                get_redis_connection_info(&connection_request),
+            )
+            .await
+            .map_err(|err| StandaloneClientConnectionError::FailedConnection(vec![(None, err)]))?;
+            connection_request.addresses = addresses;
+        }
         if connection_request.addresses.is_empty() {
             return Err(StandaloneClientConnectionError::NoAddressesProvided);
         }
+        if connection_request.tls_mode.unwrap_or_default() != TlsMode::NoTls
+            && connection_request
+                .addresses
+                .iter()
+                .any(|address| address.unix_socket_path.is_some())
+        {
+            return Err(StandaloneClientConnectionError::FailedConnection(vec![(
+                None,
+                RedisError::from((
+                    ErrorKind::InvalidClientConfig,
+                    "TLS isn't supported over unix domain sockets",
+                )),
+            )]));
+        }
         let mut redis_connection_info = get_redis_connection_info(&connection_request);
+        let topology_change_sender = redis_connection_info
+            .client_tracking
+            .as_ref()
+            .and(push_sender.clone());
         let pubsub_connection_info = redis_connection_info.clone();
         redis_connection_info.pubsub_subscriptions = None;
         let retry_strategy = match connection_request.connection_retry_strategy {
@@ -138,6 +317,7 @@ impl StandaloneClient {
         // randomize pubsub nodes, maybe a batter option is to always use the primary
         let pubsub_node_index = rand::thread_rng().gen_range(0..node_count);
         let pubsub_addr = connection_request.addresses[pubsub_node_index].clone();
+        let pubsub_node_address = format_node_address(&pubsub_addr);
         let discover_az = matches!(
             connection_request.read_from,
             Some(ClientReadFrom::AZAffinity(_))
@@ -151,7 +331,7 @@ impl StandaloneClient {
 
         let mut stream = stream::iter(connection_request.addresses.into_iter())
             .map(move |address| {
-                let info = if address.host != pubsub_addr.host || address.port != pubsub_addr.port {
+                let info = if format_node_address(&address) != format_node_address(&pubsub_addr) {
                     redis_connection_info.clone()
                 } else {
                     pubsub_connection_info.clone()
@@ -159,14 +339,17 @@ impl StandaloneClient {
                 let retry = retry_strategy;
                 let sender = push_sender.clone();
                 let tls = tls_mode.unwrap_or(TlsMode::NoTls);
+                let tls_params = tls_params.clone();
                 let discover = discover_az;
                 let timeout = connection_timeout;
+                let budget = retry_budget.clone();
                 async move {
                     get_connection_and_replication_info(
-                        &address, &retry, &info, tls, &sender, discover, timeout,
+                        &address, &retry, &info, tls, tls_params, &sender, discover, timeout,
+                        budget,
                     )
                     .await
-                    .map_err(|err| (format!("{}:{}", address.host, address.port), err))
+                    .map_err(|err| (format_node_address(&address), err))
                 }
             })
             .buffer_unordered(node_count);
@@ -178,9 +361,7 @@ impl StandaloneClient {
             match result {
                 Ok((connection, replication_status)) => {
                     nodes.push(connection);
-                    if redis::from_owned_redis_value::<String>(replication_status)
-                        .is_ok_and(|val| val.contains("role:master"))
-                    {
+                    if is_primary_replication_status(replication_status) {
                         if let Some(primary_index) = primary_index {
                             // More than one primary found
                             return Err(StandaloneClientConnectionError::PrimaryConflictFound(
@@ -230,24 +411,55 @@ impl StandaloneClient {
             Self::start_heartbeat(node.clone());
         }
 
+        let latency_tracker = match &read_from {
+            ReadFrom::LowestLatency {
+                latency_tracker, ..
+            } => Some(latency_tracker.clone()),
+            _ => None,
+        };
         for node in nodes.iter() {
-            Self::start_periodic_connection_check(node.clone());
+            Self::start_periodic_connection_check(
+                node.clone(),
+                latency_tracker.clone(),
+                connection_timeout,
+            );
+        }
+
+        let pubsub_node_index = nodes
+            .iter()
+            .position(|node| node.node_address() == pubsub_node_address)
+            .unwrap_or(primary_index);
+        let inner = Arc::new(DropWrapper {
+            primary_index: AtomicUsize::new(primary_index),
+            nodes,
+            pubsub_node_index,
+            read_from,
+            primary_election_lock: Default::default(),
+            connection_timeout,
+            topology_change_sender,
+            circuit_breaker: connection_request.circuit_breaker.map(CircuitBreaker::new),
+            request_hedger: connection_request.request_hedging.map(RequestHedger::new),
+            adaptive_timeout,
+            read_your_writes: connection_request
+                .read_your_writes
+                .then(|| Arc::new(ReadYourWrites::new())),
+        });
+
+        if let Some(interval) = connection_request.primary_check_interval {
+            Self::start_periodic_primary_check(Arc::downgrade(&inner), interval);
+        }
+        if let Some(sentinel_configuration) = connection_request.sentinel_configuration {
+            Self::start_sentinel_failover_listener(Arc::downgrade(&inner), sentinel_configuration);
         }
 
         // Successfully created new client. Update the telemetry
         Telemetry::incr_total_clients(1);
 
-        Ok(Self {
-            inner: Arc::new(DropWrapper {
-                primary_index,
-                nodes,
-                read_from,
-            }),
-        })
+        Ok(Self { inner })
     }
 
     fn get_primary_connection(&self) -> &ReconnectingConnection {
-        self.inner.nodes.get(self.inner.primary_index).unwrap()
+        self.inner.nodes.get(self.inner.primary_index()).unwrap()
     }
 
     fn round_robin_read_from_replica(
@@ -255,6 +467,7 @@ impl StandaloneClient {
         latest_read_replica_index: &Arc<AtomicUsize>,
     ) -> &ReconnectingConnection {
         let initial_index = latest_read_replica_index.load(Ordering::Relaxed);
+        let primary_index = self.inner.primary_index();
         let mut check_count = 0;
         loop {
             check_count += 1;
@@ -264,7 +477,7 @@ impl StandaloneClient {
                 return self.get_primary_connection();
             }
             let index = (initial_index + check_count) % self.inner.nodes.len();
-            if index == self.inner.primary_index {
+            if index == primary_index {
                 continue;
             }
             let Some(connection) = self.inner.nodes.get(index) else {
@@ -282,6 +495,35 @@ impl StandaloneClient {
         }
     }
 
+    /// Returns the connected replica with the lowest measured latency,
+    /// falling back to round robin until latency samples are collected.
+    fn lowest_latency_read_from_replica(
+        &self,
+        latest_read_replica_index: &Arc<AtomicUsize>,
+        latency_tracker: &LatencyTracker,
+    ) -> &ReconnectingConnection {
+        let primary_index = self.inner.primary_index();
+        let addresses: Vec<_> = self
+            .inner
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(index, node)| *index != primary_index && node.is_connected())
+            .map(|(index, node)| (index, node.node_address()))
+            .collect();
+        let current_index = latest_read_replica_index.load(Ordering::Relaxed);
+        let candidates = addresses
+            .iter()
+            .map(|(index, address)| (*index, address.as_str()));
+        match latency_tracker.select(candidates, current_index) {
+            Some(index) => {
+                latest_read_replica_index.store(index, Ordering::Relaxed);
+                &self.inner.nodes[index]
+            }
+            None => self.round_robin_read_from_replica(latest_read_replica_index),
+        }
+    }
+
     async fn round_robin_read_from_replica_az_awareness(
         &self,
         latest_read_replica_index: &Arc<AtomicUsize>,
@@ -371,16 +613,41 @@ impl StandaloneClient {
         self.round_robin_read_from_replica(latest_read_replica_index)
     }
 
+    /// Returns true if reads from `node` return the client's writes.
+    fn is_readable(&self, node: &ReconnectingConnection) -> bool {
+        let primary = self.get_primary_connection();
+        match &self.inner.read_your_writes {
+            Some(read_your_writes) if !std::ptr::eq(node, primary) => {
+                read_your_writes.is_caught_up(&primary.node_address(), &node.node_address())
+            }
+            _ => true,
+        }
+    }
+
     async fn get_connection(&self, readonly: bool) -> &ReconnectingConnection {
         if self.inner.nodes.len() == 1 || !readonly {
             return self.get_primary_connection();
         }
 
+        let node = self.get_read_connection().await;
+        if self.is_readable(node) {
+            node
+        } else {
+            // The replica didn't replicate the client's writes yet.
+            self.get_primary_connection()
+        }
+    }
+
+    async fn get_read_connection(&self) -> &ReconnectingConnection {
         match &self.inner.read_from {
             ReadFrom::Primary => self.get_primary_connection(),
             ReadFrom::PreferReplica {
                 latest_read_replica_index,
             } => self.round_robin_read_from_replica(latest_read_replica_index),
+            ReadFrom::LowestLatency {
+                latest_read_replica_index,
+                latency_tracker,
+            } => self.lowest_latency_read_from_replica(latest_read_replica_index, latency_tracker),
             ReadFrom::AZAffinity {
                 client_az,
                 last_read_replica_index,
@@ -404,12 +671,38 @@ impl StandaloneClient {
         }
     }
 
+    /// Checks the circuit breaker of the node, if one is configured.
+    /// The returned permit must be recorded with the outcome of the request.
+    fn acquire_circuit_permit(
+        &self,
+        reconnecting_connection: &ReconnectingConnection,
+    ) -> RedisResult<Option<CircuitPermit<'_>>> {
+        self.inner
+            .circuit_breaker
+            .as_ref()
+            .map(|circuit_breaker| {
+                circuit_breaker.try_acquire(&reconnecting_connection.node_address())
+            })
+            .transpose()
+    }
+
     async fn send_request(
+        &self,
         cmd: &redis::Cmd,
         reconnecting_connection: &ReconnectingConnection,
     ) -> RedisResult<Value> {
         let mut connection = reconnecting_connection.get_connection().await?;
+        let permit = self.acquire_circuit_permit(reconnecting_connection)?;
+        let start = std::time::Instant::now();
         let result = connection.send_packed_command(cmd).await;
+        if let Some(permit) = permit {
+            permit.record(&result);
+        }
+        if let (Some(adaptive_timeout), Ok(_)) = (&self.inner.adaptive_timeout, &result)
+            && AdaptiveTimeout::applies_to(cmd)
+        {
+            adaptive_timeout.record(&reconnecting_connection.node_address(), start.elapsed());
+        }
         match result {
             Err(err) if err.is_unrecoverable_error() => {
                 log_warn("send request", format!("received disconnect error `{err}`"));
@@ -429,75 +722,230 @@ impl StandaloneClient {
             .inner
             .nodes
             .iter()
-            .map(|node| Self::send_request(cmd, node));
+            .map(|node| self.send_request(cmd, node));
 
         // TODO - once Value::Error will be merged, these will need to be updated to handle this new value.
-        match response_policy {
-            Some(ResponsePolicy::AllSucceeded) => {
-                future::try_join_all(requests)
+        let fan_out = async {
+            match response_policy {
+                Some(ResponsePolicy::AllSucceeded) => {
+                    future::try_join_all(requests)
+                        .await
+                        .map(|mut results| results.pop().unwrap()) // unwrap is safe, since at least one function succeeded
+                }
+                Some(ResponsePolicy::OneSucceeded) => future::select_ok(requests.map(Box::pin))
                     .await
-                    .map(|mut results| results.pop().unwrap()) // unwrap is safe, since at least one function succeeded
-            }
-            Some(ResponsePolicy::OneSucceeded) => future::select_ok(requests.map(Box::pin))
-                .await
-                .map(|(result, _)| result),
-            Some(ResponsePolicy::FirstSucceededNonEmptyOrAllEmpty) => {
-                future::select_ok(requests.map(|request| {
-                    Box::pin(async move {
-                        let result = request.await?;
-                        match result {
-                            Value::Nil => {
-                                Err((redis::ErrorKind::ResponseError, "no value found").into())
+                    .map(|(result, _)| result),
+                Some(ResponsePolicy::FirstSucceededNonEmptyOrAllEmpty) => {
+                    future::select_ok(requests.map(|request| {
+                        Box::pin(async move {
+                            let result = request.await?;
+                            match result {
+                                Value::Nil => {
+                                    Err((redis::ErrorKind::ResponseError, "no value found").into())
+                                }
+                                _ => Ok(result),
                             }
-                            _ => Ok(result),
-                        }
-                    })
-                }))
-                .await
-                .map(|(result, _)| result)
+                        })
+                    }))
+                    .await
+                    .map(|(result, _)| result)
+                }
+                Some(ResponsePolicy::Aggregate(op)) => future::try_join_all(requests)
+                    .await
+                    .and_then(|results| cluster_routing::aggregate(results, op)),
+                Some(ResponsePolicy::AggregateLogical(op)) => future::try_join_all(requests)
+                    .await
+                    .and_then(|results| cluster_routing::logical_aggregate(results, op)),
+                Some(ResponsePolicy::CombineArrays) => future::try_join_all(requests)
+                    .await
+                    .and_then(cluster_routing::combine_array_results),
+                Some(ResponsePolicy::CombineMaps) => future::try_join_all(requests)
+                    .await
+                    .and_then(cluster_routing::combine_map_results),
+                Some(ResponsePolicy::Special) => {
+                    // Await all futures and collect results
+                    let results = future::try_join_all(requests).await?;
+                    // Create key-value pairs where the key is the node address and the value is the corresponding result
+                    let node_result_pairs = self
+                        .inner
+                        .nodes
+                        .iter()
+                        .zip(results)
+                        .map(|(node, result)| {
+                            (Value::BulkString(node.node_address().into()), result)
+                        })
+                        .collect();
+
+                    Ok(Value::Map(node_result_pairs))
+                }
+
+                None => {
+                    // This is our assumption - if there's no coherent way to aggregate the responses, we just collect them in an array, and pass it to the user.
+                    // TODO - once Value::Error is merged, we can use join_all and report separate errors and also pass successes.
+                    future::try_join_all(requests).await.map(Value::Array)
+                }
             }
-            Some(ResponsePolicy::Aggregate(op)) => future::try_join_all(requests)
-                .await
-                .and_then(|results| cluster_routing::aggregate(results, op)),
-            Some(ResponsePolicy::AggregateLogical(op)) => future::try_join_all(requests)
-                .await
-                .and_then(|results| cluster_routing::logical_aggregate(results, op)),
-            Some(ResponsePolicy::CombineArrays) => future::try_join_all(requests)
-                .await
-                .and_then(cluster_routing::combine_array_results),
-            Some(ResponsePolicy::CombineMaps) => future::try_join_all(requests)
-                .await
-                .and_then(cluster_routing::combine_map_results),
-            Some(ResponsePolicy::Special) => {
-                // Await all futures and collect results
-                let results = future::try_join_all(requests).await?;
-                // Create key-value pairs where the key is the node address and the value is the corresponding result
-                let node_result_pairs = self
+        };
+        // Once the result is known, the requests that are still in flight are dropped since their replies are no longer needed,
+        // so their circuit permits are cancelled rather than counted as failures.
+        let mut fan_out = pin!(fan_out);
+        future::poll_fn(|cx| cancel_permits(|| fan_out.as_mut().poll(cx))).await
+    }
+
+    async fn send_request_to_single_node(
+        &mut self,
+        cmd: &redis::Cmd,
+        readonly: bool,
+    ) -> RedisResult<Value> {
+        let primary_index = self.inner.primary_index();
+        let reconnecting_connection = self.get_connection(readonly).await;
+        let result = if readonly {
+            self.send_read_request(cmd, reconnecting_connection).await
+        } else {
+            self.send_write_request(cmd, reconnecting_connection).await
+        };
+        match result {
+            Err(err) if err.kind() == redis::ErrorKind::ReadOnly => {
+                // The primary was demoted to a replica. Find the new primary and retry the request on it.
+                if !self.inner.elect_primary(primary_index).await {
+                    return Err(err);
+                }
+                log_debug(
+                    "send request",
+                    "retrying request after the primary was moved",
+                );
+                self.send_write_request(cmd, self.get_primary_connection())
+                    .await
+            }
+            _ => result,
+        }
+    }
+
+    /// Sends a request to the primary, and records the replication offset after writes, if read-your-writes is enabled.
+    async fn send_write_request(
+        &self,
+        cmd: &redis::Cmd,
+        reconnecting_connection: &ReconnectingConnection,
+    ) -> RedisResult<Value> {
+        let result = self.send_request(cmd, reconnecting_connection).await;
+        if result.is_ok() && is_tracked_write(cmd) {
+            self.record_write(reconnecting_connection).await;
+        }
+        result
+    }
+
+    /// Records the replication offset of the primary after a write was sent on `reconnecting_connection`, if read-your-writes is enabled,
+    /// and starts polling the replicas if they aren't polled already.
+    async fn record_write(&self, reconnecting_connection: &ReconnectingConnection) {
+        let Some(read_your_writes) = &self.inner.read_your_writes else {
+            return;
+        };
+        let primary = self.get_primary_connection();
+        if !std::ptr::eq(reconnecting_connection, primary) {
+            return;
+        }
+
+        let primary_address = primary.node_address();
+        let recorded = match primary.get_connection().await {
+            Ok(mut connection) => {
+                read_your_writes
+                    .record_write(&primary_address, &mut connection)
+                    .await
+            }
+            Err(err) => {
+                read_your_writes.pin_to_primary(&primary_address);
+                Err(err)
+            }
+        };
+        match recorded {
+            Ok(true) => {
+                let replicas: Vec<ReconnectingConnection> = self
                     .inner
                     .nodes
                     .iter()
-                    .zip(results)
-                    .map(|(node, result)| (Value::BulkString(node.node_address().into()), result))
+                    .filter(|node| !std::ptr::eq(*node, primary))
+                    .cloned()
                     .collect();
-
-                Ok(Value::Map(node_result_pairs))
+                let read_your_writes = read_your_writes.clone();
+                task::spawn(async move {
+                    let mut connections = Vec::with_capacity(replicas.len());
+                    for replica in replicas {
+                        if let Ok(connection) = replica.get_connection().await {
+                            connections.push((replica.node_address(), connection));
+                        }
+                    }
+                    read_your_writes
+                        .poll_replicas(&primary_address, connections)
+                        .await;
+                });
             }
+            Ok(false) => {}
+            Err(err) => log_warn(
+                "read your writes",
+                format!("Failed to record the replication offset of {primary_address}: {err}"),
+            ),
+        }
+    }
 
-            None => {
-                // This is our assumption - if there's no coherent way to aggregate the responses, we just collect them in an array, and pass it to the user.
-                // TODO - once Value::Error is merged, we can use join_all and report separate errors and also pass successes.
-                future::try_join_all(requests).await.map(Value::Array)
+    /// Sends a read, and if reads are routed to replicas and hedging is enabled, hedges it to another node when it's slow.
+    async fn send_read_request(
+        &self,
+        cmd: &redis::Cmd,
+        reconnecting_connection: &ReconnectingConnection,
+    ) -> RedisResult<Value> {
+        let request = self.send_request_and_record_latency(cmd, reconnecting_connection);
+        match &self.inner.request_hedger {
+            Some(request_hedger)
+                if !matches!(self.inner.read_from, ReadFrom::Primary)
+                    && RequestHedger::applies_to(cmd) =>
+            {
+                request_hedger
+                    .run(request, || {
+                        self.hedge_connection(reconnecting_connection)
+                            .map(|node| self.send_request_and_record_latency(cmd, node))
+                    })
+                    .await
             }
+            _ => request.await,
         }
     }
 
-    async fn send_request_to_single_node(
-        &mut self,
+    async fn send_request_and_record_latency(
+        &self,
         cmd: &redis::Cmd,
-        readonly: bool,
+        reconnecting_connection: &ReconnectingConnection,
     ) -> RedisResult<Value> {
-        let reconnecting_connection = self.get_connection(readonly).await;
-        Self::send_request(cmd, reconnecting_connection).await
+        let start = std::time::Instant::now();
+        let result = self.send_request(cmd, reconnecting_connection).await;
+        // Blocking and known-slow commands don't reflect the node's round-trip time.
+        if let (
+            ReadFrom::LowestLatency {
+                latency_tracker, ..
+            },
+            Ok(_),
+        ) = (&self.inner.read_from, &result)
+            && AdaptiveTimeout::applies_to(cmd)
+        {
+            latency_tracker.record(&reconnecting_connection.node_address(), start.elapsed());
+        }
+        result
+    }
+
+    /// Returns a connected node other than `excluded` to send a hedged read to.
+    /// Replicas are checked in order after `excluded`, and the primary is used if none is connected.
+    fn hedge_connection(
+        &self,
+        excluded: &ReconnectingConnection,
+    ) -> Option<&ReconnectingConnection> {
+        let nodes = &self.inner.nodes;
+        let excluded_index = nodes.iter().position(|node| std::ptr::eq(node, excluded))?;
+        let primary_index = self.inner.primary_index();
+        (1..nodes.len())
+            .map(|offset| (excluded_index + offset) % nodes.len())
+            .filter(|index| *index != primary_index)
+            .chain(std::iter::once(primary_index).filter(|index| *index != excluded_index))
+            .map(|index| &nodes[index])
+            .find(|node| node.is_connected() && self.is_readable(node))
     }
 
     pub async fn send_command(&mut self, cmd: &redis::Cmd) -> RedisResult<Value> {
@@ -509,7 +957,23 @@ impl StandaloneClient {
             let response_policy = ResponsePolicy::for_command(cmd_bytes.as_slice());
             return self.send_request_to_all_nodes(cmd, response_policy).await;
         }
-        self.send_request_to_single_node(cmd, is_readonly_cmd(cmd_bytes.as_slice()))
+        // FT cursors are kept by the node that created them, so all the commands of a cursor are sent to the primary.
+        let readonly = is_readonly_cmd(cmd_bytes.as_slice()) && !is_ft_cursor_command(cmd);
+        self.send_request_to_single_node(cmd, readonly).await
+    }
+
+    /// Creates a new connection to the primary, which isn't shared with other requests.
+    pub async fn create_dedicated_connection(
+        &self,
+    ) -> RedisResult<redis::aio::MultiplexedConnection> {
+        self.get_primary_connection()
+            .create_dedicated_connection()
+            .await
+    }
+
+    /// Sends the command to every node, regardless of the command's routing.
+    pub async fn send_command_to_all_nodes(&mut self, cmd: &redis::Cmd) -> RedisResult<Value> {
+        self.send_request_to_all_nodes(cmd, Some(ResponsePolicy::AllSucceeded))
             .await
     }
 
@@ -521,9 +985,17 @@ impl StandaloneClient {
     ) -> RedisResult<Vec<Value>> {
         let reconnecting_connection = self.get_primary_connection();
         let mut connection = reconnecting_connection.get_connection().await?;
+        let permit = self.acquire_circuit_permit(reconnecting_connection)?;
         let result = connection
             .send_packed_commands(pipeline, offset, count)
             .await;
+        if let Some(permit) = permit {
+            permit.record(&result);
+        }
+        // Writes in pipelines and transactions are tracked like single writes, so that the reads after them see them.
+        if result.is_ok() && pipeline.cmd_iter().any(|cmd| is_tracked_write(cmd)) {
+            self.record_write(reconnecting_connection).await;
+        }
         match result {
             Err(err) if err.is_unrecoverable_error() => {
                 log_warn(
@@ -576,7 +1048,13 @@ impl StandaloneClient {
     // Monitors passive connection status and reconnects if necessary.
     // This function is cheaper alternative to start_heartbeat(),
     // as it avoids sending PING commands to the server, checking only the connection state.
-    fn start_periodic_connection_check(reconnecting_connection: ReconnectingConnection) {
+    // If a latency tracker is provided, a PING is sent to sample the round-trip time of the node.
+    // The PING is bounded by `ping_timeout`, so that an unresponsive node doesn't stall the checks.
+    fn start_periodic_connection_check(
+        reconnecting_connection: ReconnectingConnection,
+        latency_tracker: Option<Arc<LatencyTracker>>,
+        ping_timeout: Duration,
+    ) {
         task::spawn(async move {
             loop {
                 reconnecting_connection
@@ -593,7 +1071,8 @@ impl StandaloneClient {
                     return;
                 }
 
-                let Some(connection) = reconnecting_connection.try_get_connection().await else {
+                let Some(mut connection) = reconnecting_connection.try_get_connection().await
+                else {
                     log_debug(
                         "StandaloneClient",
                         "connection checker is skipping a connections since its reconnecting",
@@ -608,7 +1087,100 @@ impl StandaloneClient {
                         "connection checker has triggered reconnect",
                     );
                     reconnecting_connection.reconnect(ReconnectReason::ConnectionDropped);
+                } else if let Some(latency_tracker) = &latency_tracker {
+                    let start = std::time::Instant::now();
+                    let ping = redis::cmd("PING");
+                    if let Ok(Ok(_)) =
+                        tokio::time::timeout(ping_timeout, connection.send_packed_command(&ping))
+                            .await
+                    {
+                        latency_tracker
+                            .record(&reconnecting_connection.node_address(), start.elapsed());
+                    }
+                }
+            }
+        });
+    }
+
+    // Periodically checks the replication role of the nodes, in order to detect primary failovers
+    // that weren't noticed by a failed write.
+    fn start_periodic_primary_check(inner: Weak<DropWrapper>, interval: Duration) {
+        task::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                let Some(inner) = inner.upgrade() else {
+                    log_debug(
+                        "StandaloneClient",
+                        "primary checker stopped after client was dropped",
+                    );
+                    return;
+                };
+                inner.elect_primary(inner.primary_index()).await;
+            }
+        });
+    }
+
+    // Listens to the primary failovers published by the sentinels, and moves the primary accordingly.
+    // If the connection to a sentinel is lost, the next sentinel is used.
+    fn start_sentinel_failover_listener(
+        inner: Weak<DropWrapper>,
+        sentinel_configuration: SentinelConfiguration,
+    ) {
+        task::spawn(async move {
+            let sentinels = sentinel::sentinels_connection_info(&sentinel_configuration);
+            loop {
+                for connection_info in sentinels.iter() {
+                    if inner.strong_count() == 0 {
+                        log_debug(
+                            "StandaloneClient",
+                            "sentinel listener stopped after client was dropped",
+                        );
+                        return;
+                    }
+                    let pubsub = match sentinel::subscribe_to_switch_master(connection_info).await {
+                        Ok(pubsub) => pubsub,
+                        Err(err) => {
+                            log_warn(
+                                "sentinel listener",
+                                format!("Failed to subscribe to `{}`: {err}", connection_info.addr),
+                            );
+                            continue;
+                        }
+                    };
+                    let mut messages = pubsub.into_on_message();
+                    loop {
+                        match tokio::time::timeout(
+                            super::CONNECTION_CHECKS_INTERVAL,
+                            messages.next(),
+                        )
+                        .await
+                        {
+                            Ok(Some(msg)) => {
+                                let Some(new_primary) = sentinel::parse_switch_master(
+                                    &msg,
+                                    &sentinel_configuration.master_name,
+                                ) else {
+                                    continue;
+                                };
+                                let Some(inner) = inner.upgrade() else {
+                                    return;
+                                };
+                                inner.move_primary_to(&new_primary).await;
+                            }
+                            // The connection to the sentinel was closed.
+                            Ok(None) => break,
+                            Err(_) if inner.strong_count() == 0 => {
+                                log_debug(
+                                    "StandaloneClient",
+                                    "sentinel listener stopped after client was dropped",
+                                );
+                                return;
+                            }
+                            Err(_) => {}
+                        }
+                    }
                 }
+                tokio::time::sleep(super::CONNECTION_CHECKS_INTERVAL).await;
             }
         });
     }
@@ -626,30 +1198,110 @@ impl StandaloneClient {
         Ok(Value::Okay)
     }
 
+    /// Returns the address of the node to which the client currently sends writes.
+    pub fn primary_address(&self) -> String {
+        self.get_primary_connection().node_address()
+    }
+
     /// Retrieve the username used to authenticate with the server.
     pub fn get_username(&self) -> Option<String> {
         // All nodes in the client should have the same username configured, thus any connection would work here.
         self.get_primary_connection().get_username()
     }
+
+    /// Add the given channels or patterns to the desired subscriptions of the client, and subscribe to them on the pubsub node.
+    /// The subscriptions are restored whenever the pubsub node reconnects.
+    pub async fn add_pubsub_subscriptions(
+        &self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<Value> {
+        let node = &self.inner.nodes[self.inner.pubsub_node_index];
+        node.update_pubsub_subscriptions(|subscriptions| {
+            subscriptions
+                .entry(kind)
+                .or_default()
+                .extend(channels_or_patterns.iter().cloned());
+        })?;
+        Self::send_pubsub_commands(node, pubsub_command(kind, true), channels_or_patterns).await
+    }
+
+    /// Remove the given channels or patterns from the desired subscriptions of the client, and unsubscribe from them on the pubsub node.
+    pub async fn remove_pubsub_subscriptions(
+        &self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<Value> {
+        let node = &self.inner.nodes[self.inner.pubsub_node_index];
+        node.update_pubsub_subscriptions(|subscriptions| {
+            if let Some(subscribed) = subscriptions.get_mut(&kind) {
+                for channel_or_pattern in channels_or_patterns.iter() {
+                    subscribed.remove(channel_or_pattern);
+                }
+            }
+        })?;
+        Self::send_pubsub_commands(node, pubsub_command(kind, false), channels_or_patterns).await
+    }
+
+    async fn send_pubsub_commands(
+        node: &ReconnectingConnection,
+        command: &str,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<Value> {
+        // A disconnected node applies the updated subscriptions once it reconnects.
+        let Some(mut connection) = node.try_get_connection().await else {
+            return Ok(Value::Okay);
+        };
+        // Each channel is sent in a separate command, since every channel receives its own push reply.
+        for channel_or_pattern in channels_or_patterns {
+            connection
+                .send_packed_command(redis::cmd(command).arg(channel_or_pattern))
+                .await?;
+        }
+        Ok(Value::Okay)
+    }
 }
 
+/// Returns true if `cmd` is a write whose replication offset is tracked for read-your-writes.
+fn is_tracked_write(cmd: &redis::Cmd) -> bool {
+    Routable::command(cmd).is_some_and(|command| {
+        !is_readonly_cmd(&command) && RoutingInfo::is_key_routing_command(&command)
+    })
+}
+
+fn pubsub_command(kind: PubSubSubscriptionKind, subscribe: bool) -> &'static str {
+    match (kind, subscribe) {
+        (PubSubSubscriptionKind::Exact, true) => "SUBSCRIBE",
+        (PubSubSubscriptionKind::Pattern, true) => "PSUBSCRIBE",
+        (PubSubSubscriptionKind::Sharded, true) => "SSUBSCRIBE",
+        (PubSubSubscriptionKind::Exact, false) => "UNSUBSCRIBE",
+        (PubSubSubscriptionKind::Pattern, false) => "PUNSUBSCRIBE",
+        (PubSubSubscriptionKind::Sharded, false) => "SUNSUBSCRIBE",
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn get_connection_and_replication_info(
     address: &NodeAddress,
     retry_strategy: &RetryStrategy,
     connection_info: &redis::RedisConnectionInfo,
     tls_mode: TlsMode,
+    tls_params: Option<redis::TlsConnParams>,
     push_sender: &Option<mpsc::UnboundedSender<PushInfo>>,
     discover_az: bool,
     connection_timeout: Duration,
+    retry_budget: Option<Arc<RetryBudget>>,
 ) -> Result<(ReconnectingConnection, Value), (ReconnectingConnection, RedisError)> {
     let result = ReconnectingConnection::new(
         address,
         *retry_strategy,
         connection_info.clone(),
         tls_mode,
+        tls_params,
         push_sender.clone(),
         discover_az,
         connection_timeout,
+        retry_budget,
     )
     .await;
     let reconnecting_connection = match result {
@@ -678,12 +1330,21 @@ async fn get_connection_and_replication_info(
     }
 }
 
+fn is_primary_replication_status(replication_status: Value) -> bool {
+    redis::from_owned_redis_value::<String>(replication_status)
+        .is_ok_and(|val| val.contains("role:master"))
+}
+
 fn get_read_from(read_from: Option<super::ReadFrom>) -> ReadFrom {
     match read_from {
         Some(super::ReadFrom::Primary) => ReadFrom::Primary,
         Some(super::ReadFrom::PreferReplica) => ReadFrom::PreferReplica {
             latest_read_replica_index: Default::default(),
         },
+        Some(super::ReadFrom::LowestLatency) => ReadFrom::LowestLatency {
+            latest_read_replica_index: Default::default(),
+            latency_tracker: Default::default(),
+        },
         Some(super::ReadFrom::AZAffinity(az)) => ReadFrom::AZAffinity {
             client_az: az,
             last_read_replica_index: Default::default(),
@@ -25,7 +25,69 @@ pub struct ConnectionRequest {
     pub periodic_checks: Option<PeriodicCheck>,
     pub pubsub_subscriptions: Option<redis::PubSubSubscriptionInfo>,
     pub inflight_requests_limit: Option<u32>,
+    pub inflight_overflow_policy: InflightOverflowPolicy,
     pub lazy_connect: bool,
+    pub sentinel_configuration: Option<SentinelConfiguration>,
+    pub client_side_cache: Option<ClientSideCacheConfiguration>,
+    /// Enables a circuit breaker per node, which fails requests to an unhealthy node immediately.
+    pub circuit_breaker: Option<redis::CircuitBreakerConfig>,
+    /// Hedges slow reads that are routed to replicas, by also sending them to another node.
+    pub request_hedging: Option<redis::RequestHedgingConfig>,
+    /// Retries single commands that failed with a transient error, when it's safe to resend them.
+    /// Retries share the request timeout of the command.
+    pub command_retry_strategy: Option<ConnectionRetryStrategy>,
+    /// Caps the retries of the client to a percentage of its recent requests.
+    /// Reconnect attempts after the first one also draw from the budget, and are postponed while it's exhausted.
+    pub retry_budget: Option<redis::RetryBudgetConfig>,
+    /// Derives the request timeout from the observed latency of each node, capped by `request_timeout`.
+    /// Blocking and known-slow commands, such as `KEYS`, scripts and `FT.*` commands, keep the configured request timeout.
+    pub adaptive_timeout: Option<redis::AdaptiveTimeoutConfig>,
+    /// Namespaces the keys of the client, by prepending the prefix to the keys of its commands.
+    pub key_prefix: Option<String>,
+    /// Sends reads to a replica only after it replicated the client's writes, and to the primary otherwise.
+    /// Each tracked write costs an additional `INFO REPLICATION` round trip to the primary, and if it fails,
+    /// reads are sent to the primary until the next tracked write.
+    /// Multi-slot writes in cluster mode, such as `MSET` or `DEL` of keys in multiple slots,
+    /// and writes in pipelines and transactions aren't tracked.
+    pub read_your_writes: bool,
+    /// Standalone mode only. Checks the replication role of the nodes at this interval, to detect primary failovers
+    /// that weren't noticed by a failed write. Disabled by default.
+    pub primary_check_interval: Option<Duration>,
+    pub tls_configuration: Option<TlsConfiguration>,
+    /// Provides the credentials, instead of `authentication_info`, and refreshes them before they expire.
+    pub credential_provider: Option<std::sync::Arc<dyn super::CredentialProvider>>,
+}
+
+/// PEM encoded certificates used for TLS connections to the servers.
+#[derive(Clone, Default, Debug)]
+pub struct TlsConfiguration {
+    /// CA certificates trusted to verify the servers. If empty, the platform's trust store is used.
+    pub root_certs: Vec<Vec<u8>>,
+    /// The client certificate chain, for mutual TLS.
+    pub client_cert: Option<Vec<u8>>,
+    /// The private key of the client certificate.
+    pub client_key: Option<Vec<u8>>,
+    /// Overrides the server name sent in SNI and verified against the server certificates.
+    pub server_name: Option<String>,
+}
+
+/// Connection details of the sentinels monitoring a standalone deployment.
+/// When set, the primary and replicas are discovered through the sentinels instead of `addresses`.
+#[derive(Clone, Debug)]
+pub struct SentinelConfiguration {
+    pub addresses: Vec<NodeAddress>,
+    pub master_name: String,
+    pub authentication_info: Option<AuthenticationInfo>,
+    pub tls_mode: Option<TlsMode>,
+}
+
+/// Configuration of the in-process cache for read commands, kept up to date with `CLIENT TRACKING` invalidations.
+/// Requires RESP3.
+#[derive(Clone, Debug)]
+pub struct ClientSideCacheConfiguration {
+    /// The maximum number of cached responses. Zero means the default size.
+    pub max_entries: usize,
+    pub tracking_mode: redis::ClientTrackingMode,
 }
 
 #[derive(PartialEq, Eq, Clone, Default, Debug)]
@@ -34,6 +96,19 @@ pub struct AuthenticationInfo {
     pub password: Option<String>,
 }
 
+/// What happens to a request sent while `inflight_requests_limit` requests are already in flight.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InflightOverflowPolicy {
+    /// Fail the request immediately.
+    #[default]
+    FailFast,
+    /// Wait for an inflight request to complete, and fail the request if none completes within the given duration.
+    Wait(Duration),
+    /// Wait for an inflight request to complete, unless the given number of requests are already waiting.
+    /// A waiting request fails if no slot frees up within the request timeout.
+    Queue(u32),
+}
+
 #[derive(Default, Clone, Copy, Debug)]
 pub enum PeriodicCheck {
     #[default]
@@ -46,11 +121,16 @@ pub enum PeriodicCheck {
 pub struct NodeAddress {
     pub host: String,
     pub port: u16,
+    /// Path of a unix domain socket to connect through, instead of `host` and `port`.
+    pub unix_socket_path: Option<String>,
 }
 
 impl ::std::fmt::Display for NodeAddress {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        write!(f, "Host: `{}`, Port: {}", self.host, self.port)
+        match &self.unix_socket_path {
+            Some(path) => write!(f, "Unix socket: `{path}`"),
+            None => write!(f, "Host: `{}`, Port: {}", self.host, self.port),
+        }
     }
 }
 
@@ -59,6 +139,7 @@ pub enum ReadFrom {
     #[default]
     Primary,
     PreferReplica,
+    LowestLatency,
     AZAffinity(String),
     AZAffinityReplicasAndPrimary(String),
 }
@@ -90,18 +171,58 @@ fn chars_to_string_option(chars: &::protobuf::Chars) -> Option<String> {
     }
 }
 
+#[cfg(feature = "proto")]
+fn bytes_to_vec_option(bytes: &::bytes::Bytes) -> Option<Vec<u8>> {
+    if bytes.is_empty() {
+        None
+    } else {
+        Some(bytes.to_vec())
+    }
+}
+
 #[cfg(feature = "proto")]
 pub(crate) fn none_if_zero(value: u32) -> Option<u32> {
     if value == 0 { None } else { Some(value) }
 }
 
+#[cfg(feature = "proto")]
+fn node_address_from_proto(address: protobuf::NodeAddress) -> NodeAddress {
+    NodeAddress {
+        host: address.host.to_string(),
+        port: address.port as u16,
+        unix_socket_path: chars_to_string_option(&address.unix_socket_path),
+    }
+}
+
+#[cfg(feature = "proto")]
+fn tls_mode_from_proto(tls_mode: ::protobuf::EnumOrUnknown<protobuf::TlsMode>) -> Option<TlsMode> {
+    tls_mode.enum_value().ok().map(|val| match val {
+        protobuf::TlsMode::NoTls => TlsMode::NoTls,
+        protobuf::TlsMode::SecureTls => TlsMode::SecureTls,
+        protobuf::TlsMode::InsecureTls => TlsMode::InsecureTls,
+    })
+}
+
+#[cfg(feature = "proto")]
+fn authentication_info_from_proto(
+    authentication_info: protobuf::AuthenticationInfo,
+) -> Option<AuthenticationInfo> {
+    let password = chars_to_string_option(&authentication_info.password);
+    let username = chars_to_string_option(&authentication_info.username);
+    if password.is_none() && username.is_none() {
+        return None;
+    }
+
+    Some(AuthenticationInfo { password, username })
+}
+
 #[cfg(feature = "proto")]
 impl From<protobuf::ConnectionRequest> for ConnectionRequest {
     fn from(value: protobuf::ConnectionRequest) -> Self {
         let read_from = value.read_from.enum_value().ok().map(|val| match val {
             protobuf::ReadFrom::Primary => ReadFrom::Primary,
             protobuf::ReadFrom::PreferReplica => ReadFrom::PreferReplica,
-            protobuf::ReadFrom::LowestLatency => todo!(),
+            protobuf::ReadFrom::LowestLatency => ReadFrom::LowestLatency,
             protobuf::ReadFrom::AZAffinity => {
                 if let Some(client_az) = chars_to_string_option(&value.client_az) {
                     ReadFrom::AZAffinity(client_az)
@@ -133,15 +254,10 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
         });
 
         let client_name = chars_to_string_option(&value.client_name);
-        let authentication_info = value.authentication_info.0.and_then(|authentication_info| {
-            let password = chars_to_string_option(&authentication_info.password);
-            let username = chars_to_string_option(&authentication_info.username);
-            if password.is_none() && username.is_none() {
-                return None;
-            }
-
-            Some(AuthenticationInfo { password, username })
-        });
+        let authentication_info = value
+            .authentication_info
+            .0
+            .and_then(|authentication_info| authentication_info_from_proto(*authentication_info));
 
         let database_id = value.database_id as i64;
         let protocol = value.protocol.enum_value().ok().map(|val| match val {
@@ -149,19 +265,12 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
             protobuf::ProtocolVersion::RESP2 => redis::ProtocolVersion::RESP2,
         });
 
-        let tls_mode = value.tls_mode.enum_value().ok().map(|val| match val {
-            protobuf::TlsMode::NoTls => TlsMode::NoTls,
-            protobuf::TlsMode::SecureTls => TlsMode::SecureTls,
-            protobuf::TlsMode::InsecureTls => TlsMode::InsecureTls,
-        });
+        let tls_mode = tls_mode_from_proto(value.tls_mode);
 
         let addresses = value
             .addresses
             .into_iter()
-            .map(|addr| NodeAddress {
-                host: addr.host.to_string(),
-                port: addr.port as u16,
-            })
+            .map(node_address_from_proto)
             .collect();
         let cluster_mode_enabled = value.cluster_mode_enabled;
         let request_timeout = none_if_zero(value.request_timeout);
@@ -176,6 +285,16 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
                     number_of_retries: strategy.number_of_retries,
                     jitter_percent: strategy.jitter_percent,
                 });
+        let command_retry_strategy =
+            value
+                .command_retry_strategy
+                .0
+                .map(|strategy| ConnectionRetryStrategy {
+                    exponent_base: strategy.exponent_base,
+                    factor: strategy.factor,
+                    number_of_retries: strategy.number_of_retries,
+                    jitter_percent: strategy.jitter_percent,
+                });
         let periodic_checks = value
             .periodic_checks
             .map(|periodic_check| match periodic_check {
@@ -223,7 +342,137 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
         }
 
         let inflight_requests_limit = none_if_zero(value.inflight_requests_limit);
+        let inflight_overflow_policy = match value.inflight_overflow_policy {
+            Some(protobuf::connection_request::Inflight_overflow_policy::InflightOverflowWait(
+                wait,
+            )) => InflightOverflowPolicy::Wait(Duration::from_millis(wait.max_wait_ms.into())),
+            Some(
+                protobuf::connection_request::Inflight_overflow_policy::InflightOverflowQueue(
+                    queue,
+                ),
+            ) => InflightOverflowPolicy::Queue(queue.max_queue_length),
+            None => InflightOverflowPolicy::FailFast,
+        };
         let lazy_connect = value.lazy_connect;
+        let sentinel_configuration = value
+            .sentinel_configuration
+            .0
+            .map(|sentinel_configuration| SentinelConfiguration {
+                addresses: sentinel_configuration
+                    .addresses
+                    .into_iter()
+                    .map(node_address_from_proto)
+                    .collect(),
+                master_name: sentinel_configuration.master_name.to_string(),
+                authentication_info: sentinel_configuration.authentication_info.0.and_then(
+                    |authentication_info| authentication_info_from_proto(*authentication_info),
+                ),
+                tls_mode: tls_mode_from_proto(sentinel_configuration.tls_mode),
+            });
+        let client_side_cache =
+            value
+                .client_side_cache
+                .0
+                .map(|client_side_cache| ClientSideCacheConfiguration {
+                    max_entries: client_side_cache.max_entries as usize,
+                    tracking_mode: match client_side_cache.tracking_mode.enum_value() {
+                        Ok(protobuf::ClientTrackingMode::BroadcastTracking) => {
+                            redis::ClientTrackingMode::Broadcast {
+                                prefixes: client_side_cache
+                                    .prefixes
+                                    .iter()
+                                    .map(|prefix| prefix.to_string())
+                                    .collect(),
+                            }
+                        }
+                        Ok(protobuf::ClientTrackingMode::DefaultTracking) => {
+                            redis::ClientTrackingMode::Default
+                        }
+                        Err(mode) => {
+                            log_warn(
+                                "client creation",
+                                format!(
+                                    "Unknown client tracking mode {mode}, using default tracking"
+                                ),
+                            );
+                            redis::ClientTrackingMode::Default
+                        }
+                    },
+                });
+        let circuit_breaker = value.circuit_breaker.0.map(|circuit_breaker| {
+            let defaults = redis::CircuitBreakerConfig::default();
+            redis::CircuitBreakerConfig {
+                failure_threshold: none_if_zero(circuit_breaker.failure_threshold)
+                    .unwrap_or(defaults.failure_threshold),
+                open_duration: none_if_zero(circuit_breaker.open_duration_ms)
+                    .map(|duration| Duration::from_millis(duration.into()))
+                    .unwrap_or(defaults.open_duration),
+                half_open_probes: none_if_zero(circuit_breaker.half_open_probes)
+                    .unwrap_or(defaults.half_open_probes),
+            }
+        });
+
+        let request_hedging = value.request_hedging.0.map(|request_hedging| {
+            let defaults = redis::RequestHedgingConfig::default();
+            redis::RequestHedgingConfig {
+                delay: match request_hedging.delay {
+                    Some(protobuf::request_hedging_configuration::Delay::DelayMs(delay)) => {
+                        redis::HedgeDelay::Fixed(Duration::from_millis(delay.into()))
+                    }
+                    Some(protobuf::request_hedging_configuration::Delay::DelayPercentile(
+                        percentile,
+                    )) => redis::HedgeDelay::Percentile(percentile),
+                    None => defaults.delay,
+                },
+                max_hedge_percentage: none_if_zero(request_hedging.max_hedge_percentage)
+                    .unwrap_or(defaults.max_hedge_percentage),
+            }
+        });
+
+        let retry_budget = value.retry_budget.0.map(|retry_budget| {
+            let defaults = redis::RetryBudgetConfig::default();
+            redis::RetryBudgetConfig {
+                retry_percentage: none_if_zero(retry_budget.retry_percentage)
+                    .unwrap_or(defaults.retry_percentage),
+                min_retries_per_second: retry_budget
+                    .min_retries_per_second
+                    .unwrap_or(defaults.min_retries_per_second),
+            }
+        });
+
+        let adaptive_timeout = value.adaptive_timeout.0.map(|adaptive_timeout| {
+            let defaults = redis::AdaptiveTimeoutConfig::default();
+            redis::AdaptiveTimeoutConfig {
+                floor: none_if_zero(adaptive_timeout.floor_ms)
+                    .map(|floor| Duration::from_millis(floor.into()))
+                    .unwrap_or(defaults.floor),
+                multiplier: Some(adaptive_timeout.multiplier)
+                    .filter(|multiplier| *multiplier > 0.0)
+                    .unwrap_or(defaults.multiplier),
+            }
+        });
+
+        let key_prefix = chars_to_string_option(&value.key_prefix);
+
+        let read_your_writes = value.read_your_writes;
+
+        let primary_check_interval = (value.primary_check_interval > 0)
+            .then(|| Duration::from_secs(value.primary_check_interval.into()));
+
+        let tls_configuration =
+            value
+                .tls_configuration
+                .0
+                .map(|tls_configuration| TlsConfiguration {
+                    root_certs: tls_configuration
+                        .root_certs
+                        .iter()
+                        .map(|root_cert| root_cert.to_vec())
+                        .collect(),
+                    client_cert: bytes_to_vec_option(&tls_configuration.client_cert),
+                    client_key: bytes_to_vec_option(&tls_configuration.client_key),
+                    server_name: chars_to_string_option(&tls_configuration.server_name),
+                });
 
         ConnectionRequest {
             read_from,
@@ -240,7 +489,20 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
             periodic_checks,
             pubsub_subscriptions,
             inflight_requests_limit,
+            inflight_overflow_policy,
             lazy_connect,
+            sentinel_configuration,
+            client_side_cache,
+            circuit_breaker,
+            request_hedging,
+            command_retry_strategy,
+            retry_budget,
+            adaptive_timeout,
+            key_prefix,
+            read_your_writes,
+            primary_check_interval,
+            tls_configuration,
+            credential_provider: None,
         }
     }
 }
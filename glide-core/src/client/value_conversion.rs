@@ -26,7 +26,11 @@ pub(crate) enum ExpectedReturnType<'a> {
     ArrayOfStrings,
     ArrayOfBools,
     ArrayOfDoubleOrNull,
+    // The per-field statuses of the hash field expiration commands, with the number of fields in the command.
+    ArrayOfFieldStatuses(usize),
     FTAggregateReturnType,
+    // The reply of `FT.AGGREGATE ... WITHCURSOR` and `FT.CURSOR READ`, which is a page of results and the cursor id.
+    FTAggregateCursorReturnType,
     FTSearchReturnType,
     FTProfileReturnType(&'a Option<ExpectedReturnType<'a>>),
     FTInfoReturnType,
@@ -193,6 +197,21 @@ pub(crate) fn convert_to_expected_type(
             )
                 .into()),
         },
+        // A missing key is reported as `-2` for every field, so that the reply always has a status per field.
+        ExpectedReturnType::ArrayOfFieldStatuses(field_count) => match value {
+            Value::Nil => Ok(Value::Array(vec![Value::Int(-2); field_count])),
+            Value::Array(array) => array
+                .into_iter()
+                .map(|status| from_owned_redis_value::<i64>(status).map(Value::Int))
+                .collect::<RedisResult<_>>()
+                .map(Value::Array),
+            _ => Err((
+                ErrorKind::TypeError,
+                "Response couldn't be converted to an array of field statuses",
+                format!("(response was {:?})", get_value_type(&value)),
+            )
+                .into()),
+        },
         ExpectedReturnType::ArrayOfStrings => match value {
             Value::Array(array) => convert_array_elements(array, ExpectedReturnType::BulkString),
             _ => Err((
@@ -951,6 +970,36 @@ pub(crate) fn convert_to_expected_type(
             )
                 .into()),
         },
+        ExpectedReturnType::FTAggregateCursorReturnType => match value {
+            /*
+            Example of the response
+                1) <FT.AGGREGATE response>
+                2) (integer) 4567
+
+            Converting response to
+                1) <converted FT.AGGREGATE response>
+                2) (integer) 4567
+
+            The cursor id is 0 once the results are exhausted.
+            */
+            Value::Array(mut array) if array.len() == 2 => {
+                let cursor_id = array.pop().unwrap();
+                let results = array.pop().unwrap();
+                Ok(Value::Array(vec![
+                    convert_to_expected_type(
+                        results,
+                        Some(ExpectedReturnType::FTAggregateReturnType),
+                    )?,
+                    cursor_id,
+                ]))
+            }
+            _ => Err((
+                ErrorKind::TypeError,
+                "Response couldn't be converted for FT.AGGREGATE cursor",
+                format!("(response was {:?})", get_value_type(&value)),
+            )
+                .into()),
+        },
         ExpectedReturnType::FTSearchReturnType => match value {
             /*
             Example of the response
@@ -1396,6 +1445,19 @@ fn is_array(val: Value) -> bool {
     matches!(val, Value::Array(_))
 }
 
+/// Returns the number of fields in the `FIELDS numfields field ...` argument of a hash field command,
+/// which is searched for from the argument at `first_index`, so that a key or a numeric argument named `FIELDS` isn't mistaken for it.
+fn hash_field_count(cmd: &Cmd, first_index: usize) -> Option<usize> {
+    let fields_position = (first_index..)
+        .map_while(|idx| cmd.arg_idx(idx).map(|arg| (idx, arg)))
+        .find(|(_, arg)| arg.eq_ignore_ascii_case(b"FIELDS"))?
+        .0;
+    std::str::from_utf8(cmd.arg_idx(fields_position + 1)?)
+        .ok()?
+        .parse()
+        .ok()
+}
+
 pub(crate) fn expected_type_for_cmd(cmd: &Cmd) -> Option<ExpectedReturnType> {
     let command = cmd.command()?;
 
@@ -1464,8 +1526,27 @@ pub(crate) fn expected_type_for_cmd(cmd: &Cmd) -> Option<ExpectedReturnType> {
         | b"COPY"
         | b"MSETNX"
         | b"XGROUP DESTROY"
-        | b"XGROUP CREATECONSUMER" => Some(ExpectedReturnType::Boolean),
-        b"SMISMEMBER" | b"SCRIPT EXISTS" => Some(ExpectedReturnType::ArrayOfBools),
+        | b"XGROUP CREATECONSUMER"
+        | b"HSETEX"
+        | b"DELIFEQ"
+        | b"BF.ADD"
+        | b"BF.EXISTS" => Some(ExpectedReturnType::Boolean),
+        b"SMISMEMBER" | b"SCRIPT EXISTS" | b"BF.MADD" | b"BF.MEXISTS" | b"BF.INSERT" => {
+            Some(ExpectedReturnType::ArrayOfBools)
+        }
+        // `BF.INFO key field` replies with the value of the field alone.
+        b"BF.INFO" => cmd.arg_idx(2).is_none().then_some(ExpectedReturnType::Map {
+            key_type: &None,
+            value_type: &None,
+        }),
+        // `HEXPIRE key seconds [NX | XX | GT | LT] FIELDS numfields field ...`
+        b"HEXPIRE" | b"HPEXPIRE" | b"HEXPIREAT" | b"HPEXPIREAT" => {
+            hash_field_count(cmd, 3).map(ExpectedReturnType::ArrayOfFieldStatuses)
+        }
+        // `HTTL key FIELDS numfields field ...`
+        b"HTTL" | b"HPTTL" | b"HEXPIRETIME" | b"HPEXPIRETIME" | b"HPERSIST" => {
+            hash_field_count(cmd, 2).map(ExpectedReturnType::ArrayOfFieldStatuses)
+        }
         b"SMEMBERS" | b"SINTER" | b"SDIFF" | b"SUNION" => Some(ExpectedReturnType::Set),
         b"ZSCORE" | b"GEODIST" => Some(ExpectedReturnType::DoubleOrNull),
         b"ZMSCORE" => Some(ExpectedReturnType::ArrayOfDoubleOrNull),
@@ -1533,7 +1614,11 @@ pub(crate) fn expected_type_for_cmd(cmd: &Cmd) -> Option<ExpectedReturnType> {
             key_type: &None,
             value_type: &None,
         }),
+        b"FT.AGGREGATE" if cmd.position(b"WITHCURSOR").is_some() => {
+            Some(ExpectedReturnType::FTAggregateCursorReturnType)
+        }
         b"FT.AGGREGATE" => Some(ExpectedReturnType::FTAggregateReturnType),
+        b"FT.CURSOR READ" => Some(ExpectedReturnType::FTAggregateCursorReturnType),
         b"FT.SEARCH" => Some(ExpectedReturnType::FTSearchReturnType),
         // TODO replace with tuple
         b"FT.PROFILE" => Some(ExpectedReturnType::FTProfileReturnType(
@@ -3342,6 +3427,195 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_convert_hash_field_statuses() {
+        let cmd = redis::cmd("HEXPIRE")
+            .arg("session")
+            .arg("60")
+            .arg("FIELDS")
+            .arg("2")
+            .arg("token")
+            .arg("user")
+            .clone();
+        let expected_type = expected_type_for_cmd(&cmd);
+        assert!(matches!(
+            expected_type,
+            Some(ExpectedReturnType::ArrayOfFieldStatuses(2))
+        ));
+        assert_eq!(
+            convert_to_expected_type(
+                Value::Array(vec![Value::Int(1), Value::Int(-2)]),
+                expected_type
+            )
+            .unwrap(),
+            Value::Array(vec![Value::Int(1), Value::Int(-2)])
+        );
+        assert_eq!(
+            convert_to_expected_type(Value::Nil, expected_type).unwrap(),
+            Value::Array(vec![Value::Int(-2), Value::Int(-2)])
+        );
+        assert!(
+            convert_to_expected_type(Value::BulkString(b"OK".to_vec()), expected_type).is_err()
+        );
+
+        assert!(expected_type_for_cmd(redis::cmd("HTTL").arg("session")).is_none());
+        // A key named `FIELDS` isn't mistaken for the `FIELDS` argument.
+        assert!(matches!(
+            expected_type_for_cmd(
+                redis::cmd("HTTL")
+                    .arg("FIELDS")
+                    .arg("FIELDS")
+                    .arg("1")
+                    .arg("token")
+            ),
+            Some(ExpectedReturnType::ArrayOfFieldStatuses(1))
+        ));
+        assert!(matches!(
+            expected_type_for_cmd(
+                redis::cmd("HPEXPIRE")
+                    .arg("FIELDS")
+                    .arg("100")
+                    .arg("NX")
+                    .arg("FIELDS")
+                    .arg("3")
+                    .arg("a")
+                    .arg("b")
+                    .arg("FIELDS")
+            ),
+            Some(ExpectedReturnType::ArrayOfFieldStatuses(3))
+        ));
+        assert!(matches!(
+            expected_type_for_cmd(
+                redis::cmd("HSETEX")
+                    .arg("session")
+                    .arg("FIELDS")
+                    .arg("1")
+                    .arg("token")
+                    .arg("value")
+            ),
+            Some(ExpectedReturnType::Boolean)
+        ));
+    }
+
+    #[test]
+    fn test_convert_conditional_writes() {
+        let cmd = redis::cmd("DELIFEQ").arg("lock").arg("token").clone();
+        let expected_type = expected_type_for_cmd(&cmd);
+        assert!(matches!(expected_type, Some(ExpectedReturnType::Boolean)));
+        assert_eq!(
+            convert_to_expected_type(Value::Int(0), expected_type).unwrap(),
+            Value::Boolean(false)
+        );
+
+        // `SET ... IFEQ` replies with `OK`, or nil when the value doesn't match.
+        assert!(
+            expected_type_for_cmd(
+                redis::cmd("SET")
+                    .arg("lock")
+                    .arg("new-token")
+                    .arg("IFEQ")
+                    .arg("token")
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_convert_bloom_filter_replies() {
+        let cmd = redis::cmd("BF.MEXISTS")
+            .arg("seen")
+            .arg("a")
+            .arg("b")
+            .clone();
+        assert_eq!(
+            convert_to_expected_type(
+                Value::Array(vec![Value::Int(1), Value::Int(0)]),
+                expected_type_for_cmd(&cmd)
+            )
+            .unwrap(),
+            Value::Array(vec![Value::Boolean(true), Value::Boolean(false)])
+        );
+
+        let cmd = redis::cmd("BF.INFO").arg("seen").clone();
+        let expected_type = expected_type_for_cmd(&cmd);
+        let info = vec![
+            Value::BulkString(b"Capacity".to_vec()),
+            Value::Int(100),
+            Value::BulkString(b"Number of items inserted".to_vec()),
+            Value::Int(2),
+        ];
+        let expected_map = Value::Map(vec![
+            (Value::BulkString(b"Capacity".to_vec()), Value::Int(100)),
+            (
+                Value::BulkString(b"Number of items inserted".to_vec()),
+                Value::Int(2),
+            ),
+        ]);
+        assert_eq!(
+            convert_to_expected_type(Value::Array(info), expected_type).unwrap(),
+            expected_map
+        );
+
+        let cmd = redis::cmd("BF.INFO").arg("seen").arg("CAPACITY").clone();
+        assert!(expected_type_for_cmd(&cmd).is_none());
+    }
+
+    #[test]
+    fn test_convert_ft_aggregate_cursor_replies() {
+        let page = Value::Array(vec![
+            Value::Array(vec![
+                Value::Int(1),
+                Value::Array(vec![
+                    Value::BulkString(b"condition".to_vec()),
+                    Value::BulkString(b"new".to_vec()),
+                ]),
+            ]),
+            Value::Int(4567),
+        ]);
+        let expected_page = Value::Array(vec![
+            Value::Array(vec![Value::Map(vec![(
+                Value::BulkString(b"condition".to_vec()),
+                Value::BulkString(b"new".to_vec()),
+            )])]),
+            Value::Int(4567),
+        ]);
+
+        let cmd = redis::cmd("FT.AGGREGATE")
+            .arg("idx")
+            .arg("*")
+            .arg("WITHCURSOR")
+            .arg("COUNT")
+            .arg("1")
+            .clone();
+        let expected_type = expected_type_for_cmd(&cmd);
+        assert!(matches!(
+            expected_type,
+            Some(ExpectedReturnType::FTAggregateCursorReturnType)
+        ));
+        assert_eq!(
+            convert_to_expected_type(page.clone(), expected_type).unwrap(),
+            expected_page
+        );
+
+        let cmd = redis::cmd("FT.CURSOR")
+            .arg("READ")
+            .arg("idx")
+            .arg("4567")
+            .clone();
+        let expected_type = expected_type_for_cmd(&cmd);
+        assert!(matches!(
+            expected_type,
+            Some(ExpectedReturnType::FTAggregateCursorReturnType)
+        ));
+        assert_eq!(
+            convert_to_expected_type(page, expected_type).unwrap(),
+            expected_page
+        );
+        assert!(
+            convert_to_expected_type(Value::Array(vec![Value::Int(0)]), expected_type).is_err()
+        );
+    }
+
     #[test]
     fn test_convert_spop_to_set_for_spop_count() {
         assert!(matches!(
@@ -0,0 +1,181 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use crate::client::WeakClient;
+use logger_core::{log_debug, log_warn};
+use nanoid::nanoid;
+use once_cell::sync::Lazy;
+use redis::cluster_routing::{Routable, RoutingInfo};
+use redis::{Cmd, RedisResult};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use tokio::runtime::Handle;
+
+// This is a container for storing the cursors of `FT.AGGREGATE ... WITHCURSOR`.
+// A search cursor is kept by the node that created it, so alongside the cursor id that the server returned,
+// we store the routing to that node, and send all the `FT.CURSOR` commands of the cursor there.
+// Like the cluster scan cursors, only the id of the cursor in the container is passed to the wrapper layer.
+// In wrapper layer we wrap the id in an object, which, when dropped, trigger the removal of the cursor from the container.
+// When the last ref to a cursor that the server still holds is dropped, the cursor is released with `FT.CURSOR DEL`,
+// instead of waiting for the server to expire it after it's idle.
+
+/// A cursor of `FT.AGGREGATE ... WITHCURSOR`, pinned to the node that created it.
+/// The cursor doesn't keep its client's connections open, so a closed client isn't kept alive by the container.
+pub struct FtCursor {
+    client: WeakClient,
+    runtime: Handle,
+    routing: Option<RoutingInfo>,
+    index: Vec<u8>,
+    cursor_id: i64,
+    // Set once the server no longer holds the cursor, because it was exhausted or deleted.
+    released: AtomicBool,
+}
+
+pub type FtCursorRC = Arc<FtCursor>;
+
+impl FtCursor {
+    /// Creates a cursor of `index`, which is served by the node of `routing`.
+    /// Must be called from within a Tokio runtime, which is used to release the cursor when it's dropped.
+    pub(crate) fn new(
+        client: WeakClient,
+        routing: Option<RoutingInfo>,
+        index: Vec<u8>,
+        cursor_id: i64,
+    ) -> Self {
+        FtCursor {
+            client,
+            runtime: Handle::current(),
+            routing,
+            index,
+            cursor_id,
+            released: AtomicBool::new(false),
+        }
+    }
+
+    pub(crate) fn routing(&self) -> Option<RoutingInfo> {
+        self.routing.clone()
+    }
+
+    /// Builds `FT.CURSOR <subcommand>` for the server's cursor, followed by `args`.
+    pub(crate) fn command<'a>(
+        &self,
+        subcommand: &[u8],
+        args: impl IntoIterator<Item = &'a [u8]>,
+    ) -> Cmd {
+        let mut cmd = redis::cmd("FT.CURSOR");
+        cmd.arg(subcommand).arg(&self.index).arg(self.cursor_id);
+        for arg in args {
+            cmd.arg(arg);
+        }
+        cmd
+    }
+
+    /// Marks the cursor as released by the server, so it isn't deleted when it's dropped.
+    pub(crate) fn mark_released(&self) {
+        self.released.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for FtCursor {
+    fn drop(&mut self) {
+        if self.released.load(Ordering::Relaxed) {
+            return;
+        }
+        // Once the client is closed, the server expires the cursor after it's idle.
+        let Some(mut client) = self.client.upgrade() else {
+            return;
+        };
+        let routing = self.routing.clone();
+        let cmd = self.command(b"DEL", []);
+        self.runtime.spawn(async move {
+            if let Err(err) = client.send_single_command(&cmd, routing, None, true).await {
+                log_warn(
+                    "ft_cursor release",
+                    format!("Failed to release a dropped FT cursor: {err}"),
+                );
+            }
+        });
+    }
+}
+
+/// Returns true if `cmd` creates or uses an FT cursor, and so must be served by the node that holds the cursor.
+pub(crate) fn is_ft_cursor_command(cmd: &Cmd) -> bool {
+    match cmd.command().as_deref() {
+        Some(b"FT.AGGREGATE") => cmd.position(b"WITHCURSOR").is_some(),
+        Some(b"FT.CURSOR READ") | Some(b"FT.CURSOR DEL") => true,
+        _ => false,
+    }
+}
+
+static CONTAINER: Lazy<Mutex<HashMap<String, FtCursorRC>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub fn insert_ft_cursor(cursor: FtCursor) -> String {
+    let id = nanoid!();
+    CONTAINER
+        .lock()
+        .unwrap()
+        .insert(id.clone(), Arc::new(cursor));
+    log_debug(
+        "ft_cursor insert",
+        format!("Inserted to container ft_cursor with id: `{:?}`", id),
+    );
+    id
+}
+
+pub fn get_ft_cursor(id: &str) -> RedisResult<FtCursorRC> {
+    let cursor = CONTAINER.lock().unwrap().get(id).cloned();
+    log_debug(
+        "ft_cursor get",
+        format!("Retrieved from container ft_cursor with id: `{:?}`", id),
+    );
+    match cursor {
+        Some(cursor) => Ok(cursor),
+        None => Err(redis::RedisError::from((
+            redis::ErrorKind::ResponseError,
+            "Invalid ft_cursor id",
+            format!("The ft_cursor sent with id: `{:?}` does not exist", id),
+        ))),
+    }
+}
+
+/// Removes the cursor from the container. Once no request uses it, it's released on its node.
+pub fn remove_ft_cursor(id: String) {
+    log_debug(
+        "ft_cursor remove",
+        format!("Removed from container ft_cursor with id: `{:?}`", id),
+    );
+    CONTAINER.lock().unwrap().remove(&id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_ft_cursor_command() {
+        assert!(is_ft_cursor_command(
+            redis::cmd("FT.AGGREGATE")
+                .arg("idx")
+                .arg("*")
+                .arg("WITHCURSOR")
+        ));
+        assert!(!is_ft_cursor_command(
+            redis::cmd("FT.AGGREGATE").arg("idx").arg("*")
+        ));
+        assert!(is_ft_cursor_command(
+            redis::cmd("FT.CURSOR").arg("read").arg("idx").arg("id")
+        ));
+        assert!(is_ft_cursor_command(
+            redis::cmd("FT.CURSOR").arg("DEL").arg("idx").arg("id")
+        ));
+        assert!(!is_ft_cursor_command(redis::cmd("FT.SEARCH").arg("idx")));
+    }
+
+    #[test]
+    fn test_unknown_ft_cursor_is_rejected() {
+        assert!(get_ft_cursor("unknown").is_err());
+    }
+}
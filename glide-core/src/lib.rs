@@ -11,8 +11,9 @@ mod socket_listener;
 pub use socket_listener::*;
 pub mod errors;
 pub mod scripts_container;
-pub use client::ConnectionRequest;
+pub use client::{ConnectionRequest, parse_connection_uri};
 pub mod cluster_scan_container;
+pub mod ft_cursor_container;
 pub mod request_type;
 pub use telemetrylib::{
     DEFAULT_FLUSH_SIGNAL_INTERVAL_MS, DEFAULT_TRACE_SAMPLE_PERCENTAGE, GlideOpenTelemetry,
@@ -144,6 +144,17 @@ pub enum RequestType {
     HSetNX = 614,
     HStrlen = 615,
     HVals = 616,
+    HExpire = 617,
+    HPExpire = 618,
+    HExpireAt = 619,
+    HPExpireAt = 620,
+    HTTL = 621,
+    HPTTL = 622,
+    HExpireTime = 623,
+    HPExpireTime = 624,
+    HPersist = 625,
+    HGetEx = 626,
+    HSetEx = 627,
 
     //// HyperLogLog commands
     PfAdd = 701,
@@ -377,6 +388,7 @@ pub enum RequestType {
     SetRange = 1520,
     Strlen = 1521,
     Substr = 1522,
+    DelIfEq = 1523,
 
     //// Transaction commands
     Discard = 1601,
@@ -423,6 +435,17 @@ pub enum RequestType {
     FtInfo = 2111,
     FtProfile = 2112,
     FtSearch = 2113,
+    FtCursorRead = 2114,
+    FtCursorDel = 2115,
+    BfAdd = 2201,
+    BfCard = 2202,
+    BfExists = 2203,
+    BfInfo = 2204,
+    BfInsert = 2205,
+    BfLoad = 2206,
+    BfMAdd = 2207,
+    BfMExists = 2208,
+    BfReserve = 2209,
 }
 
 fn get_two_word_command(first: &str, second: &str) -> Cmd {
@@ -432,9 +455,15 @@ fn get_two_word_command(first: &str, second: &str) -> Cmd {
 }
 
 #[cfg(feature = "proto")]
-impl From<::protobuf::EnumOrUnknown<ProtobufRequestType>> for RequestType {
-    fn from(value: ::protobuf::EnumOrUnknown<ProtobufRequestType>) -> Self {
-        match value.enum_value_or(ProtobufRequestType::InvalidRequest) {
+impl TryFrom<::protobuf::EnumOrUnknown<ProtobufRequestType>> for RequestType {
+    /// The number of a request type that isn't known to this version of the core,
+    /// such as one that was sent by a newer binding.
+    type Error = i32;
+
+    fn try_from(value: ::protobuf::EnumOrUnknown<ProtobufRequestType>) -> Result<Self, i32> {
+        // This match and the one in the reverse conversion are exhaustive,
+        // so that a request type that is added to only one of the enums fails to compile.
+        Ok(match value.enum_value()? {
             ProtobufRequestType::InvalidRequest => RequestType::InvalidRequest,
             ProtobufRequestType::CustomCommand => RequestType::CustomCommand,
             ProtobufRequestType::Get => RequestType::Get,
@@ -520,6 +549,17 @@ impl From<::protobuf::EnumOrUnknown<ProtobufRequestType>> for RequestType {
             ProtobufRequestType::HSetNX => RequestType::HSetNX,
             ProtobufRequestType::SIsMember => RequestType::SIsMember,
             ProtobufRequestType::HVals => RequestType::HVals,
+            ProtobufRequestType::HExpire => RequestType::HExpire,
+            ProtobufRequestType::HPExpire => RequestType::HPExpire,
+            ProtobufRequestType::HExpireAt => RequestType::HExpireAt,
+            ProtobufRequestType::HPExpireAt => RequestType::HPExpireAt,
+            ProtobufRequestType::HTTL => RequestType::HTTL,
+            ProtobufRequestType::HPTTL => RequestType::HPTTL,
+            ProtobufRequestType::HExpireTime => RequestType::HExpireTime,
+            ProtobufRequestType::HPExpireTime => RequestType::HPExpireTime,
+            ProtobufRequestType::HPersist => RequestType::HPersist,
+            ProtobufRequestType::HGetEx => RequestType::HGetEx,
+            ProtobufRequestType::HSetEx => RequestType::HSetEx,
             ProtobufRequestType::PTTL => RequestType::PTTL,
             ProtobufRequestType::ZRemRangeByRank => RequestType::ZRemRangeByRank,
             ProtobufRequestType::Persist => RequestType::Persist,
@@ -688,7 +728,558 @@ impl From<::protobuf::EnumOrUnknown<ProtobufRequestType>> for RequestType {
             ProtobufRequestType::FtInfo => RequestType::FtInfo,
             ProtobufRequestType::FtProfile => RequestType::FtProfile,
             ProtobufRequestType::FtSearch => RequestType::FtSearch,
-            _ => todo!(),
+            ProtobufRequestType::FtCursorRead => RequestType::FtCursorRead,
+            ProtobufRequestType::FtCursorDel => RequestType::FtCursorDel,
+            ProtobufRequestType::BfAdd => RequestType::BfAdd,
+            ProtobufRequestType::BfCard => RequestType::BfCard,
+            ProtobufRequestType::BfExists => RequestType::BfExists,
+            ProtobufRequestType::BfInfo => RequestType::BfInfo,
+            ProtobufRequestType::BfInsert => RequestType::BfInsert,
+            ProtobufRequestType::BfLoad => RequestType::BfLoad,
+            ProtobufRequestType::BfMAdd => RequestType::BfMAdd,
+            ProtobufRequestType::BfMExists => RequestType::BfMExists,
+            ProtobufRequestType::BfReserve => RequestType::BfReserve,
+            ProtobufRequestType::Asking => RequestType::Asking,
+            ProtobufRequestType::ClusterAddSlots => RequestType::ClusterAddSlots,
+            ProtobufRequestType::ClusterAddSlotsRange => RequestType::ClusterAddSlotsRange,
+            ProtobufRequestType::ClusterBumpEpoch => RequestType::ClusterBumpEpoch,
+            ProtobufRequestType::ClusterCountFailureReports => {
+                RequestType::ClusterCountFailureReports
+            }
+            ProtobufRequestType::ClusterCountKeysInSlot => RequestType::ClusterCountKeysInSlot,
+            ProtobufRequestType::ClusterDelSlots => RequestType::ClusterDelSlots,
+            ProtobufRequestType::ClusterDelSlotsRange => RequestType::ClusterDelSlotsRange,
+            ProtobufRequestType::ClusterFailover => RequestType::ClusterFailover,
+            ProtobufRequestType::ClusterFlushSlots => RequestType::ClusterFlushSlots,
+            ProtobufRequestType::ClusterForget => RequestType::ClusterForget,
+            ProtobufRequestType::ClusterGetKeysInSlot => RequestType::ClusterGetKeysInSlot,
+            ProtobufRequestType::ClusterInfo => RequestType::ClusterInfo,
+            ProtobufRequestType::ClusterKeySlot => RequestType::ClusterKeySlot,
+            ProtobufRequestType::ClusterLinks => RequestType::ClusterLinks,
+            ProtobufRequestType::ClusterMeet => RequestType::ClusterMeet,
+            ProtobufRequestType::ClusterMyId => RequestType::ClusterMyId,
+            ProtobufRequestType::ClusterMyShardId => RequestType::ClusterMyShardId,
+            ProtobufRequestType::ClusterNodes => RequestType::ClusterNodes,
+            ProtobufRequestType::ClusterReplicas => RequestType::ClusterReplicas,
+            ProtobufRequestType::ClusterReplicate => RequestType::ClusterReplicate,
+            ProtobufRequestType::ClusterReset => RequestType::ClusterReset,
+            ProtobufRequestType::ClusterSaveConfig => RequestType::ClusterSaveConfig,
+            ProtobufRequestType::ClusterSetConfigEpoch => RequestType::ClusterSetConfigEpoch,
+            ProtobufRequestType::ClusterSetslot => RequestType::ClusterSetslot,
+            ProtobufRequestType::ClusterShards => RequestType::ClusterShards,
+            ProtobufRequestType::ClusterSlaves => RequestType::ClusterSlaves,
+            ProtobufRequestType::ClusterSlots => RequestType::ClusterSlots,
+            ProtobufRequestType::ReadOnly => RequestType::ReadOnly,
+            ProtobufRequestType::ReadWrite => RequestType::ReadWrite,
+            ProtobufRequestType::Auth => RequestType::Auth,
+            ProtobufRequestType::ClientCaching => RequestType::ClientCaching,
+            ProtobufRequestType::ClientKillSimple => RequestType::ClientKillSimple,
+            ProtobufRequestType::ClientTracking => RequestType::ClientTracking,
+            ProtobufRequestType::ClientTrackingInfo => RequestType::ClientTrackingInfo,
+            ProtobufRequestType::Hello => RequestType::Hello,
+            ProtobufRequestType::Quit => RequestType::Quit,
+            ProtobufRequestType::Reset => RequestType::Reset,
+            ProtobufRequestType::Keys => RequestType::Keys,
+            ProtobufRequestType::Migrate => RequestType::Migrate,
+            ProtobufRequestType::WaitAof => RequestType::WaitAof,
+            ProtobufRequestType::GeoRadius => RequestType::GeoRadius,
+            ProtobufRequestType::GeoRadiusReadOnly => RequestType::GeoRadiusReadOnly,
+            ProtobufRequestType::GeoRadiusByMember => RequestType::GeoRadiusByMember,
+            ProtobufRequestType::GeoRadiusByMemberReadOnly => {
+                RequestType::GeoRadiusByMemberReadOnly
+            }
+            ProtobufRequestType::BRPopLPush => RequestType::BRPopLPush,
+            ProtobufRequestType::RPopLPush => RequestType::RPopLPush,
+            ProtobufRequestType::PSubscribe => RequestType::PSubscribe,
+            ProtobufRequestType::PUnsubscribe => RequestType::PUnsubscribe,
+            ProtobufRequestType::SSubscribe => RequestType::SSubscribe,
+            ProtobufRequestType::Subscribe => RequestType::Subscribe,
+            ProtobufRequestType::SUnsubscribe => RequestType::SUnsubscribe,
+            ProtobufRequestType::Unsubscribe => RequestType::Unsubscribe,
+            ProtobufRequestType::Eval => RequestType::Eval,
+            ProtobufRequestType::EvalReadOnly => RequestType::EvalReadOnly,
+            ProtobufRequestType::EvalSha => RequestType::EvalSha,
+            ProtobufRequestType::EvalShaReadOnly => RequestType::EvalShaReadOnly,
+            ProtobufRequestType::ScriptDebug => RequestType::ScriptDebug,
+            ProtobufRequestType::ScriptLoad => RequestType::ScriptLoad,
+            ProtobufRequestType::AclCat => RequestType::AclCat,
+            ProtobufRequestType::AclDelUser => RequestType::AclDelUser,
+            ProtobufRequestType::AclDryRun => RequestType::AclDryRun,
+            ProtobufRequestType::AclGenPass => RequestType::AclGenPass,
+            ProtobufRequestType::AclGetUser => RequestType::AclGetUser,
+            ProtobufRequestType::AclList => RequestType::AclList,
+            ProtobufRequestType::AclLoad => RequestType::AclLoad,
+            ProtobufRequestType::AclLog => RequestType::AclLog,
+            ProtobufRequestType::AclSave => RequestType::AclSave,
+            ProtobufRequestType::AclSetSser => RequestType::AclSetSser,
+            ProtobufRequestType::AclUsers => RequestType::AclUsers,
+            ProtobufRequestType::AclWhoami => RequestType::AclWhoami,
+            ProtobufRequestType::BgRewriteAof => RequestType::BgRewriteAof,
+            ProtobufRequestType::BgSave => RequestType::BgSave,
+            ProtobufRequestType::Command_ => RequestType::Command_,
+            ProtobufRequestType::CommandCount => RequestType::CommandCount,
+            ProtobufRequestType::CommandDocs => RequestType::CommandDocs,
+            ProtobufRequestType::CommandGetKeys => RequestType::CommandGetKeys,
+            ProtobufRequestType::CommandGetKeysAndFlags => RequestType::CommandGetKeysAndFlags,
+            ProtobufRequestType::CommandInfo => RequestType::CommandInfo,
+            ProtobufRequestType::CommandList => RequestType::CommandList,
+            ProtobufRequestType::FailOver => RequestType::FailOver,
+            ProtobufRequestType::LatencyDoctor => RequestType::LatencyDoctor,
+            ProtobufRequestType::LatencyGraph => RequestType::LatencyGraph,
+            ProtobufRequestType::LatencyHistogram => RequestType::LatencyHistogram,
+            ProtobufRequestType::LatencyHistory => RequestType::LatencyHistory,
+            ProtobufRequestType::LatencyLatest => RequestType::LatencyLatest,
+            ProtobufRequestType::LatencyReset => RequestType::LatencyReset,
+            ProtobufRequestType::MemoryDoctor => RequestType::MemoryDoctor,
+            ProtobufRequestType::MemoryMallocStats => RequestType::MemoryMallocStats,
+            ProtobufRequestType::MemoryPurge => RequestType::MemoryPurge,
+            ProtobufRequestType::MemoryStats => RequestType::MemoryStats,
+            ProtobufRequestType::MemoryUsage => RequestType::MemoryUsage,
+            ProtobufRequestType::ModuleList => RequestType::ModuleList,
+            ProtobufRequestType::ModuleLoad => RequestType::ModuleLoad,
+            ProtobufRequestType::ModuleLoadEx => RequestType::ModuleLoadEx,
+            ProtobufRequestType::ModuleUnload => RequestType::ModuleUnload,
+            ProtobufRequestType::Monitor => RequestType::Monitor,
+            ProtobufRequestType::PSync => RequestType::PSync,
+            ProtobufRequestType::ReplConf => RequestType::ReplConf,
+            ProtobufRequestType::ReplicaOf => RequestType::ReplicaOf,
+            ProtobufRequestType::RestoreAsking => RequestType::RestoreAsking,
+            ProtobufRequestType::Role => RequestType::Role,
+            ProtobufRequestType::Save => RequestType::Save,
+            ProtobufRequestType::ShutDown => RequestType::ShutDown,
+            ProtobufRequestType::SlaveOf => RequestType::SlaveOf,
+            ProtobufRequestType::SlowLogGet => RequestType::SlowLogGet,
+            ProtobufRequestType::SlowLogLen => RequestType::SlowLogLen,
+            ProtobufRequestType::SlowLogReset => RequestType::SlowLogReset,
+            ProtobufRequestType::SwapDb => RequestType::SwapDb,
+            ProtobufRequestType::Sync => RequestType::Sync,
+            ProtobufRequestType::ZRangeByLex => RequestType::ZRangeByLex,
+            ProtobufRequestType::ZRangeByScore => RequestType::ZRangeByScore,
+            ProtobufRequestType::ZRevRange => RequestType::ZRevRange,
+            ProtobufRequestType::ZRevRangeByLex => RequestType::ZRevRangeByLex,
+            ProtobufRequestType::ZRevRangeByScore => RequestType::ZRevRangeByScore,
+            ProtobufRequestType::XSetId => RequestType::XSetId,
+            ProtobufRequestType::GetSet => RequestType::GetSet,
+            ProtobufRequestType::PSetEx => RequestType::PSetEx,
+            ProtobufRequestType::SetEx => RequestType::SetEx,
+            ProtobufRequestType::SetNX => RequestType::SetNX,
+            ProtobufRequestType::Substr => RequestType::Substr,
+            ProtobufRequestType::DelIfEq => RequestType::DelIfEq,
+            ProtobufRequestType::Discard => RequestType::Discard,
+            ProtobufRequestType::Exec => RequestType::Exec,
+            ProtobufRequestType::Multi => RequestType::Multi,
+        })
+    }
+}
+
+#[cfg(feature = "proto")]
+impl From<RequestType> for ProtobufRequestType {
+    fn from(value: RequestType) -> Self {
+        match value {
+            RequestType::InvalidRequest => ProtobufRequestType::InvalidRequest,
+            RequestType::CustomCommand => ProtobufRequestType::CustomCommand,
+            RequestType::BitCount => ProtobufRequestType::BitCount,
+            RequestType::BitField => ProtobufRequestType::BitField,
+            RequestType::BitFieldReadOnly => ProtobufRequestType::BitFieldReadOnly,
+            RequestType::BitOp => ProtobufRequestType::BitOp,
+            RequestType::BitPos => ProtobufRequestType::BitPos,
+            RequestType::GetBit => ProtobufRequestType::GetBit,
+            RequestType::SetBit => ProtobufRequestType::SetBit,
+            RequestType::Asking => ProtobufRequestType::Asking,
+            RequestType::ClusterAddSlots => ProtobufRequestType::ClusterAddSlots,
+            RequestType::ClusterAddSlotsRange => ProtobufRequestType::ClusterAddSlotsRange,
+            RequestType::ClusterBumpEpoch => ProtobufRequestType::ClusterBumpEpoch,
+            RequestType::ClusterCountFailureReports => {
+                ProtobufRequestType::ClusterCountFailureReports
+            }
+            RequestType::ClusterCountKeysInSlot => ProtobufRequestType::ClusterCountKeysInSlot,
+            RequestType::ClusterDelSlots => ProtobufRequestType::ClusterDelSlots,
+            RequestType::ClusterDelSlotsRange => ProtobufRequestType::ClusterDelSlotsRange,
+            RequestType::ClusterFailover => ProtobufRequestType::ClusterFailover,
+            RequestType::ClusterFlushSlots => ProtobufRequestType::ClusterFlushSlots,
+            RequestType::ClusterForget => ProtobufRequestType::ClusterForget,
+            RequestType::ClusterGetKeysInSlot => ProtobufRequestType::ClusterGetKeysInSlot,
+            RequestType::ClusterInfo => ProtobufRequestType::ClusterInfo,
+            RequestType::ClusterKeySlot => ProtobufRequestType::ClusterKeySlot,
+            RequestType::ClusterLinks => ProtobufRequestType::ClusterLinks,
+            RequestType::ClusterMeet => ProtobufRequestType::ClusterMeet,
+            RequestType::ClusterMyId => ProtobufRequestType::ClusterMyId,
+            RequestType::ClusterMyShardId => ProtobufRequestType::ClusterMyShardId,
+            RequestType::ClusterNodes => ProtobufRequestType::ClusterNodes,
+            RequestType::ClusterReplicas => ProtobufRequestType::ClusterReplicas,
+            RequestType::ClusterReplicate => ProtobufRequestType::ClusterReplicate,
+            RequestType::ClusterReset => ProtobufRequestType::ClusterReset,
+            RequestType::ClusterSaveConfig => ProtobufRequestType::ClusterSaveConfig,
+            RequestType::ClusterSetConfigEpoch => ProtobufRequestType::ClusterSetConfigEpoch,
+            RequestType::ClusterSetslot => ProtobufRequestType::ClusterSetslot,
+            RequestType::ClusterShards => ProtobufRequestType::ClusterShards,
+            RequestType::ClusterSlaves => ProtobufRequestType::ClusterSlaves,
+            RequestType::ClusterSlots => ProtobufRequestType::ClusterSlots,
+            RequestType::ReadOnly => ProtobufRequestType::ReadOnly,
+            RequestType::ReadWrite => ProtobufRequestType::ReadWrite,
+            RequestType::Auth => ProtobufRequestType::Auth,
+            RequestType::ClientCaching => ProtobufRequestType::ClientCaching,
+            RequestType::ClientGetName => ProtobufRequestType::ClientGetName,
+            RequestType::ClientGetRedir => ProtobufRequestType::ClientGetRedir,
+            RequestType::ClientId => ProtobufRequestType::ClientId,
+            RequestType::ClientInfo => ProtobufRequestType::ClientInfo,
+            RequestType::ClientKillSimple => ProtobufRequestType::ClientKillSimple,
+            RequestType::ClientKill => ProtobufRequestType::ClientKill,
+            RequestType::ClientList => ProtobufRequestType::ClientList,
+            RequestType::ClientNoEvict => ProtobufRequestType::ClientNoEvict,
+            RequestType::ClientNoTouch => ProtobufRequestType::ClientNoTouch,
+            RequestType::ClientPause => ProtobufRequestType::ClientPause,
+            RequestType::ClientReply => ProtobufRequestType::ClientReply,
+            RequestType::ClientSetInfo => ProtobufRequestType::ClientSetInfo,
+            RequestType::ClientSetName => ProtobufRequestType::ClientSetName,
+            RequestType::ClientTracking => ProtobufRequestType::ClientTracking,
+            RequestType::ClientTrackingInfo => ProtobufRequestType::ClientTrackingInfo,
+            RequestType::ClientUnblock => ProtobufRequestType::ClientUnblock,
+            RequestType::ClientUnpause => ProtobufRequestType::ClientUnpause,
+            RequestType::Echo => ProtobufRequestType::Echo,
+            RequestType::Hello => ProtobufRequestType::Hello,
+            RequestType::Ping => ProtobufRequestType::Ping,
+            RequestType::Quit => ProtobufRequestType::Quit,
+            RequestType::Reset => ProtobufRequestType::Reset,
+            RequestType::Select => ProtobufRequestType::Select,
+            RequestType::Copy => ProtobufRequestType::Copy,
+            RequestType::Del => ProtobufRequestType::Del,
+            RequestType::Dump => ProtobufRequestType::Dump,
+            RequestType::Exists => ProtobufRequestType::Exists,
+            RequestType::Expire => ProtobufRequestType::Expire,
+            RequestType::ExpireAt => ProtobufRequestType::ExpireAt,
+            RequestType::ExpireTime => ProtobufRequestType::ExpireTime,
+            RequestType::Keys => ProtobufRequestType::Keys,
+            RequestType::Migrate => ProtobufRequestType::Migrate,
+            RequestType::Move => ProtobufRequestType::Move,
+            RequestType::ObjectEncoding => ProtobufRequestType::ObjectEncoding,
+            RequestType::ObjectFreq => ProtobufRequestType::ObjectFreq,
+            RequestType::ObjectIdleTime => ProtobufRequestType::ObjectIdleTime,
+            RequestType::ObjectRefCount => ProtobufRequestType::ObjectRefCount,
+            RequestType::Persist => ProtobufRequestType::Persist,
+            RequestType::PExpire => ProtobufRequestType::PExpire,
+            RequestType::PExpireAt => ProtobufRequestType::PExpireAt,
+            RequestType::PExpireTime => ProtobufRequestType::PExpireTime,
+            RequestType::PTTL => ProtobufRequestType::PTTL,
+            RequestType::RandomKey => ProtobufRequestType::RandomKey,
+            RequestType::Rename => ProtobufRequestType::Rename,
+            RequestType::RenameNX => ProtobufRequestType::RenameNX,
+            RequestType::Restore => ProtobufRequestType::Restore,
+            RequestType::Scan => ProtobufRequestType::Scan,
+            RequestType::Sort => ProtobufRequestType::Sort,
+            RequestType::SortReadOnly => ProtobufRequestType::SortReadOnly,
+            RequestType::Touch => ProtobufRequestType::Touch,
+            RequestType::TTL => ProtobufRequestType::TTL,
+            RequestType::Type => ProtobufRequestType::Type,
+            RequestType::Unlink => ProtobufRequestType::Unlink,
+            RequestType::Wait => ProtobufRequestType::Wait,
+            RequestType::WaitAof => ProtobufRequestType::WaitAof,
+            RequestType::GeoAdd => ProtobufRequestType::GeoAdd,
+            RequestType::GeoDist => ProtobufRequestType::GeoDist,
+            RequestType::GeoHash => ProtobufRequestType::GeoHash,
+            RequestType::GeoPos => ProtobufRequestType::GeoPos,
+            RequestType::GeoRadius => ProtobufRequestType::GeoRadius,
+            RequestType::GeoRadiusReadOnly => ProtobufRequestType::GeoRadiusReadOnly,
+            RequestType::GeoRadiusByMember => ProtobufRequestType::GeoRadiusByMember,
+            RequestType::GeoRadiusByMemberReadOnly => {
+                ProtobufRequestType::GeoRadiusByMemberReadOnly
+            }
+            RequestType::GeoSearch => ProtobufRequestType::GeoSearch,
+            RequestType::GeoSearchStore => ProtobufRequestType::GeoSearchStore,
+            RequestType::HDel => ProtobufRequestType::HDel,
+            RequestType::HExists => ProtobufRequestType::HExists,
+            RequestType::HGet => ProtobufRequestType::HGet,
+            RequestType::HGetAll => ProtobufRequestType::HGetAll,
+            RequestType::HIncrBy => ProtobufRequestType::HIncrBy,
+            RequestType::HIncrByFloat => ProtobufRequestType::HIncrByFloat,
+            RequestType::HKeys => ProtobufRequestType::HKeys,
+            RequestType::HLen => ProtobufRequestType::HLen,
+            RequestType::HMGet => ProtobufRequestType::HMGet,
+            RequestType::HMSet => ProtobufRequestType::HMSet,
+            RequestType::HRandField => ProtobufRequestType::HRandField,
+            RequestType::HScan => ProtobufRequestType::HScan,
+            RequestType::HSet => ProtobufRequestType::HSet,
+            RequestType::HSetNX => ProtobufRequestType::HSetNX,
+            RequestType::HStrlen => ProtobufRequestType::HStrlen,
+            RequestType::HVals => ProtobufRequestType::HVals,
+            RequestType::HExpire => ProtobufRequestType::HExpire,
+            RequestType::HPExpire => ProtobufRequestType::HPExpire,
+            RequestType::HExpireAt => ProtobufRequestType::HExpireAt,
+            RequestType::HPExpireAt => ProtobufRequestType::HPExpireAt,
+            RequestType::HTTL => ProtobufRequestType::HTTL,
+            RequestType::HPTTL => ProtobufRequestType::HPTTL,
+            RequestType::HExpireTime => ProtobufRequestType::HExpireTime,
+            RequestType::HPExpireTime => ProtobufRequestType::HPExpireTime,
+            RequestType::HPersist => ProtobufRequestType::HPersist,
+            RequestType::HGetEx => ProtobufRequestType::HGetEx,
+            RequestType::HSetEx => ProtobufRequestType::HSetEx,
+            RequestType::PfAdd => ProtobufRequestType::PfAdd,
+            RequestType::PfCount => ProtobufRequestType::PfCount,
+            RequestType::PfMerge => ProtobufRequestType::PfMerge,
+            RequestType::BLMove => ProtobufRequestType::BLMove,
+            RequestType::BLMPop => ProtobufRequestType::BLMPop,
+            RequestType::BLPop => ProtobufRequestType::BLPop,
+            RequestType::BRPop => ProtobufRequestType::BRPop,
+            RequestType::BRPopLPush => ProtobufRequestType::BRPopLPush,
+            RequestType::LIndex => ProtobufRequestType::LIndex,
+            RequestType::LInsert => ProtobufRequestType::LInsert,
+            RequestType::LLen => ProtobufRequestType::LLen,
+            RequestType::LMove => ProtobufRequestType::LMove,
+            RequestType::LMPop => ProtobufRequestType::LMPop,
+            RequestType::LPop => ProtobufRequestType::LPop,
+            RequestType::LPos => ProtobufRequestType::LPos,
+            RequestType::LPush => ProtobufRequestType::LPush,
+            RequestType::LPushX => ProtobufRequestType::LPushX,
+            RequestType::LRange => ProtobufRequestType::LRange,
+            RequestType::LRem => ProtobufRequestType::LRem,
+            RequestType::LSet => ProtobufRequestType::LSet,
+            RequestType::LTrim => ProtobufRequestType::LTrim,
+            RequestType::RPop => ProtobufRequestType::RPop,
+            RequestType::RPopLPush => ProtobufRequestType::RPopLPush,
+            RequestType::RPush => ProtobufRequestType::RPush,
+            RequestType::RPushX => ProtobufRequestType::RPushX,
+            RequestType::PSubscribe => ProtobufRequestType::PSubscribe,
+            RequestType::Publish => ProtobufRequestType::Publish,
+            RequestType::PubSubChannels => ProtobufRequestType::PubSubChannels,
+            RequestType::PubSubNumPat => ProtobufRequestType::PubSubNumPat,
+            RequestType::PubSubNumSub => ProtobufRequestType::PubSubNumSub,
+            RequestType::PubSubShardChannels => ProtobufRequestType::PubSubShardChannels,
+            RequestType::PubSubShardNumSub => ProtobufRequestType::PubSubShardNumSub,
+            RequestType::PUnsubscribe => ProtobufRequestType::PUnsubscribe,
+            RequestType::SPublish => ProtobufRequestType::SPublish,
+            RequestType::SSubscribe => ProtobufRequestType::SSubscribe,
+            RequestType::Subscribe => ProtobufRequestType::Subscribe,
+            RequestType::SUnsubscribe => ProtobufRequestType::SUnsubscribe,
+            RequestType::Unsubscribe => ProtobufRequestType::Unsubscribe,
+            RequestType::Eval => ProtobufRequestType::Eval,
+            RequestType::EvalReadOnly => ProtobufRequestType::EvalReadOnly,
+            RequestType::EvalSha => ProtobufRequestType::EvalSha,
+            RequestType::EvalShaReadOnly => ProtobufRequestType::EvalShaReadOnly,
+            RequestType::FCall => ProtobufRequestType::FCall,
+            RequestType::FCallReadOnly => ProtobufRequestType::FCallReadOnly,
+            RequestType::FunctionDelete => ProtobufRequestType::FunctionDelete,
+            RequestType::FunctionDump => ProtobufRequestType::FunctionDump,
+            RequestType::FunctionFlush => ProtobufRequestType::FunctionFlush,
+            RequestType::FunctionKill => ProtobufRequestType::FunctionKill,
+            RequestType::FunctionList => ProtobufRequestType::FunctionList,
+            RequestType::FunctionLoad => ProtobufRequestType::FunctionLoad,
+            RequestType::FunctionRestore => ProtobufRequestType::FunctionRestore,
+            RequestType::FunctionStats => ProtobufRequestType::FunctionStats,
+            RequestType::ScriptDebug => ProtobufRequestType::ScriptDebug,
+            RequestType::ScriptExists => ProtobufRequestType::ScriptExists,
+            RequestType::ScriptFlush => ProtobufRequestType::ScriptFlush,
+            RequestType::ScriptKill => ProtobufRequestType::ScriptKill,
+            RequestType::ScriptLoad => ProtobufRequestType::ScriptLoad,
+            RequestType::ScriptShow => ProtobufRequestType::ScriptShow,
+            RequestType::AclCat => ProtobufRequestType::AclCat,
+            RequestType::AclDelUser => ProtobufRequestType::AclDelUser,
+            RequestType::AclDryRun => ProtobufRequestType::AclDryRun,
+            RequestType::AclGenPass => ProtobufRequestType::AclGenPass,
+            RequestType::AclGetUser => ProtobufRequestType::AclGetUser,
+            RequestType::AclList => ProtobufRequestType::AclList,
+            RequestType::AclLoad => ProtobufRequestType::AclLoad,
+            RequestType::AclLog => ProtobufRequestType::AclLog,
+            RequestType::AclSave => ProtobufRequestType::AclSave,
+            RequestType::AclSetSser => ProtobufRequestType::AclSetSser,
+            RequestType::AclUsers => ProtobufRequestType::AclUsers,
+            RequestType::AclWhoami => ProtobufRequestType::AclWhoami,
+            RequestType::BgRewriteAof => ProtobufRequestType::BgRewriteAof,
+            RequestType::BgSave => ProtobufRequestType::BgSave,
+            RequestType::Command_ => ProtobufRequestType::Command_,
+            RequestType::CommandCount => ProtobufRequestType::CommandCount,
+            RequestType::CommandDocs => ProtobufRequestType::CommandDocs,
+            RequestType::CommandGetKeys => ProtobufRequestType::CommandGetKeys,
+            RequestType::CommandGetKeysAndFlags => ProtobufRequestType::CommandGetKeysAndFlags,
+            RequestType::CommandInfo => ProtobufRequestType::CommandInfo,
+            RequestType::CommandList => ProtobufRequestType::CommandList,
+            RequestType::ConfigGet => ProtobufRequestType::ConfigGet,
+            RequestType::ConfigResetStat => ProtobufRequestType::ConfigResetStat,
+            RequestType::ConfigRewrite => ProtobufRequestType::ConfigRewrite,
+            RequestType::ConfigSet => ProtobufRequestType::ConfigSet,
+            RequestType::DBSize => ProtobufRequestType::DBSize,
+            RequestType::FailOver => ProtobufRequestType::FailOver,
+            RequestType::FlushAll => ProtobufRequestType::FlushAll,
+            RequestType::FlushDB => ProtobufRequestType::FlushDB,
+            RequestType::Info => ProtobufRequestType::Info,
+            RequestType::LastSave => ProtobufRequestType::LastSave,
+            RequestType::LatencyDoctor => ProtobufRequestType::LatencyDoctor,
+            RequestType::LatencyGraph => ProtobufRequestType::LatencyGraph,
+            RequestType::LatencyHistogram => ProtobufRequestType::LatencyHistogram,
+            RequestType::LatencyHistory => ProtobufRequestType::LatencyHistory,
+            RequestType::LatencyLatest => ProtobufRequestType::LatencyLatest,
+            RequestType::LatencyReset => ProtobufRequestType::LatencyReset,
+            RequestType::Lolwut => ProtobufRequestType::Lolwut,
+            RequestType::MemoryDoctor => ProtobufRequestType::MemoryDoctor,
+            RequestType::MemoryMallocStats => ProtobufRequestType::MemoryMallocStats,
+            RequestType::MemoryPurge => ProtobufRequestType::MemoryPurge,
+            RequestType::MemoryStats => ProtobufRequestType::MemoryStats,
+            RequestType::MemoryUsage => ProtobufRequestType::MemoryUsage,
+            RequestType::ModuleList => ProtobufRequestType::ModuleList,
+            RequestType::ModuleLoad => ProtobufRequestType::ModuleLoad,
+            RequestType::ModuleLoadEx => ProtobufRequestType::ModuleLoadEx,
+            RequestType::ModuleUnload => ProtobufRequestType::ModuleUnload,
+            RequestType::Monitor => ProtobufRequestType::Monitor,
+            RequestType::PSync => ProtobufRequestType::PSync,
+            RequestType::ReplConf => ProtobufRequestType::ReplConf,
+            RequestType::ReplicaOf => ProtobufRequestType::ReplicaOf,
+            RequestType::RestoreAsking => ProtobufRequestType::RestoreAsking,
+            RequestType::Role => ProtobufRequestType::Role,
+            RequestType::Save => ProtobufRequestType::Save,
+            RequestType::ShutDown => ProtobufRequestType::ShutDown,
+            RequestType::SlaveOf => ProtobufRequestType::SlaveOf,
+            RequestType::SlowLogGet => ProtobufRequestType::SlowLogGet,
+            RequestType::SlowLogLen => ProtobufRequestType::SlowLogLen,
+            RequestType::SlowLogReset => ProtobufRequestType::SlowLogReset,
+            RequestType::SwapDb => ProtobufRequestType::SwapDb,
+            RequestType::Sync => ProtobufRequestType::Sync,
+            RequestType::Time => ProtobufRequestType::Time,
+            RequestType::SAdd => ProtobufRequestType::SAdd,
+            RequestType::SCard => ProtobufRequestType::SCard,
+            RequestType::SDiff => ProtobufRequestType::SDiff,
+            RequestType::SDiffStore => ProtobufRequestType::SDiffStore,
+            RequestType::SInter => ProtobufRequestType::SInter,
+            RequestType::SInterCard => ProtobufRequestType::SInterCard,
+            RequestType::SInterStore => ProtobufRequestType::SInterStore,
+            RequestType::SIsMember => ProtobufRequestType::SIsMember,
+            RequestType::SMembers => ProtobufRequestType::SMembers,
+            RequestType::SMIsMember => ProtobufRequestType::SMIsMember,
+            RequestType::SMove => ProtobufRequestType::SMove,
+            RequestType::SPop => ProtobufRequestType::SPop,
+            RequestType::SRandMember => ProtobufRequestType::SRandMember,
+            RequestType::SRem => ProtobufRequestType::SRem,
+            RequestType::SScan => ProtobufRequestType::SScan,
+            RequestType::SUnion => ProtobufRequestType::SUnion,
+            RequestType::SUnionStore => ProtobufRequestType::SUnionStore,
+            RequestType::BZMPop => ProtobufRequestType::BZMPop,
+            RequestType::BZPopMax => ProtobufRequestType::BZPopMax,
+            RequestType::BZPopMin => ProtobufRequestType::BZPopMin,
+            RequestType::ZAdd => ProtobufRequestType::ZAdd,
+            RequestType::ZCard => ProtobufRequestType::ZCard,
+            RequestType::ZCount => ProtobufRequestType::ZCount,
+            RequestType::ZDiff => ProtobufRequestType::ZDiff,
+            RequestType::ZDiffStore => ProtobufRequestType::ZDiffStore,
+            RequestType::ZIncrBy => ProtobufRequestType::ZIncrBy,
+            RequestType::ZInter => ProtobufRequestType::ZInter,
+            RequestType::ZInterCard => ProtobufRequestType::ZInterCard,
+            RequestType::ZInterStore => ProtobufRequestType::ZInterStore,
+            RequestType::ZLexCount => ProtobufRequestType::ZLexCount,
+            RequestType::ZMPop => ProtobufRequestType::ZMPop,
+            RequestType::ZMScore => ProtobufRequestType::ZMScore,
+            RequestType::ZPopMax => ProtobufRequestType::ZPopMax,
+            RequestType::ZPopMin => ProtobufRequestType::ZPopMin,
+            RequestType::ZRandMember => ProtobufRequestType::ZRandMember,
+            RequestType::ZRange => ProtobufRequestType::ZRange,
+            RequestType::ZRangeByLex => ProtobufRequestType::ZRangeByLex,
+            RequestType::ZRangeByScore => ProtobufRequestType::ZRangeByScore,
+            RequestType::ZRangeStore => ProtobufRequestType::ZRangeStore,
+            RequestType::ZRank => ProtobufRequestType::ZRank,
+            RequestType::ZRem => ProtobufRequestType::ZRem,
+            RequestType::ZRemRangeByLex => ProtobufRequestType::ZRemRangeByLex,
+            RequestType::ZRemRangeByRank => ProtobufRequestType::ZRemRangeByRank,
+            RequestType::ZRemRangeByScore => ProtobufRequestType::ZRemRangeByScore,
+            RequestType::ZRevRange => ProtobufRequestType::ZRevRange,
+            RequestType::ZRevRangeByLex => ProtobufRequestType::ZRevRangeByLex,
+            RequestType::ZRevRangeByScore => ProtobufRequestType::ZRevRangeByScore,
+            RequestType::ZRevRank => ProtobufRequestType::ZRevRank,
+            RequestType::ZScan => ProtobufRequestType::ZScan,
+            RequestType::ZScore => ProtobufRequestType::ZScore,
+            RequestType::ZUnion => ProtobufRequestType::ZUnion,
+            RequestType::ZUnionStore => ProtobufRequestType::ZUnionStore,
+            RequestType::XAck => ProtobufRequestType::XAck,
+            RequestType::XAdd => ProtobufRequestType::XAdd,
+            RequestType::XAutoClaim => ProtobufRequestType::XAutoClaim,
+            RequestType::XClaim => ProtobufRequestType::XClaim,
+            RequestType::XDel => ProtobufRequestType::XDel,
+            RequestType::XGroupCreate => ProtobufRequestType::XGroupCreate,
+            RequestType::XGroupCreateConsumer => ProtobufRequestType::XGroupCreateConsumer,
+            RequestType::XGroupDelConsumer => ProtobufRequestType::XGroupDelConsumer,
+            RequestType::XGroupDestroy => ProtobufRequestType::XGroupDestroy,
+            RequestType::XGroupSetId => ProtobufRequestType::XGroupSetId,
+            RequestType::XInfoConsumers => ProtobufRequestType::XInfoConsumers,
+            RequestType::XInfoGroups => ProtobufRequestType::XInfoGroups,
+            RequestType::XInfoStream => ProtobufRequestType::XInfoStream,
+            RequestType::XLen => ProtobufRequestType::XLen,
+            RequestType::XPending => ProtobufRequestType::XPending,
+            RequestType::XRange => ProtobufRequestType::XRange,
+            RequestType::XRead => ProtobufRequestType::XRead,
+            RequestType::XReadGroup => ProtobufRequestType::XReadGroup,
+            RequestType::XRevRange => ProtobufRequestType::XRevRange,
+            RequestType::XSetId => ProtobufRequestType::XSetId,
+            RequestType::XTrim => ProtobufRequestType::XTrim,
+            RequestType::Append => ProtobufRequestType::Append,
+            RequestType::Decr => ProtobufRequestType::Decr,
+            RequestType::DecrBy => ProtobufRequestType::DecrBy,
+            RequestType::Get => ProtobufRequestType::Get,
+            RequestType::GetDel => ProtobufRequestType::GetDel,
+            RequestType::GetEx => ProtobufRequestType::GetEx,
+            RequestType::GetRange => ProtobufRequestType::GetRange,
+            RequestType::GetSet => ProtobufRequestType::GetSet,
+            RequestType::Incr => ProtobufRequestType::Incr,
+            RequestType::IncrBy => ProtobufRequestType::IncrBy,
+            RequestType::IncrByFloat => ProtobufRequestType::IncrByFloat,
+            RequestType::LCS => ProtobufRequestType::LCS,
+            RequestType::MGet => ProtobufRequestType::MGet,
+            RequestType::MSet => ProtobufRequestType::MSet,
+            RequestType::MSetNX => ProtobufRequestType::MSetNX,
+            RequestType::PSetEx => ProtobufRequestType::PSetEx,
+            RequestType::Set => ProtobufRequestType::Set,
+            RequestType::SetEx => ProtobufRequestType::SetEx,
+            RequestType::SetNX => ProtobufRequestType::SetNX,
+            RequestType::SetRange => ProtobufRequestType::SetRange,
+            RequestType::Strlen => ProtobufRequestType::Strlen,
+            RequestType::Substr => ProtobufRequestType::Substr,
+            RequestType::DelIfEq => ProtobufRequestType::DelIfEq,
+            RequestType::Discard => ProtobufRequestType::Discard,
+            RequestType::Exec => ProtobufRequestType::Exec,
+            RequestType::Multi => ProtobufRequestType::Multi,
+            RequestType::UnWatch => ProtobufRequestType::UnWatch,
+            RequestType::Watch => ProtobufRequestType::Watch,
+            RequestType::JsonArrAppend => ProtobufRequestType::JsonArrAppend,
+            RequestType::JsonArrIndex => ProtobufRequestType::JsonArrIndex,
+            RequestType::JsonArrInsert => ProtobufRequestType::JsonArrInsert,
+            RequestType::JsonArrLen => ProtobufRequestType::JsonArrLen,
+            RequestType::JsonArrPop => ProtobufRequestType::JsonArrPop,
+            RequestType::JsonArrTrim => ProtobufRequestType::JsonArrTrim,
+            RequestType::JsonClear => ProtobufRequestType::JsonClear,
+            RequestType::JsonDebug => ProtobufRequestType::JsonDebug,
+            RequestType::JsonDel => ProtobufRequestType::JsonDel,
+            RequestType::JsonForget => ProtobufRequestType::JsonForget,
+            RequestType::JsonGet => ProtobufRequestType::JsonGet,
+            RequestType::JsonMGet => ProtobufRequestType::JsonMGet,
+            RequestType::JsonNumIncrBy => ProtobufRequestType::JsonNumIncrBy,
+            RequestType::JsonNumMultBy => ProtobufRequestType::JsonNumMultBy,
+            RequestType::JsonObjKeys => ProtobufRequestType::JsonObjKeys,
+            RequestType::JsonObjLen => ProtobufRequestType::JsonObjLen,
+            RequestType::JsonResp => ProtobufRequestType::JsonResp,
+            RequestType::JsonSet => ProtobufRequestType::JsonSet,
+            RequestType::JsonStrAppend => ProtobufRequestType::JsonStrAppend,
+            RequestType::JsonStrLen => ProtobufRequestType::JsonStrLen,
+            RequestType::JsonToggle => ProtobufRequestType::JsonToggle,
+            RequestType::JsonType => ProtobufRequestType::JsonType,
+            RequestType::FtList => ProtobufRequestType::FtList,
+            RequestType::FtAggregate => ProtobufRequestType::FtAggregate,
+            RequestType::FtAliasAdd => ProtobufRequestType::FtAliasAdd,
+            RequestType::FtAliasDel => ProtobufRequestType::FtAliasDel,
+            RequestType::FtAliasList => ProtobufRequestType::FtAliasList,
+            RequestType::FtAliasUpdate => ProtobufRequestType::FtAliasUpdate,
+            RequestType::FtCreate => ProtobufRequestType::FtCreate,
+            RequestType::FtDropIndex => ProtobufRequestType::FtDropIndex,
+            RequestType::FtExplain => ProtobufRequestType::FtExplain,
+            RequestType::FtExplainCli => ProtobufRequestType::FtExplainCli,
+            RequestType::FtInfo => ProtobufRequestType::FtInfo,
+            RequestType::FtProfile => ProtobufRequestType::FtProfile,
+            RequestType::FtSearch => ProtobufRequestType::FtSearch,
+            RequestType::FtCursorRead => ProtobufRequestType::FtCursorRead,
+            RequestType::FtCursorDel => ProtobufRequestType::FtCursorDel,
+            RequestType::BfAdd => ProtobufRequestType::BfAdd,
+            RequestType::BfCard => ProtobufRequestType::BfCard,
+            RequestType::BfExists => ProtobufRequestType::BfExists,
+            RequestType::BfInfo => ProtobufRequestType::BfInfo,
+            RequestType::BfInsert => ProtobufRequestType::BfInsert,
+            RequestType::BfLoad => ProtobufRequestType::BfLoad,
+            RequestType::BfMAdd => ProtobufRequestType::BfMAdd,
+            RequestType::BfMExists => ProtobufRequestType::BfMExists,
+            RequestType::BfReserve => ProtobufRequestType::BfReserve,
         }
     }
 }
@@ -782,6 +1373,17 @@ impl RequestType {
             RequestType::HSetNX => Some(cmd("HSETNX")),
             RequestType::SIsMember => Some(cmd("SISMEMBER")),
             RequestType::HVals => Some(cmd("HVALS")),
+            RequestType::HExpire => Some(cmd("HEXPIRE")),
+            RequestType::HPExpire => Some(cmd("HPEXPIRE")),
+            RequestType::HExpireAt => Some(cmd("HEXPIREAT")),
+            RequestType::HPExpireAt => Some(cmd("HPEXPIREAT")),
+            RequestType::HTTL => Some(cmd("HTTL")),
+            RequestType::HPTTL => Some(cmd("HPTTL")),
+            RequestType::HExpireTime => Some(cmd("HEXPIRETIME")),
+            RequestType::HPExpireTime => Some(cmd("HPEXPIRETIME")),
+            RequestType::HPersist => Some(cmd("HPERSIST")),
+            RequestType::HGetEx => Some(cmd("HGETEX")),
+            RequestType::HSetEx => Some(cmd("HSETEX")),
             RequestType::PTTL => Some(cmd("PTTL")),
             RequestType::ZRemRangeByRank => Some(cmd("ZREMRANGEBYRANK")),
             RequestType::Persist => Some(cmd("PERSIST")),
@@ -954,7 +1556,177 @@ impl RequestType {
             RequestType::FtInfo => Some(cmd("FT.INFO")),
             RequestType::FtProfile => Some(cmd("FT.PROFILE")),
             RequestType::FtSearch => Some(cmd("FT.SEARCH")),
-            _ => todo!(),
+            RequestType::FtCursorRead => Some(get_two_word_command("FT.CURSOR", "READ")),
+            RequestType::FtCursorDel => Some(get_two_word_command("FT.CURSOR", "DEL")),
+            RequestType::BfAdd => Some(cmd("BF.ADD")),
+            RequestType::BfCard => Some(cmd("BF.CARD")),
+            RequestType::BfExists => Some(cmd("BF.EXISTS")),
+            RequestType::BfInfo => Some(cmd("BF.INFO")),
+            RequestType::BfInsert => Some(cmd("BF.INSERT")),
+            RequestType::BfLoad => Some(cmd("BF.LOAD")),
+            RequestType::BfMAdd => Some(cmd("BF.MADD")),
+            RequestType::BfMExists => Some(cmd("BF.MEXISTS")),
+            RequestType::BfReserve => Some(cmd("BF.RESERVE")),
+            RequestType::Asking => Some(cmd("ASKING")),
+            RequestType::ClusterAddSlots => Some(get_two_word_command("CLUSTER", "ADDSLOTS")),
+            RequestType::ClusterAddSlotsRange => {
+                Some(get_two_word_command("CLUSTER", "ADDSLOTSRANGE"))
+            }
+            RequestType::ClusterBumpEpoch => Some(get_two_word_command("CLUSTER", "BUMPEPOCH")),
+            RequestType::ClusterCountFailureReports => {
+                Some(get_two_word_command("CLUSTER", "COUNT-FAILURE-REPORTS"))
+            }
+            RequestType::ClusterCountKeysInSlot => {
+                Some(get_two_word_command("CLUSTER", "COUNTKEYSINSLOT"))
+            }
+            RequestType::ClusterDelSlots => Some(get_two_word_command("CLUSTER", "DELSLOTS")),
+            RequestType::ClusterDelSlotsRange => {
+                Some(get_two_word_command("CLUSTER", "DELSLOTSRANGE"))
+            }
+            RequestType::ClusterFailover => Some(get_two_word_command("CLUSTER", "FAILOVER")),
+            RequestType::ClusterFlushSlots => Some(get_two_word_command("CLUSTER", "FLUSHSLOTS")),
+            RequestType::ClusterForget => Some(get_two_word_command("CLUSTER", "FORGET")),
+            RequestType::ClusterGetKeysInSlot => {
+                Some(get_two_word_command("CLUSTER", "GETKEYSINSLOT"))
+            }
+            RequestType::ClusterInfo => Some(get_two_word_command("CLUSTER", "INFO")),
+            RequestType::ClusterKeySlot => Some(get_two_word_command("CLUSTER", "KEYSLOT")),
+            RequestType::ClusterLinks => Some(get_two_word_command("CLUSTER", "LINKS")),
+            RequestType::ClusterMeet => Some(get_two_word_command("CLUSTER", "MEET")),
+            RequestType::ClusterMyId => Some(get_two_word_command("CLUSTER", "MYID")),
+            RequestType::ClusterMyShardId => Some(get_two_word_command("CLUSTER", "MYSHARDID")),
+            RequestType::ClusterNodes => Some(get_two_word_command("CLUSTER", "NODES")),
+            RequestType::ClusterReplicas => Some(get_two_word_command("CLUSTER", "REPLICAS")),
+            RequestType::ClusterReplicate => Some(get_two_word_command("CLUSTER", "REPLICATE")),
+            RequestType::ClusterReset => Some(get_two_word_command("CLUSTER", "RESET")),
+            RequestType::ClusterSaveConfig => Some(get_two_word_command("CLUSTER", "SAVECONFIG")),
+            RequestType::ClusterSetConfigEpoch => {
+                Some(get_two_word_command("CLUSTER", "SET-CONFIG-EPOCH"))
+            }
+            RequestType::ClusterSetslot => Some(get_two_word_command("CLUSTER", "SETSLOT")),
+            RequestType::ClusterShards => Some(get_two_word_command("CLUSTER", "SHARDS")),
+            RequestType::ClusterSlaves => Some(get_two_word_command("CLUSTER", "SLAVES")),
+            RequestType::ClusterSlots => Some(get_two_word_command("CLUSTER", "SLOTS")),
+            RequestType::ReadOnly => Some(cmd("READONLY")),
+            RequestType::ReadWrite => Some(cmd("READWRITE")),
+            RequestType::Auth => Some(cmd("AUTH")),
+            RequestType::ClientCaching => Some(get_two_word_command("CLIENT", "CACHING")),
+            RequestType::ClientKillSimple => Some(get_two_word_command("CLIENT", "KILL")),
+            RequestType::ClientTracking => Some(get_two_word_command("CLIENT", "TRACKING")),
+            RequestType::ClientTrackingInfo => Some(get_two_word_command("CLIENT", "TRACKINGINFO")),
+            RequestType::Hello => Some(cmd("HELLO")),
+            RequestType::Quit => Some(cmd("QUIT")),
+            RequestType::Reset => Some(cmd("RESET")),
+            RequestType::Keys => Some(cmd("KEYS")),
+            RequestType::Migrate => Some(cmd("MIGRATE")),
+            RequestType::WaitAof => Some(cmd("WAITAOF")),
+            RequestType::GeoRadius => Some(cmd("GEORADIUS")),
+            RequestType::GeoRadiusReadOnly => Some(cmd("GEORADIUS_RO")),
+            RequestType::GeoRadiusByMember => Some(cmd("GEORADIUSBYMEMBER")),
+            RequestType::GeoRadiusByMemberReadOnly => Some(cmd("GEORADIUSBYMEMBER_RO")),
+            RequestType::BRPopLPush => Some(cmd("BRPOPLPUSH")),
+            RequestType::RPopLPush => Some(cmd("RPOPLPUSH")),
+            RequestType::PSubscribe => Some(cmd("PSUBSCRIBE")),
+            RequestType::PUnsubscribe => Some(cmd("PUNSUBSCRIBE")),
+            RequestType::SSubscribe => Some(cmd("SSUBSCRIBE")),
+            RequestType::Subscribe => Some(cmd("SUBSCRIBE")),
+            RequestType::SUnsubscribe => Some(cmd("SUNSUBSCRIBE")),
+            RequestType::Unsubscribe => Some(cmd("UNSUBSCRIBE")),
+            RequestType::Eval => Some(cmd("EVAL")),
+            RequestType::EvalReadOnly => Some(cmd("EVAL_RO")),
+            RequestType::EvalSha => Some(cmd("EVALSHA")),
+            RequestType::EvalShaReadOnly => Some(cmd("EVALSHA_RO")),
+            RequestType::ScriptDebug => Some(get_two_word_command("SCRIPT", "DEBUG")),
+            RequestType::ScriptLoad => Some(get_two_word_command("SCRIPT", "LOAD")),
+            RequestType::AclCat => Some(get_two_word_command("ACL", "CAT")),
+            RequestType::AclDelUser => Some(get_two_word_command("ACL", "DELUSER")),
+            RequestType::AclDryRun => Some(get_two_word_command("ACL", "DRYRUN")),
+            RequestType::AclGenPass => Some(get_two_word_command("ACL", "GENPASS")),
+            RequestType::AclGetUser => Some(get_two_word_command("ACL", "GETUSER")),
+            RequestType::AclList => Some(get_two_word_command("ACL", "LIST")),
+            RequestType::AclLoad => Some(get_two_word_command("ACL", "LOAD")),
+            RequestType::AclLog => Some(get_two_word_command("ACL", "LOG")),
+            RequestType::AclSave => Some(get_two_word_command("ACL", "SAVE")),
+            RequestType::AclSetSser => Some(get_two_word_command("ACL", "SETUSER")),
+            RequestType::AclUsers => Some(get_two_word_command("ACL", "USERS")),
+            RequestType::AclWhoami => Some(get_two_word_command("ACL", "WHOAMI")),
+            RequestType::BgRewriteAof => Some(cmd("BGREWRITEAOF")),
+            RequestType::BgSave => Some(cmd("BGSAVE")),
+            RequestType::Command_ => Some(cmd("COMMAND")),
+            RequestType::CommandCount => Some(get_two_word_command("COMMAND", "COUNT")),
+            RequestType::CommandDocs => Some(get_two_word_command("COMMAND", "DOCS")),
+            RequestType::CommandGetKeys => Some(get_two_word_command("COMMAND", "GETKEYS")),
+            RequestType::CommandGetKeysAndFlags => {
+                Some(get_two_word_command("COMMAND", "GETKEYSANDFLAGS"))
+            }
+            RequestType::CommandInfo => Some(get_two_word_command("COMMAND", "INFO")),
+            RequestType::CommandList => Some(get_two_word_command("COMMAND", "LIST")),
+            RequestType::FailOver => Some(cmd("FAILOVER")),
+            RequestType::LatencyDoctor => Some(get_two_word_command("LATENCY", "DOCTOR")),
+            RequestType::LatencyGraph => Some(get_two_word_command("LATENCY", "GRAPH")),
+            RequestType::LatencyHistogram => Some(get_two_word_command("LATENCY", "HISTOGRAM")),
+            RequestType::LatencyHistory => Some(get_two_word_command("LATENCY", "HISTORY")),
+            RequestType::LatencyLatest => Some(get_two_word_command("LATENCY", "LATEST")),
+            RequestType::LatencyReset => Some(get_two_word_command("LATENCY", "RESET")),
+            RequestType::MemoryDoctor => Some(get_two_word_command("MEMORY", "DOCTOR")),
+            RequestType::MemoryMallocStats => Some(get_two_word_command("MEMORY", "MALLOC-STATS")),
+            RequestType::MemoryPurge => Some(get_two_word_command("MEMORY", "PURGE")),
+            RequestType::MemoryStats => Some(get_two_word_command("MEMORY", "STATS")),
+            RequestType::MemoryUsage => Some(get_two_word_command("MEMORY", "USAGE")),
+            RequestType::ModuleList => Some(get_two_word_command("MODULE", "LIST")),
+            RequestType::ModuleLoad => Some(get_two_word_command("MODULE", "LOAD")),
+            RequestType::ModuleLoadEx => Some(get_two_word_command("MODULE", "LOADEX")),
+            RequestType::ModuleUnload => Some(get_two_word_command("MODULE", "UNLOAD")),
+            RequestType::Monitor => Some(cmd("MONITOR")),
+            RequestType::PSync => Some(cmd("PSYNC")),
+            RequestType::ReplConf => Some(cmd("REPLCONF")),
+            RequestType::ReplicaOf => Some(cmd("REPLICAOF")),
+            RequestType::RestoreAsking => Some(cmd("RESTORE-ASKING")),
+            RequestType::Role => Some(cmd("ROLE")),
+            RequestType::Save => Some(cmd("SAVE")),
+            RequestType::ShutDown => Some(cmd("SHUTDOWN")),
+            RequestType::SlaveOf => Some(cmd("SLAVEOF")),
+            RequestType::SlowLogGet => Some(get_two_word_command("SLOWLOG", "GET")),
+            RequestType::SlowLogLen => Some(get_two_word_command("SLOWLOG", "LEN")),
+            RequestType::SlowLogReset => Some(get_two_word_command("SLOWLOG", "RESET")),
+            RequestType::SwapDb => Some(cmd("SWAPDB")),
+            RequestType::Sync => Some(cmd("SYNC")),
+            RequestType::ZRangeByLex => Some(cmd("ZRANGEBYLEX")),
+            RequestType::ZRangeByScore => Some(cmd("ZRANGEBYSCORE")),
+            RequestType::ZRevRange => Some(cmd("ZREVRANGE")),
+            RequestType::ZRevRangeByLex => Some(cmd("ZREVRANGEBYLEX")),
+            RequestType::ZRevRangeByScore => Some(cmd("ZREVRANGEBYSCORE")),
+            RequestType::XSetId => Some(cmd("XSETID")),
+            RequestType::GetSet => Some(cmd("GETSET")),
+            RequestType::PSetEx => Some(cmd("PSETEX")),
+            RequestType::SetEx => Some(cmd("SETEX")),
+            RequestType::SetNX => Some(cmd("SETNX")),
+            RequestType::Substr => Some(cmd("SUBSTR")),
+            RequestType::DelIfEq => Some(cmd("DELIFEQ")),
+            RequestType::Discard => Some(cmd("DISCARD")),
+            RequestType::Exec => Some(cmd("EXEC")),
+            RequestType::Multi => Some(cmd("MULTI")),
         }
     }
 }
+
+#[cfg(all(test, feature = "proto"))]
+mod tests {
+    use super::*;
+    use protobuf::{Enum, EnumOrUnknown};
+
+    #[test]
+    fn test_every_protobuf_request_type_is_converted() {
+        for request_type in ProtobufRequestType::VALUES {
+            let converted = RequestType::try_from(EnumOrUnknown::new(*request_type)).unwrap();
+            assert_eq!(ProtobufRequestType::from(converted), *request_type);
+            assert_eq!(converted as i32, request_type.value());
+        }
+    }
+
+    #[test]
+    fn test_unknown_request_type_is_rejected() {
+        let unknown = EnumOrUnknown::<ProtobufRequestType>::from_i32(99999);
+        assert_eq!(RequestType::try_from(unknown).unwrap_err(), 99999);
+    }
+}
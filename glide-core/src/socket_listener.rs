@@ -1,11 +1,12 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
 use super::rotating_buffer::RotatingBuffer;
-use crate::client::Client;
 use crate::client::get_or_init_runtime;
+use crate::client::{Client, CredentialProvider, Credentials};
 use crate::cluster_scan_container::get_cluster_scan_cursor;
 use crate::command_request::{
-    Batch, ClusterScan, Command, CommandRequest, Routes, SlotTypes, command, command_request,
+    Batch, ClusterScan, Command, CommandRequest, PubSubChannelType, Routes, SlotTypes,
+    UpdateCredentials, UpdatePubSubSubscriptions, command, command_request,
 };
 use crate::connection_request::ConnectionRequest;
 use crate::errors::{RequestErrorType, error_message, error_type};
@@ -13,6 +14,7 @@ use crate::response;
 use crate::response::Response;
 use ClosingReason::*;
 use PipeListeningResult::*;
+use async_trait::async_trait;
 use bytes::Bytes;
 use directories::BaseDirs;
 use logger_core::{log_debug, log_error, log_info, log_trace, log_warn};
@@ -23,7 +25,8 @@ use redis::cluster_routing::{
 };
 use redis::cluster_routing::{ResponsePolicy, Routable};
 use redis::{
-    ClusterScanArgs, Cmd, PipelineRetryStrategy, PushInfo, RedisError, ScanStateRC, Value,
+    ClusterScanArgs, Cmd, ErrorKind, PipelineRetryStrategy, PubSubSubscriptionKind, PushInfo,
+    RedisError, RedisResult, ScanStateRC, Value,
 };
 use std::cell::Cell;
 use std::collections::HashSet;
@@ -34,12 +37,14 @@ use std::ptr::from_mut;
 use std::rc::Rc;
 use std::str;
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use telemetrylib::{GlideSpan, GlideSpanStatus};
 use thiserror::Error;
 use tokio::net::{UnixListener, UnixStream};
 use tokio::sync::Mutex;
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::{Sender, channel};
+use tokio::sync::oneshot;
 use tokio::task;
 use tokio_util::task::LocalPoolHandle;
 use uuid::Uuid;
@@ -73,6 +78,58 @@ struct Writer {
     closing_sender: Sender<ClosingReason>,
 }
 
+/// A credential provider which requests refreshed credentials from the wrapper with a push response,
+/// and waits for the wrapper to send them with an `UpdateCredentials` request.
+struct SocketCredentialProvider {
+    /// The credentials given in the connection request, returned on the first call.
+    initial_credentials: std::sync::Mutex<Option<Credentials>>,
+    pending_request: std::sync::Mutex<Option<oneshot::Sender<RedisResult<Credentials>>>>,
+    credentials_request_sender: mpsc::UnboundedSender<()>,
+}
+
+impl SocketCredentialProvider {
+    /// Completes the pending credentials request with the credentials sent by the wrapper.
+    fn update_credentials(&self, request: UpdateCredentials) -> ClientUsageResult<Value> {
+        let Some(pending_request) = self.pending_request.lock().unwrap().take() else {
+            return Err(ClientUsageError::User(
+                "Received credentials without a pending credentials request".to_string(),
+            ));
+        };
+        let credentials = match request.error {
+            Some(error) => Err(RedisError::from((
+                ErrorKind::AuthenticationFailed,
+                "The wrapper failed to fetch credentials",
+                error.to_string(),
+            ))),
+            None => Ok(Credentials {
+                username: request.username.map(|username| username.to_string()),
+                token: request.token.to_string(),
+                expires_in: (request.expires_in_ms > 0)
+                    .then(|| Duration::from_millis(request.expires_in_ms)),
+            }),
+        };
+        // The request was dropped if the client was closed, so there's no one to notify.
+        let _ = pending_request.send(credentials);
+        Ok(Value::Okay)
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for SocketCredentialProvider {
+    async fn get_credentials(&self) -> RedisResult<Credentials> {
+        if let Some(credentials) = self.initial_credentials.lock().unwrap().take() {
+            return Ok(credentials);
+        }
+        let (sender, receiver) = oneshot::channel();
+        *self.pending_request.lock().unwrap() = Some(sender);
+        let closed = || RedisError::from((ErrorKind::IoError, "Socket listener was closed"));
+        self.credentials_request_sender
+            .send(())
+            .map_err(|_| closed())?;
+        receiver.await.map_err(|_| closed())?
+    }
+}
+
 enum PipeListeningResult<TRequest: Message> {
     Closed(ClosingReason),
     ReceivedValues(Vec<TRequest>),
@@ -279,13 +336,15 @@ async fn write_to_writer(response: Response, writer: &Rc<Writer>) -> Result<(),
     }
 }
 
-fn get_command(request: &Command) -> Option<Cmd> {
-    let request_type: crate::request_type::RequestType = request.request_type.into();
-    request_type.get_command()
-}
-
 fn get_redis_command(command: &Command) -> Result<Cmd, ClientUsageError> {
-    let Some(mut cmd) = get_command(command) else {
+    // An unknown request type is sent by a binding that is newer than the core, and fails only this request.
+    let request_type =
+        crate::request_type::RequestType::try_from(command.request_type).map_err(|id| {
+            ClientUsageError::User(format!(
+                "Received unknown request type {id}, the client binding may be newer than the core"
+            ))
+        })?;
+    let Some(mut cmd) = request_type.get_command() else {
         return Err(ClientUsageError::Internal(format!(
             "Received invalid request type: {:?}",
             command.request_type
@@ -324,10 +383,12 @@ async fn send_command(
     cmd: Cmd,
     mut client: Client,
     routing: Option<RoutingInfo>,
+    timeout: Option<u32>,
+    disable_retries: bool,
 ) -> ClientUsageResult<Value> {
     let child_span = create_child_span(cmd.span().as_ref(), "send_command");
     let res = client
-        .send_command(&cmd, routing)
+        .send_command_with_options(&cmd, routing, timeout, disable_retries)
         .await
         .map_err(|err| err.into());
 
@@ -377,6 +438,7 @@ async fn invoke_script(
     args: Option<Vec<Bytes>>,
     mut client: Client,
     routing: Option<RoutingInfo>,
+    timeout: Option<u32>,
 ) -> ClientUsageResult<Value> {
     // convert Vec<bytes> to vec<[u8]>
     let keys: Vec<&[u8]> = keys
@@ -389,7 +451,7 @@ async fn invoke_script(
         .unwrap_or_default();
 
     client
-        .invoke_script(&hash, &keys, &args, routing)
+        .invoke_script(&hash, &keys, &args, routing, timeout)
         .await
         .map_err(|err| err.into())
 }
@@ -462,6 +524,34 @@ async fn send_batch(
     res
 }
 
+async fn update_pubsub_subscriptions(
+    request: UpdatePubSubSubscriptions,
+    mut client: Client,
+) -> ClientUsageResult<Value> {
+    let kind = request
+        .channel_type
+        .enum_value()
+        .map(|channel_type| match channel_type {
+            PubSubChannelType::Exact => PubSubSubscriptionKind::Exact,
+            PubSubChannelType::Pattern => PubSubSubscriptionKind::Pattern,
+            PubSubChannelType::Sharded => PubSubSubscriptionKind::Sharded,
+        })
+        .map_err(|id| {
+            ClientUsageError::Internal(format!("Received unexpected channel type {id}"))
+        })?;
+    let channels_or_patterns = request
+        .channels_or_patterns
+        .into_iter()
+        .map(|channel_or_pattern| channel_or_pattern.to_vec())
+        .collect();
+    let result = if request.unsubscribe {
+        client.unsubscribe(kind, channels_or_patterns).await
+    } else {
+        client.subscribe(kind, channels_or_patterns).await
+    };
+    result.map_err(|err| err.into())
+}
+
 fn get_slot_addr(slot_type: &protobuf::EnumOrUnknown<SlotTypes>) -> ClientUsageResult<SlotAddr> {
     slot_type
         .enum_value()
@@ -533,12 +623,17 @@ fn get_route(
     }
 }
 
-fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer>) {
+fn handle_request(
+    request: CommandRequest,
+    mut client: Client,
+    writer: Rc<Writer>,
+    credential_provider: Arc<SocketCredentialProvider>,
+) {
     task::spawn_local(async move {
         let mut updated_inflight_counter = true;
         let client_clone = client.clone();
 
-        let result = match client.reserve_inflight_request() {
+        let result = match client.acquire_inflight_request().await {
             false => {
                 updated_inflight_counter = false;
                 Err(ClientUsageError::User(
@@ -556,16 +651,27 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                             Ok(mut cmd) => match get_route(request.route.0, Some(&cmd)) {
                                 Ok(routes) => {
                                     cmd.set_span(get_unsafe_span_from_ptr(request.root_span_ptr));
-                                    send_command(cmd, client, routes).await
+                                    send_command(
+                                        cmd,
+                                        client,
+                                        routes,
+                                        request.timeout,
+                                        request.disable_retries,
+                                    )
+                                    .await
                                 }
                                 Err(e) => Err(e),
                             },
                             Err(e) => Err(e),
                         }
                     }
-                    command_request::Command::Batch(batch) => {
+                    command_request::Command::Batch(mut batch) => {
                         match get_route(request.route.0, None) {
                             Ok(routes) => {
+                                batch.timeout = batch
+                                    .timeout
+                                    .filter(|timeout| *timeout != 0)
+                                    .or(request.timeout);
                                 let otel_command_span =
                                     get_unsafe_span_from_ptr(request.root_span_ptr);
                                 send_batch(batch, &mut client, routes, otel_command_span).await
@@ -583,6 +689,7 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                                     Some(script.args),
                                     client,
                                     routes,
+                                    request.timeout,
                                 )
                                 .await
                             }
@@ -598,7 +705,15 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                             .map(|pointer| *unsafe { Box::from_raw(pointer as *mut Vec<Bytes>) });
                         match get_route(request.route.0, None) {
                             Ok(routes) => {
-                                invoke_script(script.hash, keys, args, client, routes).await
+                                invoke_script(
+                                    script.hash,
+                                    keys,
+                                    args,
+                                    client,
+                                    routes,
+                                    request.timeout,
+                                )
+                                .await
                             }
                             Err(e) => Err(e),
                         }
@@ -614,6 +729,15 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                         )
                         .await
                         .map_err(|err| err.into()),
+                    command_request::Command::UpdatePubsubSubscriptions(
+                        update_pubsub_subscriptions_command,
+                    ) => {
+                        update_pubsub_subscriptions(update_pubsub_subscriptions_command, client)
+                            .await
+                    }
+                    command_request::Command::UpdateCredentials(update_credentials_command) => {
+                        credential_provider.update_credentials(update_credentials_command)
+                    }
                 },
                 None => {
                     log_debug(
@@ -642,9 +766,15 @@ async fn handle_requests(
     received_requests: Vec<CommandRequest>,
     client: &Client,
     writer: &Rc<Writer>,
+    credential_provider: &Arc<SocketCredentialProvider>,
 ) {
     for request in received_requests {
-        handle_request(request, client.clone(), writer.clone());
+        handle_request(
+            request,
+            client.clone(),
+            writer.clone(),
+            credential_provider.clone(),
+        );
     }
     // Yield to ensure that the subtasks aren't starved.
     task::yield_now().await;
@@ -687,8 +817,23 @@ async fn create_client(
     writer: &Rc<Writer>,
     request: ConnectionRequest,
     push_tx: Option<mpsc::UnboundedSender<PushInfo>>,
+    credential_provider: &Arc<SocketCredentialProvider>,
 ) -> Result<Client, ClientCreationError> {
-    let client = match Client::new(request.into(), push_tx).await {
+    let initial_expires_in_ms = request
+        .credential_provider
+        .as_ref()
+        .map(|credential_provider| credential_provider.expires_in_ms);
+    let mut request: crate::client::ConnectionRequest = request.into();
+    if let Some(expires_in_ms) = initial_expires_in_ms {
+        let authentication_info = request.authentication_info.take().unwrap_or_default();
+        *credential_provider.initial_credentials.lock().unwrap() = Some(Credentials {
+            username: authentication_info.username,
+            token: authentication_info.password.unwrap_or_default(),
+            expires_in: (expires_in_ms > 0).then(|| Duration::from_millis(expires_in_ms)),
+        });
+        request.credential_provider = Some(credential_provider.clone());
+    }
+    let client = match Client::new(request, push_tx).await {
         Ok(client) => client,
         Err(err) => return Err(ClientCreationError::ConnectionError(err)),
     };
@@ -700,13 +845,14 @@ async fn wait_for_connection_configuration_and_create_client(
     client_listener: &mut UnixStreamListener,
     writer: &Rc<Writer>,
     push_tx: Option<mpsc::UnboundedSender<PushInfo>>,
+    credential_provider: &Arc<SocketCredentialProvider>,
 ) -> Result<Client, ClientCreationError> {
     // Wait for the server's address
     match client_listener.next_values::<ConnectionRequest>().await {
         Closed(reason) => Err(ClientCreationError::SocketListenerClosed(reason)),
         ReceivedValues(mut received_requests) => {
             if let Some(request) = received_requests.pop() {
-                create_client(writer, request, push_tx).await
+                create_client(writer, request, push_tx, credential_provider).await
             } else {
                 Err(ClientCreationError::UnhandledError(
                     "No received requests".to_string(),
@@ -720,6 +866,7 @@ async fn read_values_loop(
     mut client_listener: UnixStreamListener,
     client: &Client,
     writer: Rc<Writer>,
+    credential_provider: Arc<SocketCredentialProvider>,
 ) -> ClosingReason {
     loop {
         match client_listener.next_values().await {
@@ -727,7 +874,7 @@ async fn read_values_loop(
                 return reason;
             }
             ReceivedValues(received_requests) => {
-                handle_requests(received_requests, client, &writer).await;
+                handle_requests(received_requests, client, &writer, &credential_provider).await;
             }
         }
     }
@@ -762,6 +909,20 @@ async fn push_manager_loop(mut push_rx: mpsc::UnboundedReceiver<PushInfo>, write
     }
 }
 
+/// Asks the wrapper for refreshed credentials whenever the credential provider requests them.
+async fn credentials_request_loop(
+    mut credentials_request_rx: mpsc::UnboundedReceiver<()>,
+    writer: Rc<Writer>,
+) {
+    while credentials_request_rx.recv().await.is_some() {
+        let mut response = Response::new();
+        response.callback_idx = 0; // callback_idx is not used with push notifications
+        response.is_push = true;
+        response.credentials_request = true;
+        _ = write_to_writer(response, &writer).await;
+    }
+}
+
 async fn listen_on_client_stream(socket: UnixStream) {
     let socket = Rc::new(socket);
     // Spawn a new task to listen on this client's stream
@@ -770,6 +931,14 @@ async fn listen_on_client_stream(socket: UnixStream) {
     let accumulated_outputs = Cell::new(Vec::new());
     let (sender, mut receiver) = channel(1);
     let (push_tx, push_rx) = tokio::sync::mpsc::unbounded_channel();
+    let (credentials_request_tx, credentials_request_rx) = tokio::sync::mpsc::unbounded_channel();
+    // The provider is only passed to the client if the connection request enables it.
+    // It's held here until the stream is closed, so the credentials request loop doesn't end early.
+    let credential_provider = Arc::new(SocketCredentialProvider {
+        initial_credentials: Default::default(),
+        pending_request: Default::default(),
+        credentials_request_sender: credentials_request_tx,
+    });
     let writer = Rc::new(Writer {
         socket,
         lock: write_lock,
@@ -780,6 +949,7 @@ async fn listen_on_client_stream(socket: UnixStream) {
         &mut client_listener,
         &writer,
         Some(push_tx),
+        &credential_provider,
     );
     let client = match client_creation.await {
         Ok(conn) => conn,
@@ -819,7 +989,7 @@ async fn listen_on_client_stream(socket: UnixStream) {
     };
     log_info("connection", "new connection started");
     tokio::select! {
-            reader_closing = read_values_loop(client_listener, &client, writer.clone()) => {
+            reader_closing = read_values_loop(client_listener, &client, writer.clone(), credential_provider.clone()) => {
                 if let ClosingReason::UnhandledError(err) = reader_closing {
                     let _res = write_closing_error(ClosingError{err_message: err.to_string()}, u32::MAX, &writer, "client closing").await;
                 };
@@ -835,7 +1005,12 @@ async fn listen_on_client_stream(socket: UnixStream) {
             _ = push_manager_loop(push_rx, writer.clone()) => {
                 log_trace("client closing", "push manager closed");
             }
+            _ = credentials_request_loop(credentials_request_rx, writer.clone()) => {
+                log_trace("client closing", "credentials request loop closed");
+            }
     }
+    // Fail a credentials request which wasn't answered, so the refresh task doesn't wait on it forever.
+    credential_provider.pending_request.lock().unwrap().take();
     log_trace("client closing", "closing connection");
 }
 
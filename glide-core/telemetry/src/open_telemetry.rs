@@ -25,6 +25,13 @@ const TRACE_SCOPE: &str = "valkey_glide";
 const TIMEOUT_ERROR_METRIC: &str = "glide.timeout_errors";
 const RETRIES_METRIC: &str = "glide.retry_attempts";
 const MOVED_ERROR_METRIC: &str = "glide.moved_errors";
+const CACHE_HITS_METRIC: &str = "glide.cache_hits";
+const CACHE_MISSES_METRIC: &str = "glide.cache_misses";
+const CIRCUIT_BREAKER_TRANSITIONS_METRIC: &str = "glide.circuit_breaker_transitions";
+const RETRY_BUDGET_EXHAUSTED_METRIC: &str = "glide.retry_budget_exhausted";
+const EFFECTIVE_REQUEST_TIMEOUT_METRIC: &str = "glide.effective_request_timeout";
+const INFLIGHT_QUEUE_DEPTH_METRIC: &str = "glide.inflight_queue_depth";
+const INFLIGHT_QUEUE_WAIT_TIME_METRIC: &str = "glide.inflight_queue_wait_time";
 
 /// Custom error type for OpenTelemetry errors in Glide
 #[derive(Debug, Error)]
@@ -472,6 +479,17 @@ pub struct GlideOpenTelemetry {}
 static TIMEOUT_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> = OnceLock::new();
 static RETRIES_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> = OnceLock::new();
 static MOVED_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> = OnceLock::new();
+static CACHE_HITS_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> = OnceLock::new();
+static CACHE_MISSES_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> = OnceLock::new();
+static CIRCUIT_BREAKER_TRANSITIONS_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> =
+    OnceLock::new();
+static RETRY_BUDGET_EXHAUSTED_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> =
+    OnceLock::new();
+static EFFECTIVE_REQUEST_TIMEOUT: OnceLock<opentelemetry::metrics::Gauge<u64>> = OnceLock::new();
+static INFLIGHT_QUEUE_DEPTH: OnceLock<opentelemetry::metrics::UpDownCounter<i64>> =
+    OnceLock::new();
+static INFLIGHT_QUEUE_WAIT_TIME: OnceLock<opentelemetry::metrics::Histogram<f64>> =
+    OnceLock::new();
 
 /// Singleton instance of GlideOpenTelemetry. Ensures that telemetry setup happens only once across the application.
 static OTEL: OnceCell<RwLock<GlideOpenTelemetry>> = OnceCell::new();
@@ -669,6 +687,128 @@ impl GlideOpenTelemetry {
                 )
             })?;
 
+        // Create client side cache hits counter
+        CACHE_HITS_COUNTER
+            .set(
+                meter
+                    .u64_counter(CACHE_HITS_METRIC)
+                    .with_description("Number of commands served from the client side cache")
+                    .with_unit("1")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize cache hits counter".to_owned(),
+                )
+            })?;
+
+        // Create client side cache misses counter
+        CACHE_MISSES_COUNTER
+            .set(
+                meter
+                    .u64_counter(CACHE_MISSES_METRIC)
+                    .with_description(
+                        "Number of cacheable commands not found in the client side cache",
+                    )
+                    .with_unit("1")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize cache misses counter".to_owned(),
+                )
+            })?;
+
+        // Create circuit breaker transitions counter
+        CIRCUIT_BREAKER_TRANSITIONS_COUNTER
+            .set(
+                meter
+                    .u64_counter(CIRCUIT_BREAKER_TRANSITIONS_METRIC)
+                    .with_description(
+                        "Number of node circuit breaker state transitions, by the new state",
+                    )
+                    .with_unit("1")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize circuit breaker transitions counter"
+                        .to_owned(),
+                )
+            })?;
+
+        // Create retry budget exhausted counter
+        RETRY_BUDGET_EXHAUSTED_COUNTER
+            .set(
+                meter
+                    .u64_counter(RETRY_BUDGET_EXHAUSTED_METRIC)
+                    .with_description(
+                        "Number of retries that were skipped because the retry budget was exhausted",
+                    )
+                    .with_unit("1")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize retry budget exhausted counter"
+                        .to_owned(),
+                )
+            })?;
+
+        // Create effective request timeout gauge
+        EFFECTIVE_REQUEST_TIMEOUT
+            .set(
+                meter
+                    .u64_gauge(EFFECTIVE_REQUEST_TIMEOUT_METRIC)
+                    .with_description(
+                        "The request timeout derived from the observed latencies, when adaptive timeouts are enabled",
+                    )
+                    .with_unit("ms")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize effective request timeout gauge"
+                        .to_owned(),
+                )
+            })?;
+
+        // Create inflight requests queue depth counter
+        INFLIGHT_QUEUE_DEPTH
+            .set(
+                meter
+                    .i64_up_down_counter(INFLIGHT_QUEUE_DEPTH_METRIC)
+                    .with_description(
+                        "Number of requests waiting for a free slot under the inflight requests limit",
+                    )
+                    .with_unit("1")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize inflight queue depth counter"
+                        .to_owned(),
+                )
+            })?;
+
+        // Create inflight requests queue wait time histogram
+        INFLIGHT_QUEUE_WAIT_TIME
+            .set(
+                meter
+                    .f64_histogram(INFLIGHT_QUEUE_WAIT_TIME_METRIC)
+                    .with_description(
+                        "Time requests waited for a free slot under the inflight requests limit",
+                    )
+                    .with_unit("ms")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize inflight queue wait time histogram"
+                        .to_owned(),
+                )
+            })?;
+
         Ok(())
     }
 
@@ -723,6 +863,130 @@ impl GlideOpenTelemetry {
         Ok(())
     }
 
+    /// Record a client side cache hit
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_cache_hit() -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            CACHE_HITS_COUNTER
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Cache hits counter not initialized".to_string(),
+                    )
+                })?
+                .add(1, &[]);
+        }
+        Ok(())
+    }
+
+    /// Record a client side cache miss
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_cache_miss() -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            CACHE_MISSES_COUNTER
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Cache misses counter not initialized".to_string(),
+                    )
+                })?
+                .add(1, &[]);
+        }
+        Ok(())
+    }
+
+    /// Record a transition of a node's circuit breaker to the given state
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_circuit_breaker_transition(state: &str) -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            CIRCUIT_BREAKER_TRANSITIONS_COUNTER
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Circuit breaker transitions counter not initialized"
+                            .to_string(),
+                    )
+                })?
+                .add(1, &[opentelemetry::KeyValue::new("state", state.to_owned())]);
+        }
+        Ok(())
+    }
+
+    /// Record a retry that was skipped because the retry budget was exhausted
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_retry_budget_exhausted() -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            RETRY_BUDGET_EXHAUSTED_COUNTER
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Retry budget exhausted counter not initialized"
+                            .to_string(),
+                    )
+                })?
+                .add(1, &[]);
+        }
+        Ok(())
+    }
+
+    /// Record the current effective request timeout, in milliseconds
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_effective_request_timeout(timeout_ms: u64) -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            EFFECTIVE_REQUEST_TIMEOUT
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Effective request timeout gauge not initialized"
+                            .to_string(),
+                    )
+                })?
+                .record(timeout_ms, &[]);
+        }
+        Ok(())
+    }
+
+    /// Record a change in the number of requests waiting for a free inflight request slot
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_inflight_queue_depth_change(delta: i64) -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            INFLIGHT_QUEUE_DEPTH
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Inflight queue depth counter not initialized"
+                            .to_string(),
+                    )
+                })?
+                .add(delta, &[]);
+        }
+        Ok(())
+    }
+
+    /// Record how long a request waited for a free inflight request slot
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_inflight_queue_wait_time(wait_time: Duration) -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            INFLIGHT_QUEUE_WAIT_TIME
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Inflight queue wait time histogram not initialized"
+                            .to_string(),
+                    )
+                })?
+                .record(wait_time.as_secs_f64() * 1000.0, &[]);
+        }
+        Ok(())
+    }
+
     /// Get the flush interval milliseconds
     pub fn get_flush_interval_ms(config: GlideOpenTelemetryConfig) -> Duration {
         config.flush_interval_ms
@@ -990,6 +1254,37 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_record_cache_hit() {
+        let rt = shared_runtime();
+        rt.block_on(async {
+            let _ = std::fs::remove_file(METRICS_JSON);
+            init_otel().await.unwrap();
+            GlideOpenTelemetry::record_cache_hit().unwrap();
+            GlideOpenTelemetry::record_cache_hit().unwrap();
+
+            // Add a sleep to wait for the metrics to be flushed
+            sleep(Duration::from_millis(2100)).await;
+
+            let file_content = std::fs::read_to_string(METRICS_JSON).unwrap();
+            let lines: Vec<&str> = file_content
+                .split('\n')
+                .filter(|l| !l.trim().is_empty())
+                .collect();
+
+            let metric_json: serde_json::Value =
+                serde_json::from_str(lines[lines.len() - 1]).unwrap();
+            assert_eq!(
+                metric_json["scope_metrics"][0]["metrics"][0]["name"],
+                "glide.cache_hits"
+            );
+            assert_eq!(
+                metric_json["scope_metrics"][0]["metrics"][0]["data_points"][0]["value"],
+                2
+            );
+        });
+    }
+
     #[test]
     fn test_set_status_ok() {
         let rt = shared_runtime();
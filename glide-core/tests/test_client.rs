@@ -37,6 +37,7 @@ pub(crate) mod shared_client_tests {
     use utilities::BackingServer;
     use utilities::cluster::*;
     use utilities::*;
+    use versions::Versioning;
 
     struct TestBasics {
         server: BackingServer,
@@ -190,6 +191,137 @@ pub(crate) mod shared_client_tests {
         });
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_key_prefix_namespaces_keys(#[values(false, true)] use_cluster: bool) {
+        block_on_all(async {
+            let namespace = format!("{}:", generate_random_string(6));
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    key_prefix: Some(namespace.clone()),
+                    ..Default::default()
+                },
+            )
+            .await;
+            let mut unprefixed_client = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .client;
+
+            let mut mset = cmd("MSET");
+            mset.arg("foo").arg("1").arg("bar").arg("2");
+            test_basics.client.send_command(&mset, None).await.unwrap();
+
+            // The keys are stored with the prefix, and routed by the prefixed key.
+            let value = unprefixed_client
+                .send_command(cmd("GET").arg(format!("{namespace}foo")), None)
+                .await
+                .unwrap();
+            assert_eq!(value, Value::BulkString(b"1".to_vec()));
+
+            // The prefix is stripped from the keys in replies.
+            let keys = test_basics
+                .client
+                .send_command(cmd("KEYS").arg("*"), None)
+                .await
+                .unwrap();
+            let mut keys: Vec<String> = redis::from_owned_redis_value(keys).unwrap();
+            keys.sort();
+            assert_eq!(keys, vec!["bar".to_string(), "foo".to_string()]);
+
+            let mut rpush = cmd("RPUSH");
+            rpush.arg("list").arg("value");
+            test_basics.client.send_command(&rpush, None).await.unwrap();
+            let popped = test_basics
+                .client
+                .send_command(cmd("BLPOP").arg("list").arg(1), None)
+                .await
+                .unwrap();
+            assert_eq!(
+                popped,
+                Value::Array(vec![
+                    Value::BulkString(b"list".to_vec()),
+                    Value::BulkString(b"value".to_vec())
+                ])
+            );
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_leased_connection_watch_aborts_on_concurrent_write(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    ..Default::default()
+                },
+            )
+            .await;
+            // The keys share a hash tag, so in cluster mode they're served by the leased node.
+            let hash_tag = generate_random_string(6);
+            let key = format!("{{{hash_tag}}}key");
+            let other_key = format!("{{{hash_tag}}}other");
+            let mut pipe = redis::pipe();
+            pipe.cmd("SET").arg(&key).arg("leased");
+            pipe.atomic();
+
+            // A write from the shared connection between WATCH and EXEC aborts the transaction.
+            let mut leased = test_basics
+                .client
+                .lease_connection(Some(key.as_bytes()))
+                .await
+                .unwrap();
+            leased.send_command(cmd("WATCH").arg(&key)).await.unwrap();
+            test_basics
+                .client
+                .send_command(cmd("SET").arg(&key).arg("shared"), None)
+                .await
+                .unwrap();
+            let outcome = leased.exec_transaction(&pipe, true).await.unwrap();
+            assert_eq!(outcome, glide_core::client::TransactionOutcome::Aborted);
+
+            // Watches on other leases don't interfere with each other.
+            let mut first = test_basics
+                .client
+                .lease_connection(Some(key.as_bytes()))
+                .await
+                .unwrap();
+            let mut second = test_basics
+                .client
+                .lease_connection(Some(key.as_bytes()))
+                .await
+                .unwrap();
+            first.send_command(cmd("WATCH").arg(&key)).await.unwrap();
+            second
+                .send_command(cmd("WATCH").arg(&other_key))
+                .await
+                .unwrap();
+            let outcome = first.exec_transaction(&pipe, true).await.unwrap();
+            assert_eq!(
+                outcome,
+                glide_core::client::TransactionOutcome::Committed(Value::Array(vec![Value::Okay]))
+            );
+            let outcome = second.exec_transaction(&pipe, true).await.unwrap();
+            assert!(matches!(
+                outcome,
+                glide_core::client::TransactionOutcome::Committed(_)
+            ));
+        });
+    }
+
     #[rstest]
     #[serial_test::serial]
     #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
@@ -1517,4 +1649,592 @@ pub(crate) mod shared_client_tests {
             );
         });
     }
+
+    /// Returns true if the server supports hash field expiration, which was added in Valkey 9.0 and Redis 7.4.
+    async fn supports_hash_field_expiration(client: &mut Client) -> bool {
+        let info = client
+            .send_command(
+                redis::cmd("INFO").arg("SERVER"),
+                Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random)),
+            )
+            .await
+            .unwrap();
+        let info = InfoDict::from_owned_redis_value(info).unwrap();
+        // Valkey reports the Redis version it's compatible with alongside its own version.
+        let (version, min_version) = match info.get::<String>("valkey_version") {
+            Some(version) => (version, "9.0.0"),
+            None => (info.get::<String>("redis_version").unwrap(), "7.4.0"),
+        };
+        Versioning::new(version) >= Versioning::new(min_version)
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_hash_field_expiration_with_key_named_fields(#[values(false, true)] use_cluster: bool) {
+        block_on_all(async {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: false,
+                    ..Default::default()
+                },
+            )
+            .await;
+            if !supports_hash_field_expiration(&mut test_basics.client).await {
+                return;
+            }
+            let client = &mut test_basics.client;
+
+            // The key and the fields are named `FIELDS`, so they aren't mistaken for the `FIELDS` argument.
+            let result = client
+                .send_command(
+                    redis::cmd("HSET")
+                        .arg("FIELDS")
+                        .arg("FIELDS")
+                        .arg("value")
+                        .arg("token")
+                        .arg("value"),
+                    None,
+                )
+                .await;
+            assert_eq!(result, Ok(Value::Int(2)));
+
+            let result = client
+                .send_command(
+                    redis::cmd("HEXPIRE")
+                        .arg("FIELDS")
+                        .arg("60")
+                        .arg("NX")
+                        .arg("FIELDS")
+                        .arg("2")
+                        .arg("FIELDS")
+                        .arg("token"),
+                    None,
+                )
+                .await;
+            assert_eq!(result, Ok(Value::Array(vec![Value::Int(1), Value::Int(1)])));
+
+            let result = client
+                .send_command(
+                    redis::cmd("HPERSIST")
+                        .arg("FIELDS")
+                        .arg("FIELDS")
+                        .arg("3")
+                        .arg("FIELDS")
+                        .arg("token")
+                        .arg("missing"),
+                    None,
+                )
+                .await;
+            assert_eq!(
+                result,
+                Ok(Value::Array(vec![
+                    Value::Int(1),
+                    Value::Int(1),
+                    Value::Int(-2)
+                ]))
+            );
+
+            let result = client
+                .send_command(redis::cmd("DEL").arg("FIELDS"), None)
+                .await;
+            assert_eq!(result, Ok(Value::Int(1)));
+            // Every field of a missing key has the missing field status.
+            let result = client
+                .send_command(
+                    redis::cmd("HEXPIRE")
+                        .arg("FIELDS")
+                        .arg("60")
+                        .arg("FIELDS")
+                        .arg("2")
+                        .arg("FIELDS")
+                        .arg("token"),
+                    None,
+                )
+                .await;
+            assert_eq!(
+                result,
+                Ok(Value::Array(vec![Value::Int(-2), Value::Int(-2)]))
+            );
+        });
+    }
+
+    /// Returns the routing to the primary that serves `key`. A standalone client ignores it.
+    fn primary_of_key(key: &str) -> RoutingInfo {
+        RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(Route::new(
+            get_slot(key.as_bytes()),
+            SlotAddr::Master,
+        )))
+    }
+
+    /// Returns the number of `GET` calls that the primary serving `key` received since its stats were reset.
+    async fn get_calls_of_key(client: &mut Client, key: &str) -> u32 {
+        let info = client
+            .send_command(
+                redis::cmd("INFO").arg("commandstats"),
+                Some(primary_of_key(key)),
+            )
+            .await
+            .unwrap();
+        InfoDict::from_owned_redis_value(info)
+            .unwrap()
+            .get::<String>("cmdstat_get")
+            .and_then(|stats| {
+                stats
+                    .split_once(',')?
+                    .0
+                    .strip_prefix("calls=")?
+                    .parse::<u32>()
+                    .ok()
+            })
+            .unwrap_or(0)
+    }
+
+    /// Pauses the commands of `mode`, `ALL` or `WRITE`, on the primary that serves `key`, so that they aren't answered for `duration`.
+    async fn pause_primary_of_key(
+        client: &mut Client,
+        key: &str,
+        duration: std::time::Duration,
+        mode: &str,
+    ) {
+        let result = client
+            .send_command(
+                redis::cmd("CLIENT")
+                    .arg("PAUSE")
+                    .arg(duration.as_millis() as u64)
+                    .arg(mode),
+                Some(primary_of_key(key)),
+            )
+            .await;
+        assert_eq!(result, Ok(Value::Okay));
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_client_side_cache_serves_reads_until_invalidated(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async {
+            let key = generate_random_string(10);
+            let mut client = create_shared_client(
+                use_cluster,
+                TestConfiguration {
+                    protocol: glide_core::connection_request::ProtocolVersion::RESP3,
+                    ..Default::default()
+                },
+                |connection_request| {
+                    connection_request.client_side_cache =
+                        Some(glide_core::client::ClientSideCacheConfiguration {
+                            max_entries: 0,
+                            tracking_mode: redis::ClientTrackingMode::Default,
+                        });
+                },
+            )
+            .await;
+            let mut other_client =
+                create_shared_client(use_cluster, TestConfiguration::default(), |_| {}).await;
+            let result = other_client
+                .send_command(redis::cmd("SET").arg(&key).arg("bar"), None)
+                .await;
+            assert_eq!(result, Ok(Value::Okay));
+            let result = other_client
+                .send_command(
+                    redis::cmd("CONFIG").arg("RESETSTAT"),
+                    Some(primary_of_key(&key)),
+                )
+                .await;
+            assert_eq!(result, Ok(Value::Okay));
+
+            let mut get_cmd = redis::cmd("GET");
+            get_cmd.arg(&key);
+            for _ in 0..2 {
+                let result = client.send_command(&get_cmd, None).await;
+                assert_eq!(result, Ok(Value::BulkString(b"bar".to_vec())));
+            }
+            assert_eq!(get_calls_of_key(&mut other_client, &key).await, 1);
+
+            // The server doesn't send an invalidation for the client's own write, which evicts the key.
+            let result = client
+                .send_command(redis::cmd("SET").arg(&key).arg("baz"), None)
+                .await;
+            assert_eq!(result, Ok(Value::Okay));
+            for _ in 0..2 {
+                let result = client.send_command(&get_cmd, None).await;
+                assert_eq!(result, Ok(Value::BulkString(b"baz".to_vec())));
+            }
+            assert_eq!(get_calls_of_key(&mut other_client, &key).await, 2);
+
+            // Writes of other clients are invalidated by the server.
+            let result = other_client
+                .send_command(redis::cmd("SET").arg(&key).arg("other"), None)
+                .await;
+            assert_eq!(result, Ok(Value::Okay));
+            while client.send_command(&get_cmd, None).await
+                != Ok(Value::BulkString(b"other".to_vec()))
+            {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_per_request_timeout_overrides_client_timeout(#[values(false, true)] use_cluster: bool) {
+        block_on_all(async {
+            let key = generate_random_string(10);
+            let mut client = create_shared_client(
+                use_cluster,
+                TestConfiguration {
+                    request_timeout: Some(60_000),
+                    ..Default::default()
+                },
+                |_| {},
+            )
+            .await;
+            let mut other_client =
+                create_shared_client(use_cluster, TestConfiguration::default(), |_| {}).await;
+            let mut get_command = redis::cmd("GET");
+            get_command.arg(&key);
+
+            // A zero timeout uses the client's request timeout.
+            let result = client
+                .send_command_with_timeout(&get_command, None, Some(0))
+                .await;
+            assert_eq!(result, Ok(Value::Nil));
+
+            // The paused primary doesn't answer the request, so it times out.
+            let pause = std::time::Duration::from_secs(2);
+            pause_primary_of_key(&mut other_client, &key, pause, "ALL").await;
+            let start = std::time::Instant::now();
+            let err = client
+                .send_command_with_timeout(&get_command, None, Some(100))
+                .await
+                .unwrap_err();
+            assert!(err.is_timeout(), "{err}");
+            assert!(start.elapsed() < std::time::Duration::from_secs(1));
+            // The following tests share the server, so they wait for the pause to end.
+            tokio::time::sleep(pause.saturating_sub(start.elapsed())).await;
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_inflight_overflow_queue_waits_for_released_request(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async {
+            let client = create_shared_client(
+                use_cluster,
+                TestConfiguration::default(),
+                |connection_request| {
+                    connection_request.inflight_requests_limit = Some(1);
+                    connection_request.inflight_overflow_policy =
+                        glide_core::client::InflightOverflowPolicy::Queue(1);
+                },
+            )
+            .await;
+            assert!(client.acquire_inflight_request().await);
+
+            let waiting_client = client.clone();
+            let waiting =
+                tokio::spawn(async move { waiting_client.acquire_inflight_request().await });
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            // The queue is full, so another request is rejected right away.
+            assert!(!client.acquire_inflight_request().await);
+
+            client.release_inflight_request();
+            assert!(waiting.await.unwrap());
+            assert!(!client.reserve_inflight_request());
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_inflight_overflow_wait_times_out(#[values(false, true)] use_cluster: bool) {
+        block_on_all(async {
+            let client = create_shared_client(
+                use_cluster,
+                TestConfiguration::default(),
+                |connection_request| {
+                    connection_request.inflight_requests_limit = Some(1);
+                    connection_request.inflight_overflow_policy =
+                        glide_core::client::InflightOverflowPolicy::Wait(
+                            std::time::Duration::from_millis(50),
+                        );
+                },
+            )
+            .await;
+            assert!(client.acquire_inflight_request().await);
+            let start = std::time::Instant::now();
+            assert!(!client.acquire_inflight_request().await);
+            assert!(start.elapsed() >= std::time::Duration::from_millis(50));
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_command_retry_strategy_retries_within_request_timeout(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async {
+            let stream_key = generate_random_string(10);
+            let counter_key = generate_random_string(10);
+            let mut client = create_shared_client(
+                use_cluster,
+                TestConfiguration {
+                    request_timeout: Some(200),
+                    ..Default::default()
+                },
+                |connection_request| {
+                    connection_request.command_retry_strategy =
+                        Some(glide_core::client::ConnectionRetryStrategy {
+                            exponent_base: 2,
+                            factor: 10,
+                            number_of_retries: 3,
+                            jitter_percent: None,
+                        });
+                },
+            )
+            .await;
+            let mut other_client =
+                create_shared_client(use_cluster, TestConfiguration::default(), |_| {}).await;
+
+            // A read whose connection was killed is resent on the new connection.
+            let client_id = client
+                .send_command(
+                    redis::cmd("CLIENT").arg("ID"),
+                    Some(primary_of_key(&stream_key)),
+                )
+                .await
+                .and_then(i64::from_owned_redis_value)
+                .unwrap();
+            let read = async {
+                client
+                    .clone()
+                    .send_command(
+                        redis::cmd("XREAD")
+                            .arg("BLOCK")
+                            .arg(2000)
+                            .arg("STREAMS")
+                            .arg(&stream_key)
+                            .arg("0-0"),
+                        None,
+                    )
+                    .await
+            };
+            let kill_and_write = async {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                let result = other_client
+                    .send_command(
+                        redis::cmd("CLIENT").arg("KILL").arg("ID").arg(client_id),
+                        Some(primary_of_key(&stream_key)),
+                    )
+                    .await;
+                assert_eq!(result, Ok(Value::Int(1)));
+                tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+                other_client
+                    .send_command(
+                        redis::cmd("XADD")
+                            .arg(&stream_key)
+                            .arg("*")
+                            .arg("field")
+                            .arg("value"),
+                        None,
+                    )
+                    .await
+                    .unwrap();
+            };
+            let (result, _) = tokio::join!(read, kill_and_write);
+            assert!(
+                matches!(result, Ok(Value::Map(ref streams)) if streams.len() == 1),
+                "{result:?}"
+            );
+
+            // A write that timed out might have been applied, so it isn't retried.
+            let pause = std::time::Duration::from_secs(1);
+            pause_primary_of_key(&mut other_client, &counter_key, pause, "ALL").await;
+            let start = std::time::Instant::now();
+            let err = client
+                .send_command(redis::cmd("INCR").arg(&counter_key), None)
+                .await
+                .unwrap_err();
+            assert!(err.is_timeout(), "{err}");
+
+            // Retries share the request timeout, so a read that timed out has no time left to be retried.
+            let read_start = std::time::Instant::now();
+            let err = client
+                .send_command(redis::cmd("GET").arg(&counter_key), None)
+                .await
+                .unwrap_err();
+            assert!(err.is_timeout(), "{err}");
+            assert!(read_start.elapsed() < std::time::Duration::from_millis(400));
+
+            // The paused write is applied once the pause ends.
+            tokio::time::sleep(
+                pause.saturating_sub(start.elapsed()) + std::time::Duration::from_millis(100),
+            )
+            .await;
+            let result = other_client
+                .send_command(redis::cmd("GET").arg(&counter_key), None)
+                .await;
+            assert_eq!(result, Ok(Value::BulkString(b"1".to_vec())));
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_exhausted_retry_budget_returns_the_original_error(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async {
+            let key = generate_random_string(10);
+            let mut client = create_shared_client(
+                use_cluster,
+                TestConfiguration::default(),
+                |connection_request| {
+                    connection_request.retry_budget = Some(redis::RetryBudgetConfig {
+                        retry_percentage: 10,
+                        min_retries_per_second: 0,
+                    });
+                    connection_request.command_retry_strategy =
+                        Some(glide_core::client::ConnectionRetryStrategy {
+                            exponent_base: 2,
+                            factor: 10,
+                            number_of_retries: 3,
+                            jitter_percent: None,
+                        });
+                },
+            )
+            .await;
+            let mut other_client =
+                create_shared_client(use_cluster, TestConfiguration::default(), |_| {}).await;
+            let client_id = client
+                .send_command(redis::cmd("CLIENT").arg("ID"), Some(primary_of_key(&key)))
+                .await
+                .and_then(i64::from_owned_redis_value)
+                .unwrap();
+
+            // The read would be resent after its connection was killed, but the budget has no retries left.
+            let read = async {
+                client
+                    .clone()
+                    .send_command(
+                        redis::cmd("XREAD")
+                            .arg("BLOCK")
+                            .arg(2000)
+                            .arg("STREAMS")
+                            .arg(&key)
+                            .arg("0-0"),
+                        None,
+                    )
+                    .await
+            };
+            let kill = async {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                let result = other_client
+                    .send_command(
+                        redis::cmd("CLIENT").arg("KILL").arg("ID").arg(client_id),
+                        Some(primary_of_key(&key)),
+                    )
+                    .await;
+                assert_eq!(result, Ok(Value::Int(1)));
+            };
+            let (result, _) = tokio::join!(read, kill);
+            let err = result.unwrap_err();
+            assert!(err.is_connection_dropped() || err.is_io_error(), "{err}");
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_adaptive_timeout_fails_slow_requests_before_request_timeout(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async {
+            let key = generate_random_string(10);
+            let mut client = create_shared_client(
+                use_cluster,
+                TestConfiguration {
+                    request_timeout: Some(5000),
+                    ..Default::default()
+                },
+                |connection_request| {
+                    connection_request.adaptive_timeout = Some(redis::AdaptiveTimeoutConfig {
+                        floor: std::time::Duration::from_millis(100),
+                        multiplier: 3.0,
+                    });
+                },
+            )
+            .await;
+            let mut other_client =
+                create_shared_client(use_cluster, TestConfiguration::default(), |_| {}).await;
+            let mut get_command = redis::cmd("GET");
+            get_command.arg(&key);
+            // Enough fast replies to compute the p99 latency, followed by a reply that's delayed by the pause.
+            for _ in 0..100 {
+                let result = client.send_command(&get_command, None).await;
+                assert_eq!(result, Ok(Value::Nil));
+            }
+            let pause = std::time::Duration::from_secs(2);
+            pause_primary_of_key(&mut other_client, &key, pause, "ALL").await;
+            let start = std::time::Instant::now();
+            let err = client.send_command(&get_command, None).await.unwrap_err();
+            assert!(err.is_timeout(), "{err}");
+            assert!(start.elapsed() < std::time::Duration::from_secs(1));
+            // The following tests share the server, so they wait for the pause to end.
+            tokio::time::sleep(pause.saturating_sub(start.elapsed())).await;
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_adaptive_timeout_doesnt_apply_to_slow_commands(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async {
+            let key = generate_random_string(10);
+            let mut client = create_shared_client(
+                use_cluster,
+                TestConfiguration {
+                    request_timeout: Some(1000),
+                    ..Default::default()
+                },
+                |connection_request| {
+                    connection_request.adaptive_timeout = Some(redis::AdaptiveTimeoutConfig {
+                        floor: std::time::Duration::from_millis(100),
+                        multiplier: 3.0,
+                    });
+                },
+            )
+            .await;
+            let mut other_client =
+                create_shared_client(use_cluster, TestConfiguration::default(), |_| {}).await;
+            let mut get_command = redis::cmd("GET");
+            get_command.arg(&key);
+            for _ in 0..100 {
+                let result = client.send_command(&get_command, None).await;
+                assert_eq!(result, Ok(Value::Nil));
+            }
+            let pause = std::time::Duration::from_secs(2);
+            pause_primary_of_key(&mut other_client, &key, pause, "ALL").await;
+            let start = std::time::Instant::now();
+            let err = client
+                .send_command(redis::cmd("KEYS").arg(&key), None)
+                .await
+                .unwrap_err();
+            assert!(err.is_timeout(), "{err}");
+            assert!(start.elapsed() >= std::time::Duration::from_millis(900));
+            // The following tests share the server, so they wait for the pause to end.
+            tokio::time::sleep(pause.saturating_sub(start.elapsed())).await;
+        });
+    }
 }
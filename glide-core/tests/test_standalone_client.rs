@@ -6,10 +6,14 @@ mod utilities;
 mod standalone_client_tests {
     use crate::utilities::mocks::{Mock, ServerMock};
     use std::collections::HashMap;
+    use std::sync::Arc;
 
     use super::*;
     use glide_core::{
-        client::{Client as GlideClient, ConnectionError, StandaloneClient},
+        client::{
+            Client as GlideClient, ConnectionError, CredentialProvider, Credentials,
+            StandaloneClient,
+        },
         connection_request::{ProtocolVersion, ReadFrom},
     };
     use redis::{FromRedisValue, Value};
@@ -293,6 +297,19 @@ mod standalone_client_tests {
         });
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_read_from_replica_lowest_latency() {
+        // Once a replica was sampled, reads stick to it until another replica is measured as faster
+        test_read_from_replica(ReadFromReplicaTestConfig {
+            read_from: ReadFrom::LowestLatency,
+            expected_primary_reads: 0,
+            expected_replica_reads: vec![0, 0, 3],
+            ..Default::default()
+        });
+    }
+
     // TODO - Current test falls back to PreferReplica when run, need to integrate the az here also
     #[rstest]
     #[serial_test::serial]
@@ -374,6 +391,468 @@ mod standalone_client_tests {
         });
     }
 
+    fn replication_info_response(role: &str) -> String {
+        let info = format!("role:{role}\r\n");
+        format!("${}\r\n{info}\r\n", info.len())
+    }
+
+    /// Creates a primary and a replica which answer the replication role queries of the client creation,
+    /// and a connection request to both of them.
+    fn create_failover_mocks() -> (
+        ServerMock,
+        ServerMock,
+        glide_core::connection_request::ConnectionRequest,
+    ) {
+        let mut listeners: Vec<std::net::TcpListener> =
+            (0..2).map(|_| get_listener_on_available_port()).collect();
+        let mut constant_responses = HashMap::new();
+        constant_responses.insert(
+            "*1\r\n$4\r\nPING\r\n".to_string(),
+            Value::BulkString(b"PONG".to_vec()),
+        );
+        let old_primary =
+            ServerMock::new_with_listener(constant_responses.clone(), listeners.pop().unwrap());
+        let new_primary =
+            ServerMock::new_with_listener(constant_responses, listeners.pop().unwrap());
+        old_primary.add_response(&replication_info_cmd(), replication_info_response("master"));
+        new_primary.add_response(&replication_info_cmd(), replication_info_response("slave"));
+
+        let addresses: Vec<_> = [&old_primary, &new_primary]
+            .iter()
+            .flat_map(|mock| mock.get_addresses())
+            .collect();
+        let connection_request =
+            create_connection_request(addresses.as_slice(), &Default::default());
+        (old_primary, new_primary, connection_request)
+    }
+
+    /// Adds the replies to the replication role queries of a primary election, after `old_primary` was demoted
+    /// and `new_primary` was promoted.
+    fn add_primary_election_responses(old_primary: &ServerMock, new_primary: &ServerMock) {
+        old_primary.add_response(&replication_info_cmd(), replication_info_response("slave"));
+        new_primary.add_response(&replication_info_cmd(), replication_info_response("master"));
+    }
+
+    fn replication_info_cmd() -> redis::Cmd {
+        redis::cmd("INFO").arg("REPLICATION").to_owned()
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_write_is_retried_on_new_primary_after_failover() {
+        let (old_primary, new_primary, connection_request) = create_failover_mocks();
+        let mut set_cmd = redis::cmd("SET");
+        set_cmd.arg("foo").arg("bar");
+        // The old primary was demoted before receiving the write
+        old_primary.add_response(
+            &set_cmd,
+            "-READONLY You can't write against a read only replica.\r\n".to_string(),
+        );
+        add_primary_election_responses(&old_primary, &new_primary);
+        new_primary.add_response(&set_cmd, "+OK\r\n".to_string());
+
+        block_on_all(async {
+            let mut client = StandaloneClient::create_client(connection_request.into(), None)
+                .await
+                .unwrap();
+            let result = client.send_command(&set_cmd).await;
+            assert_eq!(result, Ok(Value::Okay));
+        });
+
+        assert_eq!(old_primary.get_number_of_received_commands(), 3);
+        assert_eq!(new_primary.get_number_of_received_commands(), 3);
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_periodic_primary_check_detects_failover_when_enabled() {
+        let (old_primary, new_primary, mut connection_request) = create_failover_mocks();
+        let mut set_cmd = redis::cmd("SET");
+        set_cmd.arg("foo").arg("bar");
+        // Replies to the periodic check, after the primary was demoted
+        add_primary_election_responses(&old_primary, &new_primary);
+        new_primary.add_response(&set_cmd, "+OK\r\n".to_string());
+        connection_request.primary_check_interval = 1;
+        let new_primary_address = new_primary.get_addresses()[0].to_string();
+
+        block_on_all(async {
+            let mut client = StandaloneClient::create_client(connection_request.into(), None)
+                .await
+                .unwrap();
+            while client.primary_address() != new_primary_address {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+            // The write is sent directly to the new primary.
+            let result = client.send_command(&set_cmd).await;
+            assert_eq!(result, Ok(Value::Okay));
+        });
+
+        assert_eq!(old_primary.get_number_of_received_commands(), 2);
+        assert_eq!(new_primary.get_number_of_received_commands(), 3);
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_runtime_pubsub_subscriptions_are_sent_to_pubsub_node() {
+        let mut constant_responses = HashMap::new();
+        constant_responses.insert(
+            "*2\r\n$5\r\nHELLO\r\n$1\r\n3\r\n".to_string(),
+            Value::Map(vec![]),
+        );
+        constant_responses.insert(
+            "*1\r\n$4\r\nPING\r\n".to_string(),
+            Value::BulkString(b"PONG".to_vec()),
+        );
+        let mock = ServerMock::new(constant_responses);
+
+        let mut info_cmd = redis::cmd("INFO");
+        info_cmd.arg("REPLICATION");
+        mock.add_response(&info_cmd, "$13\r\nrole:master\r\n\r\n".to_string());
+        let mut subscribe_cmd = redis::cmd("SUBSCRIBE");
+        subscribe_cmd.arg("news");
+        mock.add_response(
+            &subscribe_cmd,
+            ">3\r\n$9\r\nsubscribe\r\n$4\r\nnews\r\n:1\r\n".to_string(),
+        );
+        let mut unsubscribe_cmd = redis::cmd("UNSUBSCRIBE");
+        unsubscribe_cmd.arg("news");
+        mock.add_response(
+            &unsubscribe_cmd,
+            ">3\r\n$11\r\nunsubscribe\r\n$4\r\nnews\r\n:0\r\n".to_string(),
+        );
+
+        let mut connection_request =
+            create_connection_request(mock.get_addresses().as_slice(), &Default::default());
+        connection_request.protocol = ProtocolVersion::RESP3.into();
+
+        block_on_all(async {
+            let client = StandaloneClient::create_client(connection_request.into(), None)
+                .await
+                .unwrap();
+            let result = client
+                .add_pubsub_subscriptions(
+                    redis::PubSubSubscriptionKind::Exact,
+                    vec![b"news".to_vec()],
+                )
+                .await;
+            assert_eq!(result, Ok(Value::Okay));
+            let result = client
+                .remove_pubsub_subscriptions(
+                    redis::PubSubSubscriptionKind::Exact,
+                    vec![b"news".to_vec()],
+                )
+                .await;
+            assert_eq!(result, Ok(Value::Okay));
+        });
+
+        assert_eq!(mock.get_number_of_received_commands(), 3);
+    }
+
+    /// Returns a token which expires shortly, and then a token which doesn't expire.
+    struct RotatingCredentialProvider {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl CredentialProvider for RotatingCredentialProvider {
+        async fn get_credentials(&self) -> redis::RedisResult<Credentials> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::AcqRel);
+            Ok(Credentials {
+                username: None,
+                token: format!("token{call}"),
+                expires_in: (call == 0).then(|| std::time::Duration::from_millis(100)),
+            })
+        }
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_credential_provider_reauthenticates_all_nodes_before_expiry() {
+        let mut initial_auth = redis::cmd("AUTH");
+        initial_auth.arg("token0");
+        let mocks: Vec<ServerMock> = [create_primary_responses(), create_replica_response()]
+            .into_iter()
+            .map(|mut constant_responses| {
+                constant_responses.insert(
+                    String::from_utf8(initial_auth.get_packed_command()).unwrap(),
+                    Value::Okay,
+                );
+                ServerMock::new(constant_responses)
+            })
+            .collect();
+        let mut refreshed_auth = redis::cmd("AUTH");
+        refreshed_auth.arg("token1");
+        for mock in mocks.iter() {
+            mock.add_response(&refreshed_auth, "+OK\r\n".to_string());
+        }
+        let addresses: Vec<redis::ConnectionAddr> =
+            mocks.iter().flat_map(|mock| mock.get_addresses()).collect();
+        let mut connection_request: glide_core::client::ConnectionRequest =
+            create_connection_request(addresses.as_slice(), &Default::default()).into();
+        let provider = Arc::new(RotatingCredentialProvider {
+            calls: Default::default(),
+        });
+        connection_request.credential_provider = Some(provider.clone());
+
+        block_on_all(async {
+            let _client = GlideClient::new(connection_request, None).await.unwrap();
+            tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        });
+
+        assert_eq!(provider.calls.load(std::sync::atomic::Ordering::Acquire), 2);
+        for mock in mocks {
+            assert_eq!(mock.get_number_of_received_commands(), 1);
+        }
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_circuit_breaker_fails_fast_after_consecutive_timeouts() {
+        let mock = ServerMock::new(create_primary_responses());
+        let mut get_command = redis::cmd("GET");
+        get_command.arg("foo");
+        // The mock doesn't answer these requests, so they time out.
+        mock.add_response(&get_command, String::new());
+        mock.add_response(&get_command, String::new());
+        let mut connection_request: glide_core::client::ConnectionRequest =
+            create_connection_request(mock.get_addresses().as_slice(), &Default::default()).into();
+        connection_request.request_timeout = Some(100);
+        connection_request.circuit_breaker = Some(redis::CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_duration: std::time::Duration::from_secs(60),
+            half_open_probes: 1,
+        });
+
+        block_on_all(async {
+            let mut client = GlideClient::new(connection_request, None).await.unwrap();
+            for _ in 0..2 {
+                let err = client.send_command(&get_command, None).await.unwrap_err();
+                assert!(err.is_timeout(), "{err}");
+            }
+            let err = client.send_command(&get_command, None).await.unwrap_err();
+            assert_eq!(err.kind(), redis::ErrorKind::CircuitOpen);
+        });
+        assert_eq!(mock.get_number_of_received_commands(), 2);
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_read_your_writes_reads_from_primary_after_unrecorded_write(
+        #[values(false, true)] in_pipeline: bool,
+    ) {
+        let servers = create_primary_mock_with_replicas(1);
+        let mut get_command = redis::cmd("GET");
+        get_command.arg("foo");
+        let mut set_command = redis::cmd("SET");
+        set_command.arg("foo").arg("bar");
+        servers[1].add_response(&get_command, "$-1\r\n".to_string());
+        // A read from the replica after the write would return the stale value.
+        servers[1].add_response(&get_command, "$-1\r\n".to_string());
+        // The primary's INFO REPLICATION reply has no replication offset, so the write's offset isn't recorded.
+        servers[0].add_response(&set_command, "+OK\r\n".to_string());
+        servers[0].add_response(&get_command, "$3\r\nbar\r\n".to_string());
+        let mut connection_request =
+            create_connection_request(get_mock_addresses(&servers).as_slice(), &Default::default());
+        connection_request.read_from = ReadFrom::PreferReplica.into();
+        connection_request.read_your_writes = true;
+
+        block_on_all(async {
+            let mut client = GlideClient::new(connection_request.into(), None)
+                .await
+                .unwrap();
+            let result = client.send_command(&get_command, None).await.unwrap();
+            assert_eq!(result, Value::Nil);
+            if in_pipeline {
+                let mut pipeline = redis::pipe();
+                pipeline.add_command(set_command.clone());
+                let result = client
+                    .send_pipeline(&pipeline, None, false, None, Default::default())
+                    .await
+                    .unwrap();
+                assert_eq!(result, Value::Array(vec![Value::Okay]));
+            } else {
+                let result = client.send_command(&set_command, None).await.unwrap();
+                assert_eq!(result, Value::Okay);
+            }
+            let result = client.send_command(&get_command, None).await.unwrap();
+            assert_eq!(result, Value::BulkString(b"bar".to_vec()));
+            assert_eq!(servers[0].get_number_of_received_commands(), 2);
+            assert_eq!(servers[1].get_number_of_received_commands(), 1);
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_ft_cursor_is_read_until_exhausted_or_deleted() {
+        let mock = ServerMock::new(create_primary_responses());
+        let mut aggregate_command = redis::cmd("FT.AGGREGATE");
+        aggregate_command.arg("idx").arg("*").arg("WITHCURSOR");
+        let server_cursor_command = |subcommand: &str, cursor_id: i64| {
+            let mut cmd = redis::cmd("FT.CURSOR");
+            cmd.arg(subcommand).arg("idx").arg(cursor_id);
+            cmd
+        };
+        let page = |cursor_id: i64| format!("*2\r\n*1\r\n:0\r\n:{cursor_id}\r\n");
+        mock.add_response(&aggregate_command, page(4567));
+        mock.add_response(&server_cursor_command("READ", 4567), page(4567));
+        mock.add_response(&server_cursor_command("READ", 4567), page(0));
+        mock.add_response(&aggregate_command, page(8910));
+        mock.add_response(&server_cursor_command("DEL", 8910), "+OK\r\n".to_string());
+        mock.add_response(&aggregate_command, page(1112));
+        // A cursor that's removed by the wrapper is released on the server.
+        mock.add_response(&server_cursor_command("DEL", 1112), "+OK\r\n".to_string());
+        let connection_request =
+            create_connection_request(mock.get_addresses().as_slice(), &Default::default());
+
+        block_on_all(async {
+            let mut client = GlideClient::new(connection_request.into(), None)
+                .await
+                .unwrap();
+            let cursor_of = |value: Value| {
+                let Value::Array(mut page) = value else {
+                    panic!("Unexpected reply: {value:?}");
+                };
+                assert_eq!(page.first(), Some(&Value::Array(vec![])));
+                String::from_redis_value(&page.pop().unwrap()).unwrap()
+            };
+            let read_command = |subcommand: &str, cursor: &str| {
+                let mut cmd = redis::cmd("FT.CURSOR");
+                cmd.arg(subcommand).arg("idx").arg(cursor);
+                cmd
+            };
+
+            let value = client.send_command(&aggregate_command, None).await.unwrap();
+            let cursor = cursor_of(value);
+            assert_ne!(cursor, "4567");
+            let value = client
+                .send_command(&read_command("READ", &cursor), None)
+                .await
+                .unwrap();
+            assert_eq!(cursor_of(value), cursor);
+            let value = client
+                .send_command(&read_command("READ", &cursor), None)
+                .await
+                .unwrap();
+            assert_eq!(cursor_of(value), "0");
+            // The exhausted cursor was removed.
+            assert!(
+                client
+                    .send_command(&read_command("READ", &cursor), None)
+                    .await
+                    .is_err()
+            );
+
+            let value = client.send_command(&aggregate_command, None).await.unwrap();
+            let cursor = cursor_of(value);
+            let value = client
+                .send_command(&read_command("DEL", &cursor), None)
+                .await
+                .unwrap();
+            assert_eq!(value, Value::Okay);
+            assert!(
+                client
+                    .send_command(&read_command("READ", &cursor), None)
+                    .await
+                    .is_err()
+            );
+            assert_eq!(mock.get_number_of_received_commands(), 5);
+
+            let value = client.send_command(&aggregate_command, None).await.unwrap();
+            glide_core::ft_cursor_container::remove_ft_cursor(cursor_of(value));
+            while mock.get_number_of_received_commands() < 7 {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_slow_replica_read_is_hedged_to_another_node() {
+        let servers = create_primary_mock_with_replicas(1);
+        let mut get_command = redis::cmd("GET");
+        get_command.arg("foo");
+        // The replica doesn't answer, so the read is hedged to the primary.
+        servers[1].add_response(&get_command, String::new());
+        servers[0].add_response(&get_command, "$-1\r\n".to_string());
+        // The late reply to the cancelled read arrives before the reply to the next one.
+        servers[1].add_response(&get_command, "$-1\r\n$3\r\nbar\r\n".to_string());
+        let mut connection_request =
+            create_connection_request(get_mock_addresses(&servers).as_slice(), &Default::default());
+        connection_request.read_from = ReadFrom::PreferReplica.into();
+        let mut connection_request: glide_core::client::ConnectionRequest =
+            connection_request.into();
+        connection_request.request_timeout = Some(1000);
+        connection_request.request_hedging = Some(redis::RequestHedgingConfig {
+            delay: redis::HedgeDelay::Fixed(std::time::Duration::from_millis(50)),
+            max_hedge_percentage: 100,
+        });
+        connection_request.circuit_breaker = Some(redis::CircuitBreakerConfig {
+            failure_threshold: 1,
+            open_duration: std::time::Duration::from_secs(60),
+            half_open_probes: 1,
+        });
+
+        block_on_all(async {
+            let mut client = StandaloneClient::create_client(connection_request, None)
+                .await
+                .unwrap();
+            let result = client.send_command(&get_command).await.unwrap();
+            assert_eq!(result, Value::Nil);
+            // The cancelled read to the replica isn't counted as a failure, so its circuit stays closed.
+            let result = client.send_command(&get_command).await.unwrap();
+            assert_eq!(result, Value::BulkString(b"bar".to_vec()));
+        });
+        assert_eq!(servers[0].get_number_of_received_commands(), 1);
+        assert_eq!(servers[1].get_number_of_received_commands(), 2);
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_requests_abandoned_by_fan_out_are_not_circuit_breaker_failures() {
+        let servers = create_primary_mock_with_replicas(1);
+        let script_kill_command = redis::cmd("SCRIPT").arg("KILL").to_owned();
+        let mut get_command = redis::cmd("GET");
+        get_command.arg("foo");
+        // The replica doesn't answer, so the request to it is dropped once the primary succeeds.
+        servers[0].add_response(&script_kill_command, "+OK\r\n".to_string());
+        servers[1].add_response(&script_kill_command, String::new());
+        // The late reply to the dropped request arrives before the reply to the read.
+        servers[1].add_response(&get_command, "+OK\r\n$3\r\nbar\r\n".to_string());
+        let mut connection_request =
+            create_connection_request(get_mock_addresses(&servers).as_slice(), &Default::default());
+        connection_request.read_from = ReadFrom::PreferReplica.into();
+        let mut connection_request: glide_core::client::ConnectionRequest =
+            connection_request.into();
+        connection_request.request_timeout = Some(1000);
+        connection_request.circuit_breaker = Some(redis::CircuitBreakerConfig {
+            failure_threshold: 1,
+            open_duration: std::time::Duration::from_secs(60),
+            half_open_probes: 1,
+        });
+
+        block_on_all(async {
+            let mut client = StandaloneClient::create_client(connection_request, None)
+                .await
+                .unwrap();
+            let result = client.send_command(&script_kill_command).await.unwrap();
+            assert_eq!(result, Value::Okay);
+            // The dropped request to the replica isn't counted as a failure, so its circuit stays closed.
+            let result = client.send_command(&get_command).await.unwrap();
+            assert_eq!(result, Value::BulkString(b"bar".to_vec()));
+        });
+        assert_eq!(servers[0].get_number_of_received_commands(), 1);
+        assert_eq!(servers[1].get_number_of_received_commands(), 2);
+    }
+
     #[rstest]
     #[serial_test::serial]
     #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
@@ -638,4 +1117,104 @@ mod standalone_client_tests {
             );
         });
     }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(LONG_STANDALONE_TEST_TIMEOUT)]
+    fn test_sentinel_discovers_primary_and_follows_failover() {
+        // Sentinel discoveries and failovers take seconds, longer than `repeat_try_create` waits.
+        async fn wait_for<T, Fut>(f: impl Fn() -> Fut) -> T
+        where
+            Fut: std::future::Future<Output = Option<T>>,
+        {
+            for _ in 0..150 {
+                if let Some(value) = f().await {
+                    return value;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            }
+            panic!("Sentinel didn't reach the expected state");
+        }
+
+        let master_name = "mymaster";
+        let primary = RedisServer::new(ServerType::Tcp { tls: false });
+        let primary_port = get_address_info(&primary.get_client_addr()).port as u16;
+        let replica = RedisServer::new_replica(primary_port);
+        let sentinel = RedisServer::new_sentinel(master_name, primary_port);
+
+        block_on_all(async move {
+            wait_for_server_to_become_ready(&primary.get_client_addr()).await;
+            wait_for_server_to_become_ready(&replica.get_client_addr()).await;
+            wait_for_server_to_become_ready(&sentinel.get_client_addr()).await;
+
+            let sentinel_client = redis::Client::open(sentinel.connection_info()).unwrap();
+            let sentinel_connection = sentinel_client
+                .get_multiplexed_async_connection(Default::default())
+                .await
+                .unwrap();
+            // The sentinel discovers the replica through the primary, so wait until it reports it.
+            wait_for(|| {
+                let mut sentinel_connection = sentinel_connection.clone();
+                async move {
+                    let replicas: Vec<Value> = redis::cmd("SENTINEL")
+                        .arg("REPLICAS")
+                        .arg(master_name)
+                        .query_async(&mut sentinel_connection)
+                        .await
+                        .ok()?;
+                    (!replicas.is_empty()).then_some(())
+                }
+            })
+            .await;
+
+            let mut sentinel_configuration =
+                glide_core::connection_request::SentinelConfiguration::new();
+            sentinel_configuration.addresses = vec![get_address_info(&sentinel.get_client_addr())];
+            sentinel_configuration.master_name = master_name.into();
+            // The nodes are discovered through the sentinel.
+            let mut connection_request = create_connection_request(&[], &Default::default());
+            connection_request.sentinel_configuration = Some(sentinel_configuration).into();
+            let mut client = StandaloneClient::create_client(connection_request.into(), None)
+                .await
+                .unwrap();
+
+            let mut set_cmd = redis::cmd("SET");
+            set_cmd.arg("foo").arg("bar");
+            assert_eq!(client.send_command(&set_cmd).await, Ok(Value::Okay));
+
+            // The failover is refused until the replica finished its initial sync.
+            wait_for(|| {
+                let mut sentinel_connection = sentinel_connection.clone();
+                async move {
+                    redis::cmd("SENTINEL")
+                        .arg("FAILOVER")
+                        .arg(master_name)
+                        .query_async::<_, ()>(&mut sentinel_connection)
+                        .await
+                        .ok()
+                }
+            })
+            .await;
+
+            // Once the sentinel published the new primary, writes succeed on the former replica.
+            let replica_client = redis::Client::open(replica.connection_info()).unwrap();
+            wait_for(|| async {
+                let mut replica_connection = replica_client
+                    .get_multiplexed_async_connection(Default::default())
+                    .await
+                    .ok()?;
+                let role: Vec<Value> = redis::cmd("ROLE")
+                    .query_async(&mut replica_connection)
+                    .await
+                    .ok()?;
+                (role.first() == Some(&Value::BulkString(b"master".to_vec()))).then_some(())
+            })
+            .await;
+            wait_for(|| async {
+                let mut client = client.clone();
+                client.send_command(&set_cmd).await.ok()
+            })
+            .await;
+        });
+    }
 }
@@ -233,6 +233,55 @@ impl RedisServer {
         }
     }
 
+    /// Starts a replica of the server listening on `primary_port`.
+    pub fn new_replica(primary_port: u16) -> RedisServer {
+        let addr = redis::ConnectionAddr::Tcp("127.0.0.1".to_string(), get_available_port());
+        RedisServer::new_with_addr_tls_modules_and_spawner(addr, None, &[], |cmd| {
+            cmd.arg("--replicaof")
+                .arg("127.0.0.1")
+                .arg(primary_port.to_string())
+                .spawn()
+                .unwrap_or_else(|err| panic!("Failed to run {cmd:?}: {err}"))
+        })
+    }
+
+    /// Starts a sentinel that monitors the primary listening on `primary_port` under `master_name`, with a quorum of 1.
+    pub fn new_sentinel(master_name: &str, primary_port: u16) -> RedisServer {
+        let tempdir = tempfile::Builder::new()
+            .prefix("sentinel")
+            .tempdir()
+            .expect("failed to create tempdir");
+        // Sentinels rewrite their configuration file, which must be the first argument.
+        let config_path = tempdir.path().join("sentinel.conf");
+        fs::write(
+            &config_path,
+            format!(
+                "sentinel monitor {master_name} 127.0.0.1 {primary_port} 1\n\
+                 sentinel down-after-milliseconds {master_name} 1000\n\
+                 sentinel failover-timeout {master_name} 5000\n"
+            ),
+        )
+        .expect("failed to write sentinel configuration");
+        let port = get_available_port();
+        let mut sentinel_cmd = process::Command::new("redis-server");
+        sentinel_cmd
+            .arg(&config_path)
+            .arg("--sentinel")
+            .arg("--port")
+            .arg(port.to_string())
+            .arg("--bind")
+            .arg("127.0.0.1")
+            .stdout(process::Stdio::null())
+            .stderr(process::Stdio::null());
+        RedisServer {
+            process: sentinel_cmd
+                .spawn()
+                .unwrap_or_else(|err| panic!("Failed to run {sentinel_cmd:?}: {err}")),
+            tempdir: Some(tempdir),
+            addr: redis::ConnectionAddr::Tcp("127.0.0.1".to_string(), port),
+        }
+    }
+
     pub fn get_client_addr(&self) -> redis::ConnectionAddr {
         self.addr.clone()
     }
@@ -663,6 +712,10 @@ pub fn create_connection_request(
     }
     connection_request.lazy_connect = configuration.lazy_connect;
 
+    if let Some(key_prefix) = &configuration.key_prefix {
+        connection_request.key_prefix = key_prefix.deref().into();
+    }
+
     connection_request
 }
 
@@ -680,6 +733,7 @@ pub struct TestConfiguration {
     pub client_az: Option<String>,
     pub protocol: ProtocolVersion,
     pub lazy_connect: bool,
+    pub key_prefix: Option<String>,
 }
 
 pub(crate) async fn setup_test_basics_internal(configuration: &TestConfiguration) -> TestBasics {
@@ -725,6 +779,26 @@ pub async fn setup_test_basics(use_tls: bool) -> TestBasics {
     .await
 }
 
+/// Creates a client of the shared cluster if `use_cluster` is set, or of the shared standalone server otherwise.
+/// `configure` sets the options of the connection request which [`TestConfiguration`] doesn't cover.
+pub async fn create_shared_client(
+    use_cluster: bool,
+    mut configuration: TestConfiguration,
+    configure: impl FnOnce(&mut glide_core::client::ConnectionRequest),
+) -> Client {
+    let addresses = if use_cluster {
+        configuration.cluster_mode = ClusterMode::Enabled;
+        cluster::get_shared_cluster_addresses(configuration.use_tls)
+    } else {
+        vec![get_shared_server_address(configuration.use_tls)]
+    };
+    let mut connection_request = create_connection_request(&addresses, &configuration);
+    connection_request.protocol = configuration.protocol.into();
+    let mut connection_request: glide_core::client::ConnectionRequest = connection_request.into();
+    configure(&mut connection_request);
+    Client::new(connection_request, None).await.unwrap()
+}
+
 #[cfg(test)]
 #[ctor::ctor]
 fn init() {
@@ -345,6 +345,9 @@ func (client *baseClient) executeCommandWithRoute(
 		routeBytesPtr,
 		routeBytesCount,
 		C.uint64_t(spanPtr),
+		C.bool(false),
+		C.uint32_t(0),
+		C.bool(false),
 	)
 	client.mu.Unlock()
 	// Wait for result or context cancellation
@@ -0,0 +1,73 @@
+/** Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0 */
+package glide.api.models.commands.FT;
+
+import glide.api.logging.Logger;
+import glide.ffi.resolvers.FtCursorResolver;
+import lombok.NonNull;
+
+/**
+ * A cursor of <code>FT.AGGREGATE ... WITHCURSOR</code>, which reads the following pages of the
+ * aggregation results with <code>FT.CURSOR READ</code>.
+ *
+ * <p>The cursor holds external resources, which are released by calling {@link
+ * #releaseCursorHandle()}, or when the cursor is garbage collected. Unless the results of the
+ * cursor were exhausted or it was deleted with <code>FT.CURSOR DEL</code>, releasing it also
+ * releases the cursor on the server.
+ */
+public final class FTAggregateCursor {
+
+    private final String cursorHandle;
+    private final boolean isFinished;
+    private boolean isClosed = false;
+
+    /**
+     * Creates a cursor from the cursor id in the reply of <code>FT.AGGREGATE ... WITHCURSOR</code> or
+     * <code>FT.CURSOR READ</code>.
+     */
+    public FTAggregateCursor(@NonNull String cursorHandle) {
+        this.cursorHandle = cursorHandle;
+        this.isFinished = FtCursorResolver.FINISHED_CURSOR_HANDLE.equals(cursorHandle);
+    }
+
+    /** Returns the handle String representing the cursor. */
+    public String getCursorHandle() {
+        return cursorHandle;
+    }
+
+    /** Returns <code>true</code> if the results of the cursor are exhausted. */
+    public boolean isFinished() {
+        return isFinished;
+    }
+
+    /** Releases the resources of the cursor. The cursor can't be read from afterwards. */
+    public void releaseCursorHandle() {
+        internalClose();
+    }
+
+    @Override
+    protected void finalize() throws Throwable {
+        try {
+            // Release the native cursor
+            this.internalClose();
+        } finally {
+            super.finalize();
+        }
+    }
+
+    private void internalClose() {
+        if (!isClosed) {
+            try {
+                FtCursorResolver.releaseNativeCursor(cursorHandle);
+            } catch (Exception ex) {
+                Logger.log(
+                        Logger.Level.ERROR,
+                        "FTAggregateCursor",
+                        () -> "Error releasing cursor " + cursorHandle,
+                        ex);
+            } finally {
+                // Mark the cursor as closed to avoid double-free (if close() gets called more than once).
+                isClosed = true;
+            }
+        }
+    }
+}
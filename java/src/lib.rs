@@ -10,7 +10,7 @@ use glide_core::SET as TYPE_SET;
 use glide_core::STREAM as TYPE_STREAM;
 use glide_core::STRING as TYPE_STRING;
 use glide_core::ZSET as TYPE_ZSET;
-use glide_core::client::FINISHED_SCAN_CURSOR;
+use glide_core::client::{FINISHED_FT_CURSOR, FINISHED_SCAN_CURSOR};
 
 // Telemetry required for getStatistics
 use glide_core::Telemetry;
@@ -559,6 +559,54 @@ pub extern "system" fn Java_glide_ffi_resolvers_ClusterScanCursorResolver_getFin
     safe_create_jstring(env, FINISHED_SCAN_CURSOR, "getFinishedCursorHandleConstant")
 }
 
+/// Releases an FTAggregateCursor handle allocated in Rust.
+///
+/// This function is meant to be invoked by Java using JNI.
+///
+/// * `_env`    - The JNI environment. Not used.
+/// * `_class`  - The class object. Not used.
+/// * cursor      - The cursor handle to release.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_ffi_resolvers_FtCursorResolver_releaseNativeCursor<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    cursor: JString<'local>,
+) {
+    handle_panics(
+        move || {
+            fn release_native_cursor(
+                env: &mut JNIEnv<'_>,
+                cursor: JString<'_>,
+            ) -> Result<(), FFIError> {
+                let cursor_str: String = env.get_string(&cursor)?.into();
+                glide_core::ft_cursor_container::remove_ft_cursor(cursor_str);
+                Ok(())
+            }
+            let result = release_native_cursor(&mut env, cursor);
+            handle_errors(&mut env, result)
+        },
+        "releaseNativeFtCursor",
+    )
+    .unwrap_or(())
+}
+
+/// Returns the String representing an exhausted FT cursor handle.
+///
+/// This function is meant to be invoked by Java using JNI. This is used to ensure
+/// that this constant is consistent with the Rust client.
+///
+/// * `env`    - The JNI environment.
+/// * `_class`  - The class object. Not used.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_ffi_resolvers_FtCursorResolver_getFinishedCursorHandleConstant<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    _class: JClass<'local>,
+) -> JString<'local> {
+    safe_create_jstring(env, FINISHED_FT_CURSOR, "getFinishedFtCursorHandleConstant")
+}
+
 /// Returns the String representing the name of the ObjectType String.
 ///
 /// This function is meant to be invoked by Java using JNI. This is used to ensure
@@ -18,6 +18,7 @@ use byteorder::{LittleEndian, WriteBytesExt};
 use bytes::Bytes;
 use glide_core::MAX_REQUEST_ARGS_LENGTH;
 use glide_core::client::ConnectionError;
+use glide_core::client::FINISHED_FT_CURSOR;
 use glide_core::client::get_or_init_runtime;
 use glide_core::start_socket_listener;
 use napi::bindgen_prelude::BigInt;
@@ -687,6 +688,48 @@ impl Drop for ClusterScanCursor {
     }
 }
 
+/// This struct is used to keep track of the cursor of `FT.AGGREGATE ... WITHCURSOR`.
+/// Like the cluster scan cursor, only the id of the cursor in the container is passed to JavaScript.
+/// The cursor is removed from the container when the object is deleted (dropped),
+/// and released on the server unless its results were exhausted or it was deleted.
+/// To create a cursor from the id in the reply of `FT.AGGREGATE ... WITHCURSOR`:
+/// ```typescript
+/// let cursor = new FtCursor("cursor_id");
+/// ```
+#[napi]
+pub struct FtCursor {
+    cursor: String,
+}
+
+#[napi]
+impl FtCursor {
+    #[napi(constructor)]
+    #[allow(dead_code)]
+    pub fn new(cursor: String) -> Self {
+        FtCursor { cursor }
+    }
+
+    /// Returns the cursor id.
+    #[napi]
+    #[allow(dead_code)]
+    pub fn get_cursor(&self) -> String {
+        self.cursor.clone()
+    }
+
+    #[napi]
+    #[allow(dead_code)]
+    /// Returns true if the results of the cursor are exhausted.
+    pub fn is_finished(&self) -> bool {
+        self.cursor.eq(FINISHED_FT_CURSOR)
+    }
+}
+
+impl Drop for FtCursor {
+    fn drop(&mut self) {
+        glide_core::ft_cursor_container::remove_ft_cursor(self.cursor.clone());
+    }
+}
+
 #[napi]
 pub fn get_statistics(env: Env) -> Result<JsObject> {
     let total_connections = Telemetry::total_connections().to_string();
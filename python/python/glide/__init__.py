@@ -176,6 +176,7 @@ from glide.routes import (
 
 from .glide import (
     ClusterScanCursor,
+    FtCursor,
     OpenTelemetryConfig,
     OpenTelemetryMetricsConfig,
     OpenTelemetryTracesConfig,
@@ -293,6 +294,7 @@ __all__ = [
     "TrimByMinId",
     "UpdateOptions",
     "ClusterScanCursor",
+    "FtCursor",
     # PubSub
     "PubSubMsg",
     # Json
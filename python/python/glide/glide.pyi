@@ -27,6 +27,11 @@ class ClusterScanCursor:
     def get_cursor(self) -> str: ...
     def is_finished(self) -> bool: ...
 
+class FtCursor:
+    def __init__(self, cursor: str) -> None: ...
+    def get_cursor(self) -> str: ...
+    def is_finished(self) -> bool: ...
+
 class OpenTelemetryConfig:
     def __init__(
         self,
@@ -3,8 +3,8 @@
 use bytes::Bytes;
 use glide_core::MAX_REQUEST_ARGS_LENGTH;
 use glide_core::Telemetry;
-use glide_core::client::FINISHED_SCAN_CURSOR;
 use glide_core::client::get_or_init_runtime;
+use glide_core::client::{FINISHED_FT_CURSOR, FINISHED_SCAN_CURSOR};
 use glide_core::errors::error_message;
 use glide_core::start_socket_listener;
 use glide_core::{
@@ -197,6 +197,37 @@ impl Drop for ClusterScanCursor {
     }
 }
 
+/// This struct is used to keep track of the cursor of `FT.AGGREGATE ... WITHCURSOR`.
+/// Like the cluster scan cursor, only the id of the cursor in the container is passed to Python.
+/// The cursor is removed from the container when the object is deleted (dropped),
+/// and released on the server unless its results were exhausted or it was deleted.
+#[pyclass]
+pub struct FtCursor {
+    cursor: String,
+}
+
+#[pymethods]
+impl FtCursor {
+    #[new]
+    fn new(cursor: String) -> Self {
+        FtCursor { cursor }
+    }
+
+    fn get_cursor(&self) -> String {
+        self.cursor.clone()
+    }
+
+    fn is_finished(&self) -> bool {
+        self.cursor == FINISHED_FT_CURSOR
+    }
+}
+
+impl Drop for FtCursor {
+    fn drop(&mut self) {
+        glide_core::ft_cursor_container::remove_ft_cursor(self.cursor.clone());
+    }
+}
+
 #[pyclass]
 pub struct Script {
     hash: String,
@@ -234,6 +265,7 @@ fn glide(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<Level>()?;
     m.add_class::<Script>()?;
     m.add_class::<ClusterScanCursor>()?;
+    m.add_class::<FtCursor>()?;
     m.add_class::<OpenTelemetryConfig>()?;
     m.add_class::<OpenTelemetryTracesConfig>()?;
     m.add_class::<OpenTelemetryMetricsConfig>()?;